@@ -130,6 +130,10 @@ echo "DEPOSIT_ESCROW=$DEPOSIT_ESCROW"
 # 🚀 CALL JS INIT
 ############################################################
 
+# InitIssuance несёт IssuanceParams (по умолчанию — все нули) и требует
+# PDA реестра платформы: [registry, PAYER] и текущую страницу
+# [registry_page, PAYER, page_index]. init_issuance.js выводит их сам.
+
 export PROGRAM_ID LOCK_MINT REWARD_MINT DEPOSIT_ESCROW REWARD_ESCROW PLATFORM_TREASURY
 export START_TS MATURITY_TS
 export RESERVE_TOTAL="$RESERVE_TOTAL_LAMPORTS"
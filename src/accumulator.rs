@@ -4,11 +4,29 @@
 #![forbid(unsafe_code)]
 
 use crate::error::LockrionError;
+use crate::state::{LockTier, MultiplierTier, MAX_LOCK_TIERS, MAX_MULTIPLIER_TIERS};
 
-/// accounting_period fixed to 86400 by profile; day_index is floor((t-start)/86400). :contentReference[oaicite:4]{index=4}
-pub const ACCOUNTING_PERIOD: i64 = 86_400;
+/// Default accounting period (one day); per-issuance value lives in IssuanceState.accounting_period.
+/// day_index is floor((t-start)/accounting_period). :contentReference[oaicite:4]{index=4}
+pub const DEFAULT_ACCOUNTING_PERIOD: i64 = 86_400;
+/// Continuous mode: weight = amount x seconds (day_index counts seconds since start).
+pub const CONTINUOUS_ACCOUNTING_PERIOD: i64 = 1;
+/// Accepted accounting period range: one second .. one year.
+pub const MIN_ACCOUNTING_PERIOD: i64 = 1;
+pub const MAX_ACCOUNTING_PERIOD: i64 = 365 * 86_400;
+/// Upper bound for the post-maturity vesting tail (ten years); keeps claim_end_ts representable.
+pub const MAX_VESTING_DURATION: i64 = 10 * 365 * 86_400;
+/// Upper bound for final_day_index. With amounts <= u64::MAX and multipliers <= 5x
+/// (early-bird x lock tier <= 25x), weighted_locked * days < 2^64 * 2^5 * 2^32 = 2^101
+/// fits u128 (pro-rated second units: * MAX_ACCOUNTING_PERIOD < 2^25 more, still < 2^126).
+/// Continuous mode caps the term at u32::MAX seconds (~136 years), same 2^101 bound;
+/// reserve_total * weight may exceed u128 and goes through math::mul_div_floor.
+pub const MAX_FINAL_DAY_INDEX: u64 = u32::MAX as u64;
 
-pub fn raw_day_index(block_ts: i64, start_ts: i64) -> Result<u64, LockrionError> {
+pub fn raw_day_index(block_ts: i64, start_ts: i64, accounting_period: i64) -> Result<u64, LockrionError> {
+    if accounting_period <= 0 {
+        return Err(LockrionError::DivisionByZero);
+    }
     if block_ts < start_ts {
         return Ok(0);
     }
@@ -16,7 +34,7 @@ pub fn raw_day_index(block_ts: i64, start_ts: i64) -> Result<u64, LockrionError>
         .checked_sub(start_ts)
         .ok_or(LockrionError::ArithmeticUnderflow)?;
     // delta >= 0 here
-    let d = delta / ACCOUNTING_PERIOD; // deterministic integer division
+    let d = delta / accounting_period; // deterministic integer division
     if d < 0 {
         // should be unreachable given checks, but keep deterministic guard
         return Err(LockrionError::InvariantViolation);
@@ -24,6 +42,193 @@ pub fn raw_day_index(block_ts: i64, start_ts: i64) -> Result<u64, LockrionError>
     Ok(d as u64)
 }
 
+/// final_day_index = floor((maturity_ts - start_ts) / accounting_period), 0 if maturity <= start.
+/// Rejects periods outside [MIN, MAX]_ACCOUNTING_PERIOD and results above MAX_FINAL_DAY_INDEX.
+pub fn final_day_index(start_ts: i64, maturity_ts: i64, accounting_period: i64) -> Result<u64, LockrionError> {
+    if !(MIN_ACCOUNTING_PERIOD..=MAX_ACCOUNTING_PERIOD).contains(&accounting_period) {
+        return Err(LockrionError::InvalidIssuanceParams);
+    }
+    if maturity_ts <= start_ts {
+        return Ok(0);
+    }
+    let d = raw_day_index(maturity_ts, start_ts, accounting_period)?;
+    if d > MAX_FINAL_DAY_INDEX {
+        return Err(LockrionError::InvalidIssuanceParams);
+    }
+    Ok(d)
+}
+
+/// Seconds in the trailing partial period, (maturity_ts - start_ts) % accounting_period (0 if aligned).
+pub fn final_period_tail(start_ts: i64, maturity_ts: i64, accounting_period: i64) -> Result<i64, LockrionError> {
+    if accounting_period <= 0 {
+        return Err(LockrionError::DivisionByZero);
+    }
+    if maturity_ts <= start_ts {
+        return Ok(0);
+    }
+    let span = maturity_ts
+        .checked_sub(start_ts)
+        .ok_or(LockrionError::ArithmeticUnderflow)?;
+    Ok(span % accounting_period)
+}
+
+/// Pro-rated mode: a misaligned tail becomes one extra (partial) period, index final_day_index - 1.
+/// Returns (final_day_index, prorated_tail); prorated_tail == 0 means plain whole periods.
+pub fn prorated_final_day_index(
+    start_ts: i64,
+    maturity_ts: i64,
+    accounting_period: i64,
+) -> Result<(u64, i64), LockrionError> {
+    let whole = final_day_index(start_ts, maturity_ts, accounting_period)?;
+    let tail = final_period_tail(start_ts, maturity_ts, accounting_period)?;
+    if tail == 0 {
+        return Ok((whole, 0));
+    }
+    let fin = whole.checked_add(1).ok_or(LockrionError::ArithmeticOverflow)?;
+    if fin > MAX_FINAL_DAY_INDEX {
+        return Err(LockrionError::InvalidIssuanceParams);
+    }
+    Ok((fin, tail))
+}
+
+/// Current day index: bounded raw index before maturity, final_day_index from maturity on
+/// (with a pro-rated tail the raw index only reaches final_day_index after maturity).
+pub fn current_day_index(
+    now: i64,
+    start_ts: i64,
+    maturity_ts: i64,
+    accounting_period: i64,
+    final_day_index: u64,
+) -> Result<u64, LockrionError> {
+    if now >= maturity_ts {
+        return Ok(final_day_index);
+    }
+    let raw = raw_day_index(now, start_ts, accounting_period)?;
+    Ok(bounded_day_index(raw, final_day_index))
+}
+
+/// Weight units per unit of locked amount accrued over day indices [from, to).
+/// prorated_tail == 0: one unit per period (plain v1.1 weights).
+/// prorated_tail > 0: units are seconds; full periods count accounting_period,
+/// the last period [final_day_index - 1, final_day_index) counts prorated_tail.
+/// Reward shares are scale-invariant, so only the tail ratio changes.
+pub fn period_units(
+    from: u64,
+    to: u64,
+    final_day_index: u64,
+    accounting_period: i64,
+    prorated_tail: i64,
+) -> Result<u128, LockrionError> {
+    if to <= from {
+        return Ok(0);
+    }
+    let periods = u128::from(to - from);
+    if prorated_tail == 0 {
+        return Ok(periods);
+    }
+    if prorated_tail < 0 || prorated_tail >= accounting_period || to > final_day_index {
+        return Err(LockrionError::InvariantViolation);
+    }
+
+    let mut units = periods
+        .checked_mul(accounting_period as u128)
+        .ok_or(LockrionError::ArithmeticOverflow)?;
+    if to == final_day_index {
+        units = units
+            .checked_sub((accounting_period - prorated_tail) as u128)
+            .ok_or(LockrionError::ArithmeticUnderflow)?;
+    }
+    Ok(units)
+}
+
 pub fn bounded_day_index(raw: u64, final_day_index: u64) -> u64 {
     if raw > final_day_index { final_day_index } else { raw }
 }
+
+/// 1x weight multiplier in basis points.
+pub const MULTIPLIER_BPS_ONE: u16 = 10_000;
+/// Upper bound for a schedule tier (5x).
+pub const MAX_MULTIPLIER_BPS: u16 = 50_000;
+
+/// Schedule rules: at most MAX_MULTIPLIER_TIERS tiers, end_day > 0 and strictly
+/// increasing, MULTIPLIER_BPS_ONE <= mult_bps <= MAX_MULTIPLIER_BPS.
+pub fn validate_multiplier_schedule(schedule: &[MultiplierTier]) -> Result<(), LockrionError> {
+    if schedule.len() > MAX_MULTIPLIER_TIERS {
+        return Err(LockrionError::InvalidIssuanceParams);
+    }
+    let mut prev_end = 0u64;
+    for tier in schedule {
+        if tier.end_day <= prev_end {
+            return Err(LockrionError::InvalidIssuanceParams);
+        }
+        if tier.mult_bps < MULTIPLIER_BPS_ONE || tier.mult_bps > MAX_MULTIPLIER_BPS {
+            return Err(LockrionError::InvalidIssuanceParams);
+        }
+        prev_end = tier.end_day;
+    }
+    Ok(())
+}
+
+/// Multiplier for a deposit on `day_index`: first tier with day_index < end_day,
+/// 1x past the last tier (unused slots have end_day == 0 and never match).
+pub fn multiplier_bps(schedule: &[MultiplierTier], day_index: u64) -> u16 {
+    schedule
+        .iter()
+        .find(|t| day_index < t.end_day)
+        .map(|t| t.mult_bps)
+        .unwrap_or(MULTIPLIER_BPS_ONE)
+}
+
+/// Lock tier rules: at most MAX_LOCK_TIERS tiers, MULTIPLIER_BPS_ONE <= mult_bps <=
+/// MAX_MULTIPLIER_BPS, penalty_bps <= 10_000, and a tier held to maturity
+/// (unlock_day == 0) carries no penalty; otherwise 0 < unlock_day < final_day_index.
+pub fn validate_lock_tiers(tiers: &[LockTier], final_day_index: u64) -> Result<(), LockrionError> {
+    if tiers.len() > MAX_LOCK_TIERS {
+        return Err(LockrionError::InvalidIssuanceParams);
+    }
+    for tier in tiers {
+        if tier.mult_bps < MULTIPLIER_BPS_ONE || tier.mult_bps > MAX_MULTIPLIER_BPS {
+            return Err(LockrionError::InvalidIssuanceParams);
+        }
+        if tier.penalty_bps > MULTIPLIER_BPS_ONE {
+            return Err(LockrionError::InvalidIssuanceParams);
+        }
+        if tier.unlock_day == 0 && tier.penalty_bps != 0 {
+            return Err(LockrionError::InvalidIssuanceParams);
+        }
+        if tier.unlock_day >= final_day_index && tier.unlock_day != 0 {
+            return Err(LockrionError::InvalidIssuanceParams);
+        }
+    }
+    Ok(())
+}
+
+/// Tier `index` of an issuance table. An empty table has one implicit tier 0
+/// (held to maturity, 1x); unused slots (mult_bps == 0) are not selectable.
+pub fn lock_tier(tiers: &[LockTier], index: u8) -> Result<LockTier, LockrionError> {
+    if index == 0 && tiers.iter().all(|t| t.mult_bps == 0) {
+        return Ok(LockTier { unlock_day: 0, mult_bps: MULTIPLIER_BPS_ONE, penalty_bps: 0 });
+    }
+    match tiers.get(index as usize) {
+        Some(t) if t.mult_bps != 0 => Ok(*t),
+        _ => Err(LockrionError::InvalidLockTier),
+    }
+}
+
+/// floor(amount * penalty_bps / 10_000): principal kept back on an early withdrawal.
+pub fn penalty_amount(amount: u128, penalty_bps: u16) -> Result<u128, LockrionError> {
+    if penalty_bps > MULTIPLIER_BPS_ONE {
+        return Err(LockrionError::InvariantViolation);
+    }
+    weighted_amount(amount, penalty_bps)
+}
+
+/// floor(amount * mult_bps / 10_000). The same value is added to the user and the
+/// global weighted totals, so sum(user weight) == total weight stays exact.
+pub fn weighted_amount(amount: u128, mult_bps: u16) -> Result<u128, LockrionError> {
+    let num = amount
+        .checked_mul(mult_bps as u128)
+        .ok_or(LockrionError::ArithmeticOverflow)?;
+    num.checked_div(MULTIPLIER_BPS_ONE as u128)
+        .ok_or(LockrionError::DivisionByZero)
+}
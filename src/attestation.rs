@@ -0,0 +1,68 @@
+// ==============================
+// src/attestation.rs (external attestation accounts gating deposits)
+// The attestation program is trusted for the account contents it owns:
+//   0..32   schema     (Pubkey)
+//   32..64  subject    (attested wallet)
+//   64..72  expires_at (i64 unix ts; 0 = no expiry)
+// Trailing bytes are ignored so programs may append their own fields.
+// ==============================
+#![forbid(unsafe_code)]
+
+use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{error::LockrionError, state::IssuanceState};
+
+pub const ATTESTATION_LEN: usize = 72;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Attestation {
+    pub schema: Pubkey,
+    pub subject: Pubkey,
+    pub expires_at: i64,
+}
+
+impl Attestation {
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        if input.len() < ATTESTATION_LEN {
+            return Err(LockrionError::InvalidAttestation.into());
+        }
+
+        Ok(Self {
+            schema: Pubkey::new_from_array(input[0..32].try_into().unwrap()),
+            subject: Pubkey::new_from_array(input[32..64].try_into().unwrap()),
+            expires_at: i64::from_le_bytes(input[64..72].try_into().unwrap()),
+        })
+    }
+
+    pub fn pack(&self, output: &mut [u8]) -> Result<(), ProgramError> {
+        if output.len() < ATTESTATION_LEN {
+            return Err(LockrionError::InvalidAttestation.into());
+        }
+
+        output[0..32].copy_from_slice(self.schema.as_ref());
+        output[32..64].copy_from_slice(self.subject.as_ref());
+        output[64..72].copy_from_slice(&self.expires_at.to_le_bytes());
+
+        Ok(())
+    }
+}
+
+/// Checks an attestation account against the issuance gate for `subject` at `now`.
+pub fn verify(
+    issuance: &IssuanceState,
+    attestation_ai: &AccountInfo,
+    subject: &Pubkey,
+    now: i64,
+) -> Result<(), ProgramError> {
+    if attestation_ai.owner != &issuance.attestation_program {
+        return Err(LockrionError::InvalidAttestation.into());
+    }
+    let attestation = Attestation::unpack(&attestation_ai.try_borrow_data()?)?;
+    if attestation.schema != issuance.attestation_schema || &attestation.subject != subject {
+        return Err(LockrionError::InvalidAttestation.into());
+    }
+    if attestation.expires_at != 0 && now >= attestation.expires_at {
+        return Err(LockrionError::AttestationExpired.into());
+    }
+    Ok(())
+}
@@ -0,0 +1,298 @@
+// ==============================
+// src/cpi.rs (helpers for programs calling Lockrion by CPI)
+// ==============================
+#![forbid(unsafe_code)]
+
+// Depend on this crate with `features = ["no-entrypoint"]`.
+// Account order matches the processor comments; `participant` may be a PDA of
+// the calling program, signed via `signer_seeds` (pass &[] for a wallet signer).
+// Receipt-mode and attestation-gated issuances, and early (lock tier) withdrawals,
+// need extra accounts and are not covered here.
+
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program,
+};
+
+use crate::{error::LockrionError, instruction::LockrionInstruction, pda};
+
+fn encode(ix: &LockrionInstruction) -> Result<Vec<u8>, ProgramError> {
+    ix.try_to_vec().map_err(|_| LockrionError::InvalidInstruction.into())
+}
+
+// ---------------------------------------------------------------------
+// deposit
+// ---------------------------------------------------------------------
+
+pub struct DepositAccounts<'a> {
+    pub issuance: AccountInfo<'a>,
+    pub user_state: AccountInfo<'a>,
+    /// Token authority; pays UserState rent (a PDA payer must hold lamports).
+    pub participant: AccountInfo<'a>,
+    pub participant_lock_ata: AccountInfo<'a>,
+    pub deposit_escrow: AccountInfo<'a>,
+    pub token_program: AccountInfo<'a>,
+    pub system_program: AccountInfo<'a>,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn deposit_instruction(
+    program_id: &Pubkey,
+    issuance: &Pubkey,
+    user_state: &Pubkey,
+    participant: &Pubkey,
+    participant_lock_ata: &Pubkey,
+    deposit_escrow: &Pubkey,
+    token_program: &Pubkey,
+    amount: u64,
+) -> Result<Instruction, ProgramError> {
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*issuance, false),
+            AccountMeta::new(*user_state, false),
+            AccountMeta::new(*participant, true),
+            AccountMeta::new(*participant_lock_ata, false),
+            AccountMeta::new(*deposit_escrow, false),
+            AccountMeta::new_readonly(*token_program, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: encode(&LockrionInstruction::Deposit { amount })?,
+    })
+}
+
+pub fn deposit<'a>(
+    lockrion_program: &AccountInfo<'a>,
+    accounts: DepositAccounts<'a>,
+    amount: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = deposit_instruction(
+        lockrion_program.key,
+        accounts.issuance.key,
+        accounts.user_state.key,
+        accounts.participant.key,
+        accounts.participant_lock_ata.key,
+        accounts.deposit_escrow.key,
+        accounts.token_program.key,
+        amount,
+    )?;
+    invoke_signed(
+        &ix,
+        &[
+            accounts.issuance,
+            accounts.user_state,
+            accounts.participant,
+            accounts.participant_lock_ata,
+            accounts.deposit_escrow,
+            accounts.token_program,
+            accounts.system_program,
+            lockrion_program.clone(),
+        ],
+        signer_seeds,
+    )
+}
+
+// ---------------------------------------------------------------------
+// deposit_with_terms (issuances with metadata; same accounts + metadata PDA)
+// ---------------------------------------------------------------------
+
+#[allow(clippy::too_many_arguments)]
+pub fn deposit_with_terms_instruction(
+    program_id: &Pubkey,
+    issuance: &Pubkey,
+    user_state: &Pubkey,
+    participant: &Pubkey,
+    participant_lock_ata: &Pubkey,
+    deposit_escrow: &Pubkey,
+    token_program: &Pubkey,
+    amount: u64,
+    terms_hash: [u8; 32],
+) -> Result<Instruction, ProgramError> {
+    let mut ix = deposit_instruction(
+        program_id,
+        issuance,
+        user_state,
+        participant,
+        participant_lock_ata,
+        deposit_escrow,
+        token_program,
+        amount,
+    )?;
+    let metadata = pda::derive_metadata_pda(program_id, issuance).0;
+    ix.accounts.push(AccountMeta::new_readonly(metadata, false));
+    ix.data = encode(&LockrionInstruction::DepositWithTerms { amount, terms_hash })?;
+    Ok(ix)
+}
+
+pub fn deposit_with_terms<'a>(
+    lockrion_program: &AccountInfo<'a>,
+    accounts: DepositAccounts<'a>,
+    metadata: AccountInfo<'a>,
+    amount: u64,
+    terms_hash: [u8; 32],
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = deposit_with_terms_instruction(
+        lockrion_program.key,
+        accounts.issuance.key,
+        accounts.user_state.key,
+        accounts.participant.key,
+        accounts.participant_lock_ata.key,
+        accounts.deposit_escrow.key,
+        accounts.token_program.key,
+        amount,
+        terms_hash,
+    )?;
+    invoke_signed(
+        &ix,
+        &[
+            accounts.issuance,
+            accounts.user_state,
+            accounts.participant,
+            accounts.participant_lock_ata,
+            accounts.deposit_escrow,
+            accounts.token_program,
+            accounts.system_program,
+            metadata,
+            lockrion_program.clone(),
+        ],
+        signer_seeds,
+    )
+}
+
+// ---------------------------------------------------------------------
+// claim_reward
+// ---------------------------------------------------------------------
+
+pub struct ClaimRewardAccounts<'a> {
+    pub issuance: AccountInfo<'a>,
+    pub user_state: AccountInfo<'a>,
+    pub participant: AccountInfo<'a>,
+    pub participant_reward_ata: AccountInfo<'a>,
+    pub reward_escrow: AccountInfo<'a>,
+    pub token_program: AccountInfo<'a>,
+}
+
+pub fn claim_reward_instruction(
+    program_id: &Pubkey,
+    issuance: &Pubkey,
+    user_state: &Pubkey,
+    participant: &Pubkey,
+    participant_reward_ata: &Pubkey,
+    reward_escrow: &Pubkey,
+    token_program: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*issuance, false),
+            AccountMeta::new(*user_state, false),
+            AccountMeta::new(*participant, true),
+            AccountMeta::new(*participant_reward_ata, false),
+            AccountMeta::new(*reward_escrow, false),
+            AccountMeta::new_readonly(*token_program, false),
+        ],
+        data: encode(&LockrionInstruction::ClaimReward)?,
+    })
+}
+
+pub fn claim_reward<'a>(
+    lockrion_program: &AccountInfo<'a>,
+    accounts: ClaimRewardAccounts<'a>,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = claim_reward_instruction(
+        lockrion_program.key,
+        accounts.issuance.key,
+        accounts.user_state.key,
+        accounts.participant.key,
+        accounts.participant_reward_ata.key,
+        accounts.reward_escrow.key,
+        accounts.token_program.key,
+    )?;
+    invoke_signed(
+        &ix,
+        &[
+            accounts.issuance,
+            accounts.user_state,
+            accounts.participant,
+            accounts.participant_reward_ata,
+            accounts.reward_escrow,
+            accounts.token_program,
+            lockrion_program.clone(),
+        ],
+        signer_seeds,
+    )
+}
+
+// ---------------------------------------------------------------------
+// withdraw_deposit
+// ---------------------------------------------------------------------
+
+pub struct WithdrawDepositAccounts<'a> {
+    pub issuance: AccountInfo<'a>,
+    pub user_state: AccountInfo<'a>,
+    pub participant: AccountInfo<'a>,
+    pub participant_lock_ata: AccountInfo<'a>,
+    pub deposit_escrow: AccountInfo<'a>,
+    pub token_program: AccountInfo<'a>,
+}
+
+pub fn withdraw_deposit_instruction(
+    program_id: &Pubkey,
+    issuance: &Pubkey,
+    user_state: &Pubkey,
+    participant: &Pubkey,
+    participant_lock_ata: &Pubkey,
+    deposit_escrow: &Pubkey,
+    token_program: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*issuance, false),
+            AccountMeta::new(*user_state, false),
+            AccountMeta::new(*participant, true),
+            AccountMeta::new(*participant_lock_ata, false),
+            AccountMeta::new(*deposit_escrow, false),
+            AccountMeta::new_readonly(*token_program, false),
+        ],
+        data: encode(&LockrionInstruction::WithdrawDeposit)?,
+    })
+}
+
+pub fn withdraw_deposit<'a>(
+    lockrion_program: &AccountInfo<'a>,
+    accounts: WithdrawDepositAccounts<'a>,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = withdraw_deposit_instruction(
+        lockrion_program.key,
+        accounts.issuance.key,
+        accounts.user_state.key,
+        accounts.participant.key,
+        accounts.participant_lock_ata.key,
+        accounts.deposit_escrow.key,
+        accounts.token_program.key,
+    )?;
+    invoke_signed(
+        &ix,
+        &[
+            accounts.issuance,
+            accounts.user_state,
+            accounts.participant,
+            accounts.participant_lock_ata,
+            accounts.deposit_escrow,
+            accounts.token_program,
+            lockrion_program.clone(),
+        ],
+        signer_seeds,
+    )
+}
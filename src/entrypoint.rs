@@ -11,6 +11,7 @@ use solana_program::{
 
 use crate::processor::Processor;
 
+#[cfg(not(feature = "no-entrypoint"))]
 solana_program::entrypoint!(process_instruction);
 
 pub fn process_instruction(
@@ -12,6 +12,8 @@ pub enum LockrionError {
     // 0–9: Instruction
     #[error("Invalid instruction")]
     InvalidInstruction = 0,
+    #[error("Invalid issuance params")]
+    InvalidIssuanceParams = 1,
 
     // 10–19: Funding
     #[error("Reserve already funded")]
@@ -32,6 +34,10 @@ pub enum LockrionError {
     DepositWindowNotClosed = 22,
     #[error("Invalid amount")]
     InvalidAmount = 23,
+    #[error("Terms acknowledgment required")]
+    TermsNotAcknowledged = 24,
+    #[error("Terms hash mismatch")]
+    TermsHashMismatch = 25,
 
     // 30–39: Claim
     #[error("Claim window not started")]
@@ -40,6 +46,10 @@ pub enum LockrionError {
     ClaimWindowClosed = 31,
     #[error("Already claimed")]
     AlreadyClaimed = 32,
+    #[error("Nothing to settle")]
+    NothingToSettle = 33,
+    #[error("Nothing vested")]
+    NothingVested = 34,
 
     // 40–49: Sweep / Reclaim
     #[error("Sweep already executed")]
@@ -82,6 +92,28 @@ pub enum LockrionError {
     InvalidStateVersion = 70,
     #[error("Invalid account size")]
     InvalidAccountSize = 71,
+    #[error("State migration required")]
+    StateMigrationRequired = 72,
+
+    // 80–89: Position
+    #[error("Position transfer to self")]
+    PositionSelfTransfer = 80,
+    #[error("Position merge conflict")]
+    PositionMergeConflict = 81,
+    #[error("Receipt mode enabled")]
+    ReceiptModeEnabled = 82,
+    #[error("Receipt mode disabled")]
+    ReceiptModeDisabled = 83,
+    #[error("Receipt balance mismatch")]
+    ReceiptBalanceMismatch = 84,
+    #[error("Invalid lock tier")]
+    InvalidLockTier = 85,
+
+    // 90–99: Attestation
+    #[error("Invalid attestation")]
+    InvalidAttestation = 90,
+    #[error("Attestation expired")]
+    AttestationExpired = 91,
 }
 
 impl From<LockrionError> for ProgramError {
@@ -4,15 +4,66 @@
 #![forbid(unsafe_code)]
 
 use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
 
+use crate::{
+    metadata::MetadataArgs,
+    state::{LockTier, MultiplierTier},
+};
+
+/// Optional per-issuance parameters, fixed at InitIssuance (immutable afterwards).
+/// Default (all zero) = plain v1.1 behaviour.
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct IssuanceParams {
+    /// Receipt-token mode: deposit mints 1:1 receipts from the issuance receipt
+    /// mint PDA, withdraw burns them. Requires extra InitIssuance accounts.
+    pub receipt_mint: bool,
+    /// Linear reward vesting after maturity_ts, in seconds (0 = paid at once).
+    /// Claim window and sweep start shift by the same amount.
+    pub vesting_duration: i64,
+    /// Early-bird weight multipliers by deposit day (at most MAX_MULTIPLIER_TIERS,
+    /// end_day strictly increasing, mult_bps >= 10_000). Empty = weight 1x.
+    /// Not combinable with receipt_mint.
+    pub multiplier_schedule: Vec<MultiplierTier>,
+    /// Seconds per accounting period (day_index step); 0 = 86_400.
+    /// Must lie in 1 ..= 365 days and give final_day_index <= u32::MAX.
+    pub accounting_period: i64,
+    /// Count a misaligned tail (maturity_ts not on a period boundary) as a final
+    /// partial period weighted by its seconds, instead of dropping it.
+    pub prorate_final_period: bool,
+    /// Continuous accounting: weight = amount x seconds (accounting_period = 1).
+    /// accounting_period must be left 0; multiplier end_day values are then seconds.
+    pub continuous: bool,
+    /// Issuer authority stored as issuer_address: signs FundReserve / Reclaim and
+    /// keys the issuance PDA. May be a multisig or program PDA (signing via CPI).
+    /// Pubkey::default() = the platform authority (payer).
+    pub issuer: Pubkey,
+    /// Immutable name / symbol / URI / terms hash, written to the metadata PDA.
+    /// Requires the extra metadata InitIssuance account.
+    pub metadata: Option<MetadataArgs>,
+    /// Deposits (and position transfers) require an unexpired attestation account
+    /// owned by this program for the participant. Pubkey::default() = no gate.
+    /// Not combinable with receipt_mint.
+    pub attestation_program: Pubkey,
+    /// Schema the attestation must carry.
+    pub attestation_schema: Pubkey,
+    /// Commitment tiers selectable at deposit (at most MAX_LOCK_TIERS; tier 0 is
+    /// the default). Empty = one implicit tier: held to maturity, weight 1x.
+    /// unlock_day is a day_index (seconds in continuous mode). Not combinable with receipt_mint.
+    pub lock_tiers: Vec<LockTier>,
+}
+
+// Decoded once per instruction; boxing InitIssuance params would only change the API.
+#[allow(clippy::large_enum_variant)]
 #[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
 pub enum LockrionInstruction {
-    /// InitIssuance(reserve_total, start_ts, maturity_ts)
+    /// InitIssuance(reserve_total, start_ts, maturity_ts, params)
     /// Creates and initializes issuance state account.
     InitIssuance {
         reserve_total: u128,
         start_ts: i64,
         maturity_ts: i64,
+        params: IssuanceParams,
     },
 
     /// fund_reserve(amount: u64)
@@ -22,6 +73,8 @@ pub enum LockrionInstruction {
     },
 
     /// deposit(amount: u64)
+    /// An optional trailing rent_sponsor signer pays the UserState rent.
+    /// Native lock_mint: passing the funder as its own lock account wraps lamports.
     Deposit {
         amount: u64,
     },
@@ -30,11 +83,95 @@ pub enum LockrionInstruction {
     ClaimReward,
 
     /// withdraw_deposit()
+    /// Native lock_mint: the participant's wSOL account is closed (unwrapped) afterwards.
     WithdrawDeposit,
 
     /// sweep()
+    /// lock_mint == reward_mint: the deposit escrow follows as a last account and
+    /// must cover total_locked. Legacy issuances sharing one escrow sweep only the
+    /// balance above total_locked, so participants can still withdraw.
     Sweep,
 
     /// zero_participation_reclaim()
+    /// lock_mint == reward_mint: deposit escrow last, as for sweep.
     ZeroParticipationReclaim,
+
+    /// claim_reward_batch()
+    /// remaining accounts = (user_state, participant_reward_ata) pairs.
+    /// Already-claimed users are skipped; return data = per-pair bitmap (LSB first).
+    ClaimRewardBatch,
+
+    /// settle()
+    /// claim_reward + withdraw_deposit in one call; a half already done is skipped.
+    Settle,
+
+    /// transfer_position()
+    /// Moves the whole source position into the recipient's UserState (created if needed).
+    /// Issuances with terms: the recipient must already have acknowledged them by deposit
+    /// (else transfer_position_with_terms).
+    TransferPosition,
+
+    /// sync_position()
+    /// Receipt mode only: re-attributes locked_amount of two holders to their
+    /// receipt token balances (accumulators settled first).
+    /// Receipt transfers are plain SPL transfers the program does not see: until
+    /// someone syncs the pair, weight keeps accruing to the sender's UserState.
+    /// The receiver is the one incentivized to call it (permissionless).
+    SyncPosition,
+
+    /// migrate_state()
+    /// Rewrites a v1 IssuanceState / UserState into the v3 layout in place
+    /// (realloc; payer covers the extra rent). Permissionless.
+    MigrateState,
+
+    /// quote()
+    /// Read-only: finalizes accumulators in memory and returns a Borsh
+    /// view::QuoteView via return data. Writes no account (simulate-safe).
+    Quote,
+
+    /// deposit_for(beneficiary, amount)
+    /// Same accounts as deposit, but the funder (signer) supplies tokens and rent
+    /// while the UserState belongs to `beneficiary`, who alone can claim / withdraw.
+    DepositFor {
+        beneficiary: Pubkey,
+        amount: u64,
+    },
+
+    /// deposit_with_terms(amount, terms_hash)
+    /// Deposit accounts plus the issuance metadata PDA as the last account.
+    /// terms_hash must equal the stored one (never all zero); it is recorded in
+    /// UserState with the acknowledgment time. Required when the issuance has metadata.
+    DepositWithTerms {
+        amount: u64,
+        terms_hash: [u8; 32],
+    },
+
+    /// rollover(terms_hash, lock_tier)
+    /// After maturity of the source issuance, moves the participant's whole
+    /// principal from its deposit escrow straight into the target issuance
+    /// (same lock_mint, target in its deposit window). Source reward stays claimable.
+    /// terms_hash and lock_tier apply to the target as in deposit_with_tier
+    /// (target metadata PDA last when terms_hash is set).
+    Rollover {
+        terms_hash: Option<[u8; 32]>,
+        lock_tier: u8,
+    },
+
+    /// deposit_with_tier(amount, lock_tier, terms_hash)
+    /// Deposit accounts (plus the metadata PDA last when terms_hash is set, as in
+    /// deposit_with_terms) into issuance lock tier `lock_tier`. A position holding
+    /// principal cannot switch tiers.
+    DepositWithTier {
+        amount: u64,
+        lock_tier: u8,
+        terms_hash: Option<[u8; 32]>,
+    },
+
+    /// transfer_position_with_terms(terms_hash)
+    /// Transfer_position accounts with the recipient co-signing, plus the metadata
+    /// PDA last. terms_hash must equal the stored one; it is recorded in the
+    /// recipient's UserState with the acknowledgment time.
+    TransferPositionWithTerms {
+        terms_hash: [u8; 32],
+    },
 }
\ No newline at end of file
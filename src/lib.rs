@@ -12,5 +12,12 @@ pub mod processor;
 pub mod state;
 pub mod pda;
 pub mod accumulator;
+pub mod vesting;
+pub mod math;
+pub mod view;
+pub mod cpi;
+pub mod registry;
+pub mod metadata;
+pub mod attestation;
 
 solana_program::declare_id!("GyJD65QDSNaskfNEpYaxJokog84ZjAx84nvm62NzS4wj"); // TODO: replace
@@ -0,0 +1,55 @@
+// ==============================
+// src/math.rs (wide intermediate arithmetic)
+// ==============================
+#![forbid(unsafe_code)]
+
+use crate::error::LockrionError;
+
+const LO_MASK: u128 = u64::MAX as u128;
+
+/// Full 256-bit product of two u128 values as (hi, lo).
+pub fn mul_wide(a: u128, b: u128) -> (u128, u128) {
+    let (a1, a0) = (a >> 64, a & LO_MASK);
+    let (b1, b0) = (b >> 64, b & LO_MASK);
+
+    let p00 = a0 * b0;
+    let p01 = a0 * b1;
+    let p10 = a1 * b0;
+    let p11 = a1 * b1;
+
+    // mid < 3 * 2^64, no overflow
+    let mid = (p00 >> 64) + (p01 & LO_MASK) + (p10 & LO_MASK);
+    let lo = (p00 & LO_MASK) | ((mid & LO_MASK) << 64);
+    let hi = p11 + (p01 >> 64) + (p10 >> 64) + (mid >> 64);
+    (hi, lo)
+}
+
+/// floor(a * b / d) with a 256-bit intermediate; errors only if the quotient exceeds u128.
+/// Reward math needs this once weights are second-granular: reserve_total (< 2^64)
+/// times a weight (< 2^99) no longer fits u128.
+pub fn mul_div_floor(a: u128, b: u128, d: u128) -> Result<u128, LockrionError> {
+    if d == 0 {
+        return Err(LockrionError::DivisionByZero);
+    }
+    let (hi, lo) = mul_wide(a, b);
+    if hi == 0 {
+        return Ok(lo / d);
+    }
+    if hi >= d {
+        return Err(LockrionError::ArithmeticOverflow);
+    }
+
+    // Restoring long division of (hi, lo) by d; hi < d keeps the quotient in u128.
+    let mut rem = hi;
+    let mut q: u128 = 0;
+    for i in (0..128).rev() {
+        let carry = rem >> 127;
+        rem = (rem << 1) | ((lo >> i) & 1);
+        q <<= 1;
+        if carry == 1 || rem >= d {
+            rem = rem.wrapping_sub(d);
+            q |= 1;
+        }
+    }
+    Ok(q)
+}
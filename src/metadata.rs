@@ -0,0 +1,133 @@
+// ==============================
+// src/metadata.rs (immutable per-issuance metadata, PDA [metadata, issuance])
+// BYTE-EXACT layout: 312 bytes, written once by InitIssuance, never updated.
+// Strings are stored as length byte + zero-padded UTF-8 bytes.
+// ==============================
+#![forbid(unsafe_code)]
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+use crate::error::LockrionError;
+
+pub const METADATA_VERSION: u8 = 1;
+pub const METADATA_SIZE: usize = 312;
+
+pub const MAX_NAME_LEN: usize = 32;
+pub const MAX_SYMBOL_LEN: usize = 10;
+pub const MAX_URI_LEN: usize = 200;
+
+const NAME_AT: usize = 66;
+const SYMBOL_AT: usize = NAME_AT + 1 + MAX_NAME_LEN;     // 99
+const URI_AT: usize = SYMBOL_AT + 1 + MAX_SYMBOL_LEN;    // 110
+
+/// InitIssuance input (IssuanceParams::metadata).
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct MetadataArgs {
+    /// At most MAX_NAME_LEN bytes.
+    pub name: String,
+    /// At most MAX_SYMBOL_LEN bytes.
+    pub symbol: String,
+    /// At most MAX_URI_LEN bytes.
+    pub uri: String,
+    /// SHA-256 of the terms document approved for this issuance. Must not be
+    /// all zero (zero means "none acknowledged" in UserState).
+    pub terms_hash: [u8; 32],
+}
+
+impl MetadataArgs {
+    pub fn validate(&self) -> Result<(), ProgramError> {
+        if self.name.len() > MAX_NAME_LEN
+            || self.symbol.len() > MAX_SYMBOL_LEN
+            || self.uri.len() > MAX_URI_LEN
+            || self.terms_hash == [0u8; 32]
+        {
+            return Err(LockrionError::InvalidIssuanceParams.into());
+        }
+        Ok(())
+    }
+}
+
+// ============================================================
+// ISSUANCE METADATA (312 bytes exact)
+// ============================================================
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IssuanceMetadata {
+    pub version: u8,                // 0
+    pub bump: u8,                   // 1
+    pub issuance: Pubkey,           // 2..34
+    pub terms_hash: [u8; 32],       // 34..66
+    pub name: String,               // 66 len, 67..99
+    pub symbol: String,             // 99 len, 100..110
+    pub uri: String,                // 110 len, 111..311
+                                    // 311 padding
+}
+
+impl IssuanceMetadata {
+    pub fn new(issuance: Pubkey, bump: u8, args: &MetadataArgs) -> Self {
+        Self {
+            version: METADATA_VERSION,
+            bump,
+            issuance,
+            terms_hash: args.terms_hash,
+            name: args.name.clone(),
+            symbol: args.symbol.clone(),
+            uri: args.uri.clone(),
+        }
+    }
+
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        if input.len() != METADATA_SIZE {
+            return Err(LockrionError::InvalidAccountSize.into());
+        }
+        if input[0] != METADATA_VERSION {
+            return Err(LockrionError::InvalidStateVersion.into());
+        }
+
+        Ok(Self {
+            version: input[0],
+            bump: input[1],
+            issuance: Pubkey::new_from_array(input[2..34].try_into().unwrap()),
+            terms_hash: input[34..66].try_into().unwrap(),
+            name: read_str(input, NAME_AT, MAX_NAME_LEN)?,
+            symbol: read_str(input, SYMBOL_AT, MAX_SYMBOL_LEN)?,
+            uri: read_str(input, URI_AT, MAX_URI_LEN)?,
+        })
+    }
+
+    pub fn pack(&self, output: &mut [u8]) -> Result<(), ProgramError> {
+        if output.len() != METADATA_SIZE {
+            return Err(LockrionError::InvalidAccountSize.into());
+        }
+
+        output.fill(0);
+        output[0] = self.version;
+        output[1] = self.bump;
+        output[2..34].copy_from_slice(self.issuance.as_ref());
+        output[34..66].copy_from_slice(&self.terms_hash);
+        write_str(output, NAME_AT, MAX_NAME_LEN, &self.name)?;
+        write_str(output, SYMBOL_AT, MAX_SYMBOL_LEN, &self.symbol)?;
+        write_str(output, URI_AT, MAX_URI_LEN, &self.uri)?;
+
+        Ok(())
+    }
+}
+
+fn read_str(input: &[u8], at: usize, cap: usize) -> Result<String, ProgramError> {
+    let len = input[at] as usize;
+    if len > cap {
+        return Err(LockrionError::InvalidAccountSize.into());
+    }
+    String::from_utf8(input[at + 1..at + 1 + len].to_vec())
+        .map_err(|_| LockrionError::InvalidIssuanceParams.into())
+}
+
+fn write_str(output: &mut [u8], at: usize, cap: usize, s: &str) -> Result<(), ProgramError> {
+    if s.len() > cap {
+        return Err(LockrionError::InvalidIssuanceParams.into());
+    }
+    output[at] = s.len() as u8;
+    output[at + 1..at + 1 + s.len()].copy_from_slice(s.as_bytes());
+    Ok(())
+}
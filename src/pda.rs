@@ -7,6 +7,10 @@ use solana_program::pubkey::Pubkey;
 
 pub const SEED_ISSUANCE: &[u8] = b"issuance";
 pub const SEED_USER: &[u8] = b"user";
+pub const SEED_RECEIPT: &[u8] = b"receipt";
+pub const SEED_METADATA: &[u8] = b"metadata";
+pub const SEED_REGISTRY: &[u8] = b"registry";
+pub const SEED_REGISTRY_PAGE: &[u8] = b"registry_page";
 
 pub fn derive_issuance_pda(
     program_id: &Pubkey,
@@ -39,3 +43,57 @@ pub fn derive_user_pda(
         program_id,
     )
 }
+
+pub fn derive_receipt_mint_pda(
+    program_id: &Pubkey,
+    issuance_pda: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            SEED_RECEIPT,
+            issuance_pda.as_ref(),
+        ],
+        program_id,
+    )
+}
+
+pub fn derive_metadata_pda(
+    program_id: &Pubkey,
+    issuance_pda: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            SEED_METADATA,
+            issuance_pda.as_ref(),
+        ],
+        program_id,
+    )
+}
+
+pub fn derive_registry_pda(
+    program_id: &Pubkey,
+    scope: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            SEED_REGISTRY,
+            scope.as_ref(),
+        ],
+        program_id,
+    )
+}
+
+pub fn derive_registry_page_pda(
+    program_id: &Pubkey,
+    scope: &Pubkey,
+    page_index: u32,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            SEED_REGISTRY_PAGE,
+            scope.as_ref(),
+            &page_index.to_le_bytes(),
+        ],
+        program_id,
+    )
+}
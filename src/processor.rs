@@ -3,9 +3,9 @@
 // ==============================
 #![forbid(unsafe_code)]
 
-use solana_program::{program::invoke_signed, system_instruction, system_program, rent::Rent};
+use solana_program::{program::{invoke, invoke_signed, set_return_data}, system_instruction, system_program, rent::Rent};
 
-use borsh::BorshDeserialize;
+use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::program_pack::Pack;
 
 use solana_program::{
@@ -17,14 +17,23 @@ use solana_program::{
     sysvar::Sysvar,
 };
 
-use spl_token::state::Account as TokenAccount;
+use spl_token::state::{Account as TokenAccount, Mint};
 
 use crate::{
     accumulator,
+    attestation,
     error::LockrionError,
-    instruction::LockrionInstruction,
+    instruction::{IssuanceParams, LockrionInstruction},
+    math,
+    metadata::IssuanceMetadata,
     pda,
-    state::{IssuanceState, UserState},
+    registry::{self, RegistryHead, RegistryRecord},
+    state::{
+        IssuanceState, IssuanceStateV1, LockTier, MultiplierTier, UserState, UserStateV1, ISSUANCE_STATE_SIZE,
+        ISSUANCE_STATE_SIZE_V1, MAX_LOCK_TIERS, MAX_MULTIPLIER_TIERS, USER_STATE_SIZE, USER_STATE_SIZE_V1,
+    },
+    vesting,
+    view::{Phase, QuoteView},
 };
 
 // Platform-only init gate (hardcoded authority)
@@ -37,14 +46,30 @@ impl Processor {
     pub fn process(program_id: &Pubkey, accounts: &[AccountInfo], ix_data: &[u8]) -> ProgramResult {
         let ix = LockrionInstruction::try_from_slice(ix_data).map_err(|_| LockrionError::InvalidInstruction)?;
         match ix {
-            LockrionInstruction::InitIssuance { reserve_total, start_ts, maturity_ts } =>
-            Self::init_issuance(program_id, accounts, reserve_total, start_ts, maturity_ts),
+            LockrionInstruction::InitIssuance { reserve_total, start_ts, maturity_ts, params } =>
+            Self::init_issuance(program_id, accounts, reserve_total, start_ts, maturity_ts, params),
             LockrionInstruction::FundReserve { amount } => Self::fund_reserve(program_id, accounts, amount),
             LockrionInstruction::Deposit { amount } => Self::deposit(program_id, accounts, amount),
+            LockrionInstruction::DepositFor { beneficiary, amount } =>
+            Self::deposit_for(program_id, accounts, beneficiary, amount),
+            LockrionInstruction::DepositWithTerms { amount, terms_hash } =>
+            Self::deposit_with_terms(program_id, accounts, amount, terms_hash, 0),
+            LockrionInstruction::DepositWithTier { amount, lock_tier, terms_hash } =>
+            Self::deposit_with_tier(program_id, accounts, amount, lock_tier, terms_hash),
+            LockrionInstruction::TransferPositionWithTerms { terms_hash } =>
+            Self::transfer_position_with_terms(program_id, accounts, terms_hash),
             LockrionInstruction::ClaimReward => Self::claim_reward(program_id, accounts),
             LockrionInstruction::WithdrawDeposit => Self::withdraw_deposit(program_id, accounts),
             LockrionInstruction::Sweep => Self::sweep(program_id, accounts),
             LockrionInstruction::ZeroParticipationReclaim => Self::reclaim(program_id, accounts),
+            LockrionInstruction::ClaimRewardBatch => Self::claim_reward_batch(program_id, accounts),
+            LockrionInstruction::Settle => Self::settle(program_id, accounts),
+            LockrionInstruction::TransferPosition => Self::transfer_position(program_id, accounts, None),
+            LockrionInstruction::Rollover { terms_hash, lock_tier } =>
+                Self::rollover(program_id, accounts, terms_hash, lock_tier),
+            LockrionInstruction::SyncPosition => Self::sync_position(program_id, accounts),
+            LockrionInstruction::MigrateState => Self::migrate_state(program_id, accounts),
+            LockrionInstruction::Quote => Self::quote(program_id, accounts),
         }
     }
 
@@ -71,7 +96,7 @@ impl Processor {
         if issuance_ai.owner != program_id {
             return Err(LockrionError::InvalidEscrowAccount.into());
         }
-        let mut issuance = IssuanceState::unpack(&issuance_ai.try_borrow_data()?)?;
+        let mut issuance = IssuanceState::load(&issuance_ai.try_borrow_data()?)?;
 
         // PDA validation (issuance account address MUST equal canonical PDA)
         let (issuance_pda, bump) = pda::derive_issuance_pda(program_id, &issuance.issuer_address, issuance.start_ts, issuance.reserve_total);
@@ -102,6 +127,10 @@ impl Processor {
         }
 
         // Validate reward escrow is correct and mint matches reward_mint
+        // (with lock_mint == reward_mint the deposit escrow would pass the mint check)
+        if reward_escrow_ai.key != &issuance.reward_escrow {
+            return Err(LockrionError::InvalidEscrowAccount.into());
+        }
         Self::validate_token_account_mint(reward_escrow_ai, &issuance.reward_mint)?;
         Self::validate_token_account_mint(issuer_reward_ata_ai, &issuance.reward_mint)?;
 
@@ -133,17 +162,107 @@ impl Processor {
     }
 
     // ---------------------------------------------------------------------
-    // deposit(amount)
+    // deposit(amount) / deposit_for(beneficiary, amount)
     // Accounts:
     // 0 [writable] issuance_state (PDA)
-    // 1 [writable] user_state (PDA)        (may be uninitialized; created here)
-    // 2 [signer]   participant             (payer for UserState creation)
-    // 3 [writable] participant_lock_ata
+    // 1 [writable] user_state (PDA of beneficiary; may be uninitialized; created here)
+    // 2 [signer]   funder                  (token authority; default payer for UserState creation)
+    // 3 [writable] funder_lock_ata
     // 4 [writable] deposit_escrow
     // 5 []         token_program
     // 6 []         system_program
+    // receipt mode only:
+    // 7 [writable] receipt_mint (PDA)
+    // 8 [writable] beneficiary_receipt_ata
+    // attestation gate only:
+    // 7|9 []       attestation (owned by attestation_program, subject = beneficiary)
+    // optional (after the above):
+    // 7..10 [signer, writable] rent_sponsor (pays UserState rent instead of the funder)
+    //
+    // native lock_mint (wrapped SOL): 3 may be the funder itself; lamports are then
+    // moved into the wSOL deposit_escrow and synced (no funder token account needed).
+    //
+    // deposit_with_terms / deposit_with_tier with terms_hash only (last account, after all of the above):
+    // n [] issuance metadata (PDA [metadata, issuance])
+    //
+    // deposit: beneficiary = funder. deposit_for: the position belongs to the
+    // beneficiary (only they can claim / withdraw). Whoever pays the rent is
+    // recorded as rent_payer. Issuances with terms (metadata) only accept
+    // deposits carrying the terms hash, so every depositor has acknowledged it.
+    // Every deposit but deposit_with_tier selects lock tier 0; a position keeps
+    // its tier until it is emptied. On tiered issuances the tier is the
+    // beneficiary's choice: deposit_for only tops up a position that already
+    // holds principal, in that position's tier.
     // ---------------------------------------------------------------------
     fn deposit(program_id: &Pubkey, accounts: &[AccountInfo], amount: u64) -> ProgramResult {
+        Self::deposit_into(program_id, accounts, None, amount, None, 0)
+    }
+
+    fn deposit_for(program_id: &Pubkey, accounts: &[AccountInfo], beneficiary: Pubkey, amount: u64) -> ProgramResult {
+        Self::deposit_into(program_id, accounts, Some(beneficiary), amount, None, 0)
+    }
+
+    fn deposit_with_tier(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        amount: u64,
+        lock_tier: u8,
+        terms_hash: Option<[u8; 32]>,
+    ) -> ProgramResult {
+        match terms_hash {
+            Some(terms_hash) => Self::deposit_with_terms(program_id, accounts, amount, terms_hash, lock_tier),
+            None => Self::deposit_into(program_id, accounts, None, amount, None, lock_tier),
+        }
+    }
+
+    fn deposit_with_terms(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        amount: u64,
+        terms_hash: [u8; 32],
+        lock_tier: u8,
+    ) -> ProgramResult {
+        let (metadata_ai, accounts) = accounts
+            .split_last()
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        let issuance_ai = accounts.first().ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+        // Metadata is bound to the issuance by its PDA; deposit_into validates the issuance
+        Self::verify_terms(program_id, issuance_ai.key, metadata_ai, &terms_hash)?;
+
+        Self::deposit_into(program_id, accounts, None, amount, Some(terms_hash), lock_tier)
+    }
+
+    // Checks an acknowledgment against the metadata PDA of `issuance`. An all-zero
+    // hash is the "none acknowledged" marker, never a valid acknowledgment.
+    fn verify_terms(
+        program_id: &Pubkey,
+        issuance: &Pubkey,
+        metadata_ai: &AccountInfo,
+        terms_hash: &[u8; 32],
+    ) -> ProgramResult {
+        if terms_hash == &[0u8; 32] {
+            return Err(LockrionError::TermsNotAcknowledged.into());
+        }
+        let (metadata_pda, _) = pda::derive_metadata_pda(program_id, issuance);
+        if metadata_ai.key != &metadata_pda || metadata_ai.owner != program_id {
+            return Err(LockrionError::InvalidPda.into());
+        }
+        let metadata = IssuanceMetadata::unpack(&metadata_ai.try_borrow_data()?)?;
+        if &metadata.terms_hash != terms_hash {
+            return Err(LockrionError::TermsHashMismatch.into());
+        }
+        Ok(())
+    }
+
+    fn deposit_into(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        beneficiary: Option<Pubkey>,
+        amount: u64,
+        terms_hash: Option<[u8; 32]>,
+        lock_tier: u8,
+    ) -> ProgramResult {
         if amount == 0 {
             return Err(LockrionError::InvalidAmount.into());
         }
@@ -151,13 +270,15 @@ impl Processor {
         let acc_iter = &mut accounts.iter();
         let issuance_ai = next_account_info(acc_iter)?;
         let user_state_ai = next_account_info(acc_iter)?;
-        let participant_ai = next_account_info(acc_iter)?;
-        let participant_lock_ata_ai = next_account_info(acc_iter)?;
+        let funder_ai = next_account_info(acc_iter)?;
+        let funder_lock_ata_ai = next_account_info(acc_iter)?;
         let deposit_escrow_ai = next_account_info(acc_iter)?;
         let token_program_ai = next_account_info(acc_iter)?;
 
         let system_program_ai = next_account_info(acc_iter)?;
 
+        let beneficiary = beneficiary.unwrap_or(*funder_ai.key);
+
         if system_program_ai.key != &system_program::ID {
            return Err(LockrionError::InvalidInstruction.into());
         }
@@ -167,7 +288,7 @@ impl Processor {
         if issuance_ai.owner != program_id {
             return Err(LockrionError::InvalidEscrowAccount.into());
         }
-        let mut issuance = IssuanceState::unpack(&issuance_ai.try_borrow_data()?)?;
+        let mut issuance = IssuanceState::load(&issuance_ai.try_borrow_data()?)?;
 
         // Validate issuance PDA
         let (issuance_pda, bump) = pda::derive_issuance_pda(program_id, &issuance.issuer_address, issuance.start_ts, issuance.reserve_total);
@@ -180,6 +301,10 @@ impl Processor {
             return Err(LockrionError::ReserveNotFunded.into());
         }
 
+        if issuance.is_terms_required() && terms_hash.is_none() {
+            return Err(LockrionError::TermsNotAcknowledged.into());
+        }
+
         let now = Self::now_ts();
         if now < issuance.start_ts {
             return Err(LockrionError::DepositWindowNotStarted.into());
@@ -194,79 +319,90 @@ impl Processor {
             return Err(LockrionError::InvalidEscrowAccount.into());
         }
         Self::validate_token_account_mint(deposit_escrow_ai, &issuance.lock_mint)?;
-        Self::validate_token_account_mint(participant_lock_ata_ai, &issuance.lock_mint)?;
+        let wrap_lamports = issuance.is_native_lock() && funder_lock_ata_ai.key == funder_ai.key;
+        if !wrap_lamports {
+            Self::validate_token_account_mint(funder_lock_ata_ai, &issuance.lock_mint)?;
+        }
         Self::validate_token_account_authority(deposit_escrow_ai, &issuance_pda)?;
 
-        // Validate user_state PDA for (issuance, participant)
-        let (user_pda, user_bump) = pda::derive_user_pda(program_id, &issuance_pda, participant_ai.key);
+        // Validate user_state PDA for (issuance, beneficiary)
+        let (user_pda, user_bump) = pda::derive_user_pda(program_id, &issuance_pda, &beneficiary);
         if user_state_ai.key != &user_pda {
             return Err(LockrionError::InvalidPda.into());
         }
-        // If user_state is not initialized yet — create it (payer = participant)
+        // Attestation, then the optional rent sponsor, trail the (mode-dependent) fixed accounts
+        let mut sponsor_index = if issuance.is_receipt_mode() { 9 } else { 7 };
+        if issuance.is_attestation_required() {
+            let attestation_ai = accounts.get(sponsor_index).ok_or(ProgramError::NotEnoughAccountKeys)?;
+            attestation::verify(&issuance, attestation_ai, &beneficiary, now)?;
+            sponsor_index += 1;
+        }
+        let rent_payer_ai = match accounts.get(sponsor_index) {
+            Some(sponsor_ai) => {
+                if !sponsor_ai.is_signer {
+                    return Err(LockrionError::UnauthorizedCaller.into());
+                }
+                sponsor_ai
+            }
+            None => funder_ai,
+        };
+
+        // If user_state is not initialized yet — create it (payer = rent_payer)
         Self::create_user_state_if_needed(
           program_id,
           &issuance_pda,
           user_state_ai,
-          participant_ai,
+          &beneficiary,
+          rent_payer_ai,
+          system_program_ai,
           user_bump,
         )?;
-
-        // If freshly created or uninitialized: initialize EXACT bytes per State Layout v1.1
-        // UserState layout offsets:
-        // 0 version(u8)=1
-        // 1 bump(u8)
-        // 2 issuance(Pubkey)[32]
-        // 34 participant(Pubkey)[32]
-        // 66 locked_amount(u128)=0
-        // 82 user_weight_accum(u128)=0
-        // 98 user_last_day_index(u64)=issuance.last_day_index
-        // 106 reward_claimed(u8)=0
-        // 107..111 padding[5]=0
-    {
-    let mut d = user_state_ai.try_borrow_mut_data()?;
-
-    // initialize only if version != 1 (fresh account will be all-zero)
-    if d[0] != 1 {
-        // version
-        d[0] = 1;
-        // bump
-        d[1] = user_bump;
-
-        // issuance pubkey bytes
-        d[2..34].copy_from_slice(issuance_ai.key.as_ref());
-
-        // participant pubkey bytes
-        d[34..66].copy_from_slice(participant_ai.key.as_ref());
-
-        // locked_amount + user_weight_accum already zero (leave as-is)
-        // user_last_day_index = issuance.last_day_index
-        d[98..106].copy_from_slice(&issuance.last_day_index.to_le_bytes());
-
-        // reward_claimed = 0 (leave)
-        // padding = 0 (leave)
-    }
-}
+        let first_deposit = Self::init_user_state_if_needed(
+            user_state_ai,
+            issuance_ai.key,
+            &beneficiary,
+            user_bump,
+            issuance.last_day_index,
+            rent_payer_ai.key,
+        )?;
 
         // Load user state (assumes already created/initialized by separate init instruction OR off-chain create)
         // NOTE: v1 spec set exposes only 6 instructions; значит UserState должен существовать заранее,
         // либо создаётся вне контракта. Мы здесь только читаем/пишем. :contentReference[oaicite:5]{index=5}
-        let mut user = UserState::unpack(&user_state_ai.try_borrow_data()?)?;
+        let mut user = UserState::load(&user_state_ai.try_borrow_data()?)?;
         if user.bump != user_bump {
             return Err(LockrionError::InvalidPda.into());
         }
         // Cross-binding guards
-        if &user.issuance != issuance_ai.key || &user.participant != participant_ai.key {
+        if &user.issuance != issuance_ai.key || user.participant != beneficiary {
             return Err(LockrionError::InvalidUserStateAccount.into());
         }
-        if !participant_ai.is_signer {
+        if !funder_ai.is_signer {
             return Err(LockrionError::UnauthorizedCaller.into());
         }
 
+        // A third party cannot pick (or pin) the beneficiary's tier
+        let lock_tier = if beneficiary != *funder_ai.key && issuance.has_lock_tiers() {
+            if user.locked_amount == 0 {
+                return Err(LockrionError::InvalidLockTier.into());
+            }
+            user.lock_tier
+        } else {
+            lock_tier
+        };
+
+        // A position holds one lock tier; it can only be re-chosen once emptied
+        let tier = accumulator::lock_tier(&issuance.lock_tiers, lock_tier)?;
+        if user.locked_amount != 0 && user.lock_tier != lock_tier {
+            return Err(LockrionError::InvalidLockTier.into());
+        }
+
         // 1) accumulator update (global then user) BEFORE mutation :contentReference[oaicite:6]{index=6}
         Self::apply_accumulators(&mut issuance, &mut user, now)?;
 
         // 2) state mutation BEFORE CPI transfer (defensive order) :contentReference[oaicite:7]{index=7}
         let amt_u128 = u128::from(amount);
+        user.lock_tier = lock_tier;
         issuance.total_locked = issuance
             .total_locked
             .checked_add(amt_u128)
@@ -276,18 +412,93 @@ impl Processor {
             .checked_add(amt_u128)
             .ok_or(LockrionError::ArithmeticOverflow)?;
 
+        // early-bird multiplier by deposit day, then the lock tier multiplier;
+        // identical increment on both sides
+        let mult_bps = accumulator::multiplier_bps(&issuance.multiplier_schedule, issuance.last_day_index);
+        let weighted = accumulator::weighted_amount(amt_u128, mult_bps)?;
+        let weighted = accumulator::weighted_amount(weighted, tier.mult_bps)?;
+        issuance.total_weighted_locked = issuance
+            .total_weighted_locked
+            .checked_add(weighted)
+            .ok_or(LockrionError::ArithmeticOverflow)?;
+        user.weighted_locked = user
+            .weighted_locked
+            .checked_add(weighted)
+            .ok_or(LockrionError::ArithmeticOverflow)?;
+
+        if let Some(terms_hash) = terms_hash {
+            user.terms_hash = terms_hash;
+            user.terms_ack_ts = now;
+        }
+
+        if first_deposit {
+            issuance.participant_count = issuance
+                .participant_count
+                .checked_add(1)
+                .ok_or(LockrionError::ArithmeticOverflow)?;
+        }
+
         issuance.pack(&mut issuance_ai.try_borrow_mut_data()?)?;
         user.pack(&mut user_state_ai.try_borrow_mut_data()?)?;
 
-        // 3) CPI transfer participant -> deposit_escrow (participant signs)
-        Self::spl_transfer(
-            token_program_ai,
-            participant_lock_ata_ai,
-            deposit_escrow_ai,
-            participant_ai,
-            &[],
-            amount,
-        )?;
+        // 3) CPI transfer funder -> deposit_escrow (funder signs)
+        if wrap_lamports {
+            Self::wrap_native(token_program_ai, system_program_ai, funder_ai, deposit_escrow_ai, amount)?;
+        } else {
+            Self::spl_transfer(
+                token_program_ai,
+                funder_lock_ata_ai,
+                deposit_escrow_ai,
+                funder_ai,
+                &[],
+                amount,
+            )?;
+        }
+
+        // 4) receipt mode: mint 1:1 receipts to beneficiary (issuance PDA signs)
+        if issuance.is_receipt_mode() {
+            let receipt_mint_ai = next_account_info(acc_iter)?;
+            let beneficiary_receipt_ata_ai = next_account_info(acc_iter)?;
+            Self::validate_receipt_accounts(
+                program_id,
+                &issuance,
+                &issuance_pda,
+                receipt_mint_ai,
+                beneficiary_receipt_ata_ai,
+                &beneficiary,
+            )?;
+
+            let start_ts_le = issuance.start_ts.to_le_bytes();
+            let reserve_total_le = issuance.reserve_total.to_le_bytes();
+            let bump_seed = [issuance.bump];
+
+            let seeds: &[&[u8]] = &[
+                pda::SEED_ISSUANCE,
+                issuance.issuer_address.as_ref(),
+                &start_ts_le,
+                &reserve_total_le,
+                &bump_seed,
+            ];
+
+            let ix = spl_token::instruction::mint_to(
+                token_program_ai.key,
+                receipt_mint_ai.key,
+                beneficiary_receipt_ata_ai.key,
+                issuance_ai.key,
+                &[] as &[&Pubkey],
+                amount,
+            )?;
+            invoke_signed(
+                &ix,
+                &[
+                    receipt_mint_ai.clone(),
+                    beneficiary_receipt_ata_ai.clone(),
+                    issuance_ai.clone(),
+                    token_program_ai.clone(),
+                ],
+                &[seeds],
+            )?;
+        }
 
         Ok(())
     }
@@ -316,8 +527,8 @@ impl Processor {
         }
     
         // Load states
-        let mut issuance = IssuanceState::unpack(&issuance_ai.try_borrow_data()?)?;
-        let mut user = UserState::unpack(&user_state_ai.try_borrow_data()?)?;
+        let mut issuance = IssuanceState::load(&issuance_ai.try_borrow_data()?)?;
+        let mut user = UserState::load(&user_state_ai.try_borrow_data()?)?;
     
         // Validate issuance PDA
         let (issuance_pda, bump) = pda::derive_issuance_pda(
@@ -357,16 +568,13 @@ impl Processor {
         if now < issuance.maturity_ts {
             return Err(LockrionError::ClaimWindowNotStarted.into());
         }
-        let claim_end = issuance
-            .maturity_ts
-            .checked_add(issuance.claim_window)
-            .ok_or(LockrionError::ArithmeticOverflow)?;
+        let claim_end = Self::claim_end_ts(&issuance)?;
         if now >= claim_end {
             return Err(LockrionError::ClaimWindowClosed.into());
         }
     
-        // User flag check
-        if user.is_reward_claimed() {
+        // User flag check (with vesting, claim repeats until fully released)
+        if user.is_reward_settled() {
             return Err(LockrionError::AlreadyClaimed.into());
         }
     
@@ -377,26 +585,11 @@ impl Processor {
             return Err(LockrionError::NoParticipation.into());
         }
     
-        // SPL token amounts are u64; enforce representability deterministically
-        if issuance.reserve_total > (u64::MAX as u128) {
-            return Err(LockrionError::InvariantViolation.into());
-        }
-    
-        // reward = reserve_total * user_weight_accum / total_weight_accum  (u128 checked)
-        let numerator = issuance
-            .reserve_total
-            .checked_mul(user.user_weight_accum)
-            .ok_or(LockrionError::ArithmeticOverflow)?;
-        let reward_u128 = numerator
-            .checked_div(issuance.total_weight_accum)
-            .ok_or(LockrionError::DivisionByZero)?;
-        if reward_u128 > (u64::MAX as u128) {
-            return Err(LockrionError::ArithmeticOverflow.into());
+        // Defensive order: flag + withdrawn counter set BEFORE transfer :contentReference[oaicite:3]{index=3}
+        let reward_u64 = Self::release_reward(&mut issuance, &mut user, now)?;
+        if reward_u64 == 0 && !user.is_reward_settled() {
+            return Err(LockrionError::NothingVested.into());
         }
-        let reward_u64 = reward_u128 as u64;
-    
-        // Defensive order: set flag BEFORE transfer :contentReference[oaicite:3]{index=3}
-        user.reward_claimed = 1;
     
         // Persist state BEFORE CPI (atomic if CPI fails) :contentReference[oaicite:4]{index=4}
         issuance.pack(&mut issuance_ai.try_borrow_mut_data()?)?;
@@ -428,6 +621,172 @@ impl Processor {
         Ok(())
     }
     
+    // ---------------------------------------------------------------------
+    // claim_reward_batch()
+    // Accounts:
+    // 0 [writable] issuance_state (PDA)
+    // 1 [writable] reward_escrow
+    // 2 []         token_program
+    // 3.. pairs:   [writable] user_state (PDA), [writable] participant_reward_ata
+    //
+    // Permissionless crank: rewards can only go to a reward_mint account owned
+    // by the bound participant. Global accumulator is finalized once; every pair
+    // is fully validated (any invalid pair fails the whole batch), fully released
+    // users and users with nothing newly vested are skipped. Return data: bitmap, bit i (LSB first) = pair i paid here.
+    // ---------------------------------------------------------------------
+    fn claim_reward_batch(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let acc_iter = &mut accounts.iter();
+        let issuance_ai = next_account_info(acc_iter)?;
+        let reward_escrow_ai = next_account_info(acc_iter)?;
+        let token_program_ai = next_account_info(acc_iter)?;
+
+        let pairs = acc_iter.as_slice();
+        if pairs.is_empty() || !pairs.len().is_multiple_of(2) {
+            return Err(LockrionError::InvalidInstruction.into());
+        }
+
+        Self::validate_token_program(token_program_ai)?;
+
+        if issuance_ai.owner != program_id {
+            return Err(LockrionError::InvalidEscrowAccount.into());
+        }
+
+        let mut issuance = IssuanceState::load(&issuance_ai.try_borrow_data()?)?;
+
+        // Validate issuance PDA
+        let (issuance_pda, bump) = pda::derive_issuance_pda(
+            program_id,
+            &issuance.issuer_address,
+            issuance.start_ts,
+            issuance.reserve_total,
+        );
+        if issuance_ai.key != &issuance_pda || issuance.bump != bump {
+            return Err(LockrionError::InvalidPda.into());
+        }
+
+        // Escrow must match stored reward_escrow, mint + authority
+        if reward_escrow_ai.key != &issuance.reward_escrow {
+            return Err(LockrionError::InvalidEscrowAccount.into());
+        }
+        Self::validate_token_account_mint(reward_escrow_ai, &issuance.reward_mint)?;
+        Self::validate_token_account_authority(reward_escrow_ai, &issuance_pda)?;
+
+        // Window checks (same as claim_reward)
+        let now = Self::now_ts();
+        if now < issuance.maturity_ts {
+            return Err(LockrionError::ClaimWindowNotStarted.into());
+        }
+        let claim_end = Self::claim_end_ts(&issuance)?;
+        if now >= claim_end {
+            return Err(LockrionError::ClaimWindowClosed.into());
+        }
+
+        // Finalize global accumulator ONCE for the whole batch
+        let current = Self::finalize_global(&mut issuance, now)?;
+        if issuance.total_weight_accum == 0 {
+            return Err(LockrionError::NoParticipation.into());
+        }
+        issuance.pack(&mut issuance_ai.try_borrow_mut_data()?)?;
+
+        let issuer_address = issuance.issuer_address;
+        let start_ts_le = issuance.start_ts.to_le_bytes();
+        let reserve_total_le = issuance.reserve_total.to_le_bytes();
+        let bump_seed = [issuance.bump];
+
+        let seeds: &[&[u8]] = &[
+            pda::SEED_ISSUANCE,
+            issuer_address.as_ref(),
+            &start_ts_le,
+            &reserve_total_le,
+            &bump_seed,
+        ];
+        let signer_seeds: &[&[&[u8]]] = &[seeds];
+
+        let mut bitmap = vec![0u8; (pairs.len() / 2).div_ceil(8)];
+
+        for (i, pair) in pairs.chunks_exact(2).enumerate() {
+            let user_state_ai = &pair[0];
+            let participant_reward_ata_ai = &pair[1];
+
+            if user_state_ai.owner != program_id {
+                return Err(LockrionError::InvalidUserStateAccount.into());
+            }
+            let mut user = UserState::load(&user_state_ai.try_borrow_data()?)?;
+
+            // Validate user PDA + binding (participant taken from state, re-derived)
+            let (user_pda, user_bump) = pda::derive_user_pda(program_id, &issuance_pda, &user.participant);
+            if user_state_ai.key != &user_pda || user.bump != user_bump {
+                return Err(LockrionError::InvalidPda.into());
+            }
+            if &user.issuance != issuance_ai.key {
+                return Err(LockrionError::InvalidUserStateAccount.into());
+            }
+
+            // Destination must be a reward_mint account owned by the participant
+            Self::validate_token_account_mint(participant_reward_ata_ai, &issuance.reward_mint)?;
+            Self::validate_token_account_authority(participant_reward_ata_ai, &user.participant)?;
+
+            // Deterministic skip (also covers a user listed twice in one batch)
+            if user.is_reward_settled() {
+                continue;
+            }
+
+            Self::update_user_with_current(&issuance, &mut user, current)?;
+            let mut next = issuance.clone();
+            let reward_u64 = Self::release_reward(&mut next, &mut user, now)?;
+
+            // Nothing newly vested since the last release: leave both untouched
+            if reward_u64 == 0 && !user.is_reward_settled() {
+                continue;
+            }
+            issuance = next;
+
+            // Defensive order: flag + persist BEFORE transfer
+            issuance.pack(&mut issuance_ai.try_borrow_mut_data()?)?;
+            user.pack(&mut user_state_ai.try_borrow_mut_data()?)?;
+
+            if reward_u64 > 0 {
+                Self::spl_transfer(
+                    token_program_ai,
+                    reward_escrow_ai,
+                    participant_reward_ata_ai,
+                    issuance_ai,      // PDA authority
+                    signer_seeds,     // PDA signs
+                    reward_u64,
+                )?;
+            }
+
+            bitmap[i / 8] |= 1u8 << (i % 8);
+        }
+
+        set_return_data(&bitmap);
+
+        Ok(())
+    }
+    
+    // ---------------------------------------------------------------------
+    // withdraw_deposit()
+    // Accounts:
+    // 0 [writable] issuance_state (PDA)
+    // 1 [writable] user_state (PDA)
+    // 2 [signer]   participant
+    // 3 [writable] participant_lock_ata
+    // 4 [writable] deposit_escrow
+    // 5 []         token_program
+    // early (lock tier unlock) withdrawal only:
+    // 6 [writable] issuer_lock_ata         (receives the tier penalty)
+    // receipt mode only (InitIssuance never pairs it with lock tiers; were both
+    // set, these would follow issuer_lock_ata at 7 / 8):
+    // 6 [writable] receipt_mint (PDA)
+    // 7 [writable] participant_receipt_ata (burned 1:1, full principal)
+    //
+    // native lock_mint (wrapped SOL): participant_lock_ata is a wSOL account of the
+    // participant (signer, writable); it is closed after the transfer, so the
+    // principal (and that account's rent) arrives as lamports.
+    // Before maturity only a lock tier with unlock_day > 0 may withdraw, from that
+    // day_index on; penalty_bps of the principal goes to the issuer. Weight
+    // accrued until then stays claimable.
+    // ---------------------------------------------------------------------
     fn withdraw_deposit(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
         let acc_iter = &mut accounts.iter();
         let issuance_ai = next_account_info(acc_iter)?;
@@ -450,8 +809,8 @@ impl Processor {
         }
     
         // Load state
-        let mut issuance = IssuanceState::unpack(&issuance_ai.try_borrow_data()?)?;
-        let mut user = UserState::unpack(&user_state_ai.try_borrow_data()?)?;
+        let mut issuance = IssuanceState::load(&issuance_ai.try_borrow_data()?)?;
+        let mut user = UserState::load(&user_state_ai.try_borrow_data()?)?;
     
         // Validate issuance PDA (canonical seeds)
         let (issuance_pda, bump) = pda::derive_issuance_pda(
@@ -481,16 +840,24 @@ impl Processor {
         Self::validate_token_account_mint(participant_lock_ata_ai, &issuance.lock_mint)?;
         Self::validate_token_account_authority(deposit_escrow_ai, &issuance_pda)?;
     
-        // Time gate: only after maturity
-        let now = Self::now_ts();
-        if now < issuance.maturity_ts {
-            return Err(LockrionError::DepositWindowNotClosed.into());
-        }
-    
         // Must have something to withdraw
         if user.locked_amount == 0 {
             return Err(LockrionError::InvalidAmount.into());
         }
+
+        // Time gate: after maturity, or from the lock tier's unlock day (less its penalty)
+        let now = Self::now_ts();
+        let mut penalty_u128 = 0u128;
+        let mut issuer_lock_ata_ai = None;
+        if now < issuance.maturity_ts {
+            penalty_u128 = Self::early_unlock_penalty(&issuance, &user, now)?
+                .ok_or(LockrionError::DepositWindowNotClosed)?;
+
+            let penalty_ai = next_account_info(acc_iter)?;
+            Self::validate_token_account_mint(penalty_ai, &issuance.lock_mint)?;
+            Self::validate_token_account_authority(penalty_ai, &issuance.issuer_address)?;
+            issuer_lock_ata_ai = Some(penalty_ai);
+        }
     
         // Canonical order: finalize accumulators (global then user) BEFORE clearing locked_amount :contentReference[oaicite:1]{index=1} :contentReference[oaicite:2]{index=2}
         Self::apply_accumulators(&mut issuance, &mut user, now)?;
@@ -498,13 +865,22 @@ impl Processor {
         // Defensive mutation-before-transfer:
         // amount = user.locked_amount; total_locked -= amount; user.locked_amount = 0 :contentReference[oaicite:3]{index=3}
         let amount_u128 = user.locked_amount;
+        let payout_u128 = amount_u128
+            .checked_sub(penalty_u128)
+            .ok_or(LockrionError::ArithmeticUnderflow)?;
     
         issuance.total_locked = issuance
             .total_locked
             .checked_sub(amount_u128)
             .ok_or(LockrionError::ArithmeticUnderflow)?;
+        issuance.total_weighted_locked = issuance
+            .total_weighted_locked
+            .checked_sub(user.weighted_locked)
+            .ok_or(LockrionError::ArithmeticUnderflow)?;
     
         user.locked_amount = 0;
+        user.weighted_locked = 0;
+        Self::record_withdrawal(&mut issuance, amount_u128)?;
     
         // Persist state before CPI (atomic revert on CPI failure) :contentReference[oaicite:4]{index=4}
         issuance.pack(&mut issuance_ai.try_borrow_mut_data()?)?;
@@ -515,6 +891,19 @@ impl Processor {
             return Err(LockrionError::ArithmeticOverflow.into());
         }
         let amount_u64 = amount_u128 as u64;
+
+        // receipt mode: burn receipts 1:1 (participant signs)
+        if issuance.is_receipt_mode() {
+            Self::burn_receipts(
+                program_id,
+                &issuance,
+                &issuance_pda,
+                acc_iter,
+                participant_ai,
+                token_program_ai,
+                amount_u64,
+            )?;
+        }
     
         // invoke_signed (deposit_escrow -> participant_lock_ata), authority = issuance PDA
         let start_ts_le = issuance.start_ts.to_le_bytes();
@@ -529,6 +918,19 @@ impl Processor {
             &bump_seed,
         ];
         let signer_seeds: &[&[&[u8]]] = &[seeds];
+
+        if let Some(issuer_lock_ata_ai) = issuer_lock_ata_ai {
+            if penalty_u128 > 0 {
+                Self::spl_transfer(
+                    token_program_ai,
+                    deposit_escrow_ai,
+                    issuer_lock_ata_ai,
+                    issuance_ai,
+                    signer_seeds,
+                    penalty_u128 as u64,
+                )?;
+            }
+        }
     
         Self::spl_transfer(
             token_program_ai,
@@ -536,28 +938,62 @@ impl Processor {
             participant_lock_ata_ai,
             issuance_ai,      // PDA authority
             signer_seeds,     // PDA signs
-            amount_u64,
+            payout_u128 as u64,
         )?;
+
+        if issuance.is_native_lock() {
+            Self::unwrap_native(token_program_ai, participant_lock_ata_ai, participant_ai)?;
+        }
     
         Ok(())
     }
     
-    fn sweep(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    // ---------------------------------------------------------------------
+    // settle()
+    // Accounts:
+    // 0 [writable] issuance_state (PDA)
+    // 1 [writable] user_state (PDA)
+    // 2 [signer]   participant
+    // 3 [writable] participant_lock_ata
+    // 4 [writable] participant_reward_ata
+    // 5 [writable] deposit_escrow
+    // 6 [writable] reward_escrow
+    // 7 []         token_program
+    // receipt mode only (withdraw half burns receipts):
+    // 8 [writable] receipt_mint (PDA)
+    // 9 [writable] participant_receipt_ata
+    //
+    // claim half:    runs if not yet claimed, claim window open, participation > 0
+    // withdraw half: runs if locked_amount > 0 (native lock_mint: unwraps as withdraw_deposit)
+    // Neither applicable -> NothingToSettle.
+    // ---------------------------------------------------------------------
+    fn settle(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
         let acc_iter = &mut accounts.iter();
         let issuance_ai = next_account_info(acc_iter)?;
+        let user_state_ai = next_account_info(acc_iter)?;
+        let participant_ai = next_account_info(acc_iter)?;
+        let participant_lock_ata_ai = next_account_info(acc_iter)?;
+        let participant_reward_ata_ai = next_account_info(acc_iter)?;
+        let deposit_escrow_ai = next_account_info(acc_iter)?;
         let reward_escrow_ai = next_account_info(acc_iter)?;
-        let platform_treasury_ai = next_account_info(acc_iter)?;
         let token_program_ai = next_account_info(acc_iter)?;
-    
+
         Self::validate_token_program(token_program_ai)?;
-    
+
         if issuance_ai.owner != program_id {
             return Err(LockrionError::InvalidEscrowAccount.into());
         }
-    
-        // Load state
-        let mut issuance = IssuanceState::unpack(&issuance_ai.try_borrow_data()?)?;
-    
+        if user_state_ai.owner != program_id {
+            return Err(LockrionError::InvalidUserStateAccount.into());
+        }
+        if !participant_ai.is_signer {
+            return Err(LockrionError::UnauthorizedCaller.into());
+        }
+
+        // Load states
+        let mut issuance = IssuanceState::load(&issuance_ai.try_borrow_data()?)?;
+        let mut user = UserState::load(&user_state_ai.try_borrow_data()?)?;
+
         // Validate issuance PDA
         let (issuance_pda, bump) = pda::derive_issuance_pda(
             program_id,
@@ -568,23 +1004,815 @@ impl Processor {
         if issuance_ai.key != &issuance_pda || issuance.bump != bump {
             return Err(LockrionError::InvalidPda.into());
         }
-    
-        // Validate platform treasury binding (must match stored immutable)
-        if platform_treasury_ai.key != &issuance.platform_treasury {
-            return Err(LockrionError::InvalidPlatformTreasury.into());
+
+        // Validate user PDA + binding
+        let (user_pda, user_bump) = pda::derive_user_pda(program_id, &issuance_pda, participant_ai.key);
+        if user_state_ai.key != &user_pda || user.bump != user_bump {
+            return Err(LockrionError::InvalidPda.into());
+        }
+        if &user.issuance != issuance_ai.key || &user.participant != participant_ai.key {
+            return Err(LockrionError::InvalidUserStateAccount.into());
+        }
+
+        // Escrows must match stored pubkeys, mints + authority
+        if deposit_escrow_ai.key != &issuance.deposit_escrow {
+            return Err(LockrionError::InvalidEscrowAccount.into());
         }
-    
-        // Validate reward escrow matches stored
         if reward_escrow_ai.key != &issuance.reward_escrow {
             return Err(LockrionError::InvalidEscrowAccount.into());
         }
-    
-        // Mint checks (both must be reward_mint)
+        Self::validate_token_account_mint(deposit_escrow_ai, &issuance.lock_mint)?;
+        Self::validate_token_account_mint(participant_lock_ata_ai, &issuance.lock_mint)?;
+        Self::validate_token_account_mint(reward_escrow_ai, &issuance.reward_mint)?;
+        Self::validate_token_account_mint(participant_reward_ata_ai, &issuance.reward_mint)?;
+        Self::validate_token_account_authority(deposit_escrow_ai, &issuance_pda)?;
+        Self::validate_token_account_authority(reward_escrow_ai, &issuance_pda)?;
+
+        // Time gate: only after maturity
+        let now = Self::now_ts();
+        if now < issuance.maturity_ts {
+            return Err(LockrionError::ClaimWindowNotStarted.into());
+        }
+        let claim_end = Self::claim_end_ts(&issuance)?;
+
+        // Finalize accumulators (global then user) BEFORE any decision
+        Self::apply_accumulators(&mut issuance, &mut user, now)?;
+
+        // Defensive mutation-before-transfer for both halves
+        let mut do_claim = !user.is_reward_settled()
+            && now < claim_end
+            && issuance.total_weight_accum != 0;
+        let mut reward_u64 = 0u64;
+        if do_claim {
+            reward_u64 = Self::release_reward(&mut issuance, &mut user, now)?;
+            do_claim = reward_u64 != 0 || user.is_reward_settled();
+        }
+        let do_withdraw = user.locked_amount != 0;
+
+        if !do_claim && !do_withdraw {
+            return Err(LockrionError::NothingToSettle.into());
+        }
+
+        let amount_u128 = user.locked_amount;
+        if do_withdraw {
+            issuance.total_locked = issuance
+                .total_locked
+                .checked_sub(amount_u128)
+                .ok_or(LockrionError::ArithmeticUnderflow)?;
+            issuance.total_weighted_locked = issuance
+                .total_weighted_locked
+                .checked_sub(user.weighted_locked)
+                .ok_or(LockrionError::ArithmeticUnderflow)?;
+            user.locked_amount = 0;
+            user.weighted_locked = 0;
+            Self::record_withdrawal(&mut issuance, amount_u128)?;
+        }
+        if amount_u128 > (u64::MAX as u128) {
+            return Err(LockrionError::ArithmeticOverflow.into());
+        }
+        let amount_u64 = amount_u128 as u64;
+
+        // Persist state before CPI (atomic revert on CPI failure)
+        issuance.pack(&mut issuance_ai.try_borrow_mut_data()?)?;
+        user.pack(&mut user_state_ai.try_borrow_mut_data()?)?;
+
+        let start_ts_le = issuance.start_ts.to_le_bytes();
+        let reserve_total_le = issuance.reserve_total.to_le_bytes();
+        let bump_seed = [issuance.bump];
+
+        let seeds: &[&[u8]] = &[
+            pda::SEED_ISSUANCE,
+            issuance.issuer_address.as_ref(),
+            &start_ts_le,
+            &reserve_total_le,
+            &bump_seed,
+        ];
+        let signer_seeds: &[&[&[u8]]] = &[seeds];
+
+        if do_claim {
+            Self::spl_transfer(
+                token_program_ai,
+                reward_escrow_ai,
+                participant_reward_ata_ai,
+                issuance_ai,      // PDA authority
+                signer_seeds,     // PDA signs
+                reward_u64,
+            )?;
+        }
+
+        if do_withdraw {
+            if issuance.is_receipt_mode() {
+                Self::burn_receipts(
+                    program_id,
+                    &issuance,
+                    &issuance_pda,
+                    acc_iter,
+                    participant_ai,
+                    token_program_ai,
+                    amount_u64,
+                )?;
+            }
+            Self::spl_transfer(
+                token_program_ai,
+                deposit_escrow_ai,
+                participant_lock_ata_ai,
+                issuance_ai,      // PDA authority
+                signer_seeds,     // PDA signs
+                amount_u64,
+            )?;
+            if issuance.is_native_lock() {
+                Self::unwrap_native(token_program_ai, participant_lock_ata_ai, participant_ai)?;
+            }
+        }
+
+        Ok(())
+    }
+    
+    // ---------------------------------------------------------------------
+    // rollover(terms_hash, lock_tier)
+    // Accounts:
+    // 0 [writable] source issuance_state (PDA, matured)
+    // 1 [writable] source user_state (PDA)
+    // 2 [signer, writable] participant     (payer for target UserState creation)
+    // 3 [writable] source deposit_escrow
+    // 4 [writable] target issuance_state (PDA, deposit window open)
+    // 5 [writable] target user_state (PDA) (may be uninitialized; created here)
+    // 6 [writable] target deposit_escrow
+    // 7 []         token_program
+    // 8 []         system_program
+    // target attestation gate only:
+    // 9 []         attestation (owned by attestation_program, subject = participant)
+    // terms_hash only (last account, after all of the above):
+    // +0 []        target metadata (PDA [metadata, target issuance])
+    //
+    // Source side = withdraw_deposit, target side = deposit_with_tier, both
+    // accumulators finalized first; one escrow -> escrow transfer signed by the
+    // source PDA. A target with terms requires terms_hash (recorded on the target
+    // UserState like deposit_with_terms); lock_tier follows the target's tier
+    // rules. Receipt-mode issuances are not supported.
+    // ---------------------------------------------------------------------
+    fn rollover(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        terms_hash: Option<[u8; 32]>,
+        lock_tier: u8,
+    ) -> ProgramResult {
+        let (metadata_ai, accounts) = match terms_hash {
+            Some(_) => {
+                let (metadata_ai, accounts) = accounts
+                    .split_last()
+                    .ok_or(ProgramError::NotEnoughAccountKeys)?;
+                (Some(metadata_ai), accounts)
+            }
+            None => (None, accounts),
+        };
+        let acc_iter = &mut accounts.iter();
+        let source_ai = next_account_info(acc_iter)?;
+        let source_state_ai = next_account_info(acc_iter)?;
+        let participant_ai = next_account_info(acc_iter)?;
+        let source_escrow_ai = next_account_info(acc_iter)?;
+        let target_ai = next_account_info(acc_iter)?;
+        let target_state_ai = next_account_info(acc_iter)?;
+        let target_escrow_ai = next_account_info(acc_iter)?;
+        let token_program_ai = next_account_info(acc_iter)?;
+        let system_program_ai = next_account_info(acc_iter)?;
+
+        Self::validate_token_program(token_program_ai)?;
+        if system_program_ai.key != &system_program::ID {
+            return Err(LockrionError::InvalidInstruction.into());
+        }
+
+        if source_ai.owner != program_id || target_ai.owner != program_id {
+            return Err(LockrionError::InvalidEscrowAccount.into());
+        }
+        if source_state_ai.owner != program_id {
+            return Err(LockrionError::InvalidUserStateAccount.into());
+        }
+        if !participant_ai.is_signer {
+            return Err(LockrionError::UnauthorizedCaller.into());
+        }
+        if source_ai.key == target_ai.key {
+            return Err(LockrionError::InvalidInstruction.into());
+        }
+
+        let mut source = IssuanceState::load(&source_ai.try_borrow_data()?)?;
+        let mut target = IssuanceState::load(&target_ai.try_borrow_data()?)?;
+
+        // Validate both issuance PDAs
+        let (source_pda, source_bump) = pda::derive_issuance_pda(
+            program_id,
+            &source.issuer_address,
+            source.start_ts,
+            source.reserve_total,
+        );
+        if source_ai.key != &source_pda || source.bump != source_bump {
+            return Err(LockrionError::InvalidPda.into());
+        }
+        let (target_pda, target_bump) = pda::derive_issuance_pda(
+            program_id,
+            &target.issuer_address,
+            target.start_ts,
+            target.reserve_total,
+        );
+        if target_ai.key != &target_pda || target.bump != target_bump {
+            return Err(LockrionError::InvalidPda.into());
+        }
+
+        if source.is_receipt_mode() || target.is_receipt_mode() {
+            return Err(LockrionError::ReceiptModeEnabled.into());
+        }
+        match (terms_hash, metadata_ai) {
+            (Some(terms_hash), Some(metadata_ai)) => {
+                Self::verify_terms(program_id, target_ai.key, metadata_ai, &terms_hash)?;
+            }
+            _ if target.is_terms_required() => return Err(LockrionError::TermsNotAcknowledged.into()),
+            _ => {}
+        }
+        if source.lock_mint != target.lock_mint {
+            return Err(LockrionError::InvalidMint.into());
+        }
+
+        // Validate source user PDA + binding
+        let mut user = UserState::load(&source_state_ai.try_borrow_data()?)?;
+        let (user_pda, user_bump) = pda::derive_user_pda(program_id, &source_pda, participant_ai.key);
+        if source_state_ai.key != &user_pda || user.bump != user_bump {
+            return Err(LockrionError::InvalidPda.into());
+        }
+        if &user.issuance != source_ai.key || &user.participant != participant_ai.key {
+            return Err(LockrionError::InvalidUserStateAccount.into());
+        }
+
+        // Validate both escrows (stored key + mint + authority)
+        if source_escrow_ai.key != &source.deposit_escrow || target_escrow_ai.key != &target.deposit_escrow {
+            return Err(LockrionError::InvalidEscrowAccount.into());
+        }
+        Self::validate_token_account_mint(source_escrow_ai, &source.lock_mint)?;
+        Self::validate_token_account_mint(target_escrow_ai, &target.lock_mint)?;
+        Self::validate_token_account_authority(source_escrow_ai, &source_pda)?;
+        Self::validate_token_account_authority(target_escrow_ai, &target_pda)?;
+
+        // Time gates: source matured, target funded and in its deposit window
+        let now = Self::now_ts();
+        if now < source.maturity_ts {
+            return Err(LockrionError::DepositWindowNotClosed.into());
+        }
+        if !target.is_reserve_funded() {
+            return Err(LockrionError::ReserveNotFunded.into());
+        }
+        if now < target.start_ts {
+            return Err(LockrionError::DepositWindowNotStarted.into());
+        }
+        if now >= target.maturity_ts {
+            return Err(LockrionError::DepositWindowClosed.into());
+        }
+        if target.is_attestation_required() {
+            let attestation_ai = next_account_info(acc_iter)?;
+            attestation::verify(&target, attestation_ai, participant_ai.key, now)?;
+        }
+
+        if user.locked_amount == 0 {
+            return Err(LockrionError::InvalidAmount.into());
+        }
+
+        // Target UserState (payer = participant)
+        let (target_user_pda, target_user_bump) = pda::derive_user_pda(program_id, &target_pda, participant_ai.key);
+        if target_state_ai.key != &target_user_pda {
+            return Err(LockrionError::InvalidPda.into());
+        }
+        Self::create_user_state_if_needed(
+            program_id,
+            &target_pda,
+            target_state_ai,
+            participant_ai.key,
+            participant_ai,
+            system_program_ai,
+            target_user_bump,
+        )?;
+        let first_deposit = Self::init_user_state_if_needed(
+            target_state_ai,
+            target_ai.key,
+            participant_ai.key,
+            target_user_bump,
+            target.last_day_index,
+            participant_ai.key,
+        )?;
+        let mut target_user = UserState::load(&target_state_ai.try_borrow_data()?)?;
+        if target_user.bump != target_user_bump {
+            return Err(LockrionError::InvalidPda.into());
+        }
+        if &target_user.issuance != target_ai.key || &target_user.participant != participant_ai.key {
+            return Err(LockrionError::InvalidUserStateAccount.into());
+        }
+
+        // 1) finalize both accumulators BEFORE moving principal
+        Self::apply_accumulators(&mut source, &mut user, now)?;
+        Self::apply_accumulators(&mut target, &mut target_user, now)?;
+
+        // 2) source side: withdraw the whole principal
+        let amount_u128 = user.locked_amount;
+        source.total_locked = source
+            .total_locked
+            .checked_sub(amount_u128)
+            .ok_or(LockrionError::ArithmeticUnderflow)?;
+        source.total_weighted_locked = source
+            .total_weighted_locked
+            .checked_sub(user.weighted_locked)
+            .ok_or(LockrionError::ArithmeticUnderflow)?;
+        user.locked_amount = 0;
+        user.weighted_locked = 0;
+        Self::record_withdrawal(&mut source, amount_u128)?;
+
+        // 3) target side: deposit it into `lock_tier` at the target's current-day multiplier
+        let tier = accumulator::lock_tier(&target.lock_tiers, lock_tier)?;
+        if target_user.locked_amount != 0 && target_user.lock_tier != lock_tier {
+            return Err(LockrionError::InvalidLockTier.into());
+        }
+        target_user.lock_tier = lock_tier;
+        target.total_locked = target
+            .total_locked
+            .checked_add(amount_u128)
+            .ok_or(LockrionError::ArithmeticOverflow)?;
+        target_user.locked_amount = target_user
+            .locked_amount
+            .checked_add(amount_u128)
+            .ok_or(LockrionError::ArithmeticOverflow)?;
+        let mult_bps = accumulator::multiplier_bps(&target.multiplier_schedule, target.last_day_index);
+        let weighted = accumulator::weighted_amount(amount_u128, mult_bps)?;
+        let weighted = accumulator::weighted_amount(weighted, tier.mult_bps)?;
+        target.total_weighted_locked = target
+            .total_weighted_locked
+            .checked_add(weighted)
+            .ok_or(LockrionError::ArithmeticOverflow)?;
+        target_user.weighted_locked = target_user
+            .weighted_locked
+            .checked_add(weighted)
+            .ok_or(LockrionError::ArithmeticOverflow)?;
+        if first_deposit {
+            target.participant_count = target
+                .participant_count
+                .checked_add(1)
+                .ok_or(LockrionError::ArithmeticOverflow)?;
+        }
+        if let Some(terms_hash) = terms_hash {
+            target_user.terms_hash = terms_hash;
+            target_user.terms_ack_ts = now;
+        }
+
+        if amount_u128 > (u64::MAX as u128) {
+            return Err(LockrionError::ArithmeticOverflow.into());
+        }
+        let amount_u64 = amount_u128 as u64;
+
+        // Persist all four states before CPI
+        source.pack(&mut source_ai.try_borrow_mut_data()?)?;
+        user.pack(&mut source_state_ai.try_borrow_mut_data()?)?;
+        target.pack(&mut target_ai.try_borrow_mut_data()?)?;
+        target_user.pack(&mut target_state_ai.try_borrow_mut_data()?)?;
+
+        // 4) invoke_signed (source escrow -> target escrow), authority = source PDA
+        let start_ts_le = source.start_ts.to_le_bytes();
+        let reserve_total_le = source.reserve_total.to_le_bytes();
+        let bump_seed = [source.bump];
+
+        let seeds: &[&[u8]] = &[
+            pda::SEED_ISSUANCE,
+            source.issuer_address.as_ref(),
+            &start_ts_le,
+            &reserve_total_le,
+            &bump_seed,
+        ];
+
+        Self::spl_transfer(
+            token_program_ai,
+            source_escrow_ai,
+            target_escrow_ai,
+            source_ai,
+            &[seeds],
+            amount_u64,
+        )
+    }
+
+    // ---------------------------------------------------------------------
+    // transfer_position()
+    // Accounts:
+    // 0 [writable] issuance_state (PDA)
+    // 1 [writable] source user_state (PDA)
+    // 2 [signer]   participant             (payer for recipient UserState creation)
+    // 3 []         recipient
+    // 4 [writable] recipient user_state (PDA) (may be uninitialized; created here)
+    // 5 []         system_program
+    // attestation gate only:
+    // 6 []         recipient attestation (owned by attestation_program, subject = recipient)
+    // transfer_position_with_terms only (last account, after all of the above):
+    // +0 []        metadata (PDA [metadata, issuance])    (recipient must also sign)
+    //
+    // Issuances with terms only accept recipients that acknowledged the terms hash:
+    // by an earlier deposit_with_terms, or by co-signing transfer_position_with_terms.
+    //
+    // Merge rules (both sides finalized to the same current day first):
+    // - locked_amount, weighted_locked and user_weight_accum are added, totals are unchanged
+    // - an empty recipient adopts the source reward_claimed flag
+    // - otherwise reward_claimed must match (no double claim / lost weight)
+    // - fixed reward_total / reward_withdrawn (vesting) are added as well
+    // - a recipient without locked principal adopts the source lock_tier,
+    //   otherwise both locked positions must share it
+    // Source is zeroed (locked_amount, weighted_locked, user_weight_accum, reward_total, reward_withdrawn).
+    // ---------------------------------------------------------------------
+    fn transfer_position_with_terms(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        terms_hash: [u8; 32],
+    ) -> ProgramResult {
+        let (metadata_ai, accounts) = accounts
+            .split_last()
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        let issuance_ai = accounts.first().ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+        // Metadata is bound to the issuance by its PDA; transfer_position validates the issuance
+        Self::verify_terms(program_id, issuance_ai.key, metadata_ai, &terms_hash)?;
+
+        Self::transfer_position(program_id, accounts, Some(terms_hash))
+    }
+
+    fn transfer_position(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        terms_hash: Option<[u8; 32]>,
+    ) -> ProgramResult {
+        let acc_iter = &mut accounts.iter();
+        let issuance_ai = next_account_info(acc_iter)?;
+        let source_state_ai = next_account_info(acc_iter)?;
+        let participant_ai = next_account_info(acc_iter)?;
+        let recipient_ai = next_account_info(acc_iter)?;
+        let recipient_state_ai = next_account_info(acc_iter)?;
+        let system_program_ai = next_account_info(acc_iter)?;
+
+        if system_program_ai.key != &system_program::ID {
+            return Err(LockrionError::InvalidInstruction.into());
+        }
+
+        if issuance_ai.owner != program_id {
+            return Err(LockrionError::InvalidEscrowAccount.into());
+        }
+        if source_state_ai.owner != program_id {
+            return Err(LockrionError::InvalidUserStateAccount.into());
+        }
+        if !participant_ai.is_signer {
+            return Err(LockrionError::UnauthorizedCaller.into());
+        }
+        if recipient_ai.key == participant_ai.key {
+            return Err(LockrionError::PositionSelfTransfer.into());
+        }
+        // The acknowledgment is the recipient's own
+        if terms_hash.is_some() && !recipient_ai.is_signer {
+            return Err(LockrionError::UnauthorizedCaller.into());
+        }
+
+        let mut issuance = IssuanceState::load(&issuance_ai.try_borrow_data()?)?;
+
+        // Validate issuance PDA
+        let (issuance_pda, bump) = pda::derive_issuance_pda(
+            program_id,
+            &issuance.issuer_address,
+            issuance.start_ts,
+            issuance.reserve_total,
+        );
+        if issuance_ai.key != &issuance_pda || issuance.bump != bump {
+            return Err(LockrionError::InvalidPda.into());
+        }
+
+        // Receipt mode: positions follow receipt tokens (SyncPosition) instead
+        if issuance.is_receipt_mode() {
+            return Err(LockrionError::ReceiptModeEnabled.into());
+        }
+
+        // Gated issuance: the recipient must hold an attestation too
+        if issuance.is_attestation_required() {
+            let attestation_ai = next_account_info(acc_iter)?;
+            attestation::verify(&issuance, attestation_ai, recipient_ai.key, Self::now_ts())?;
+        }
+
+        // Validate source PDA + binding
+        let mut source = UserState::load(&source_state_ai.try_borrow_data()?)?;
+        let (source_pda, source_bump) = pda::derive_user_pda(program_id, &issuance_pda, participant_ai.key);
+        if source_state_ai.key != &source_pda || source.bump != source_bump {
+            return Err(LockrionError::InvalidPda.into());
+        }
+        if &source.issuance != issuance_ai.key || &source.participant != participant_ai.key {
+            return Err(LockrionError::InvalidUserStateAccount.into());
+        }
+
+        // Validate recipient PDA, create + initialize if needed (payer = participant)
+        let (recipient_pda, recipient_bump) = pda::derive_user_pda(program_id, &issuance_pda, recipient_ai.key);
+        if recipient_state_ai.key != &recipient_pda {
+            return Err(LockrionError::InvalidPda.into());
+        }
+        Self::create_user_state_if_needed(
+            program_id,
+            &issuance_pda,
+            recipient_state_ai,
+            recipient_ai.key,
+            participant_ai,
+            system_program_ai,
+            recipient_bump,
+        )?;
+        let new_holder = Self::init_user_state_if_needed(
+            recipient_state_ai,
+            issuance_ai.key,
+            recipient_ai.key,
+            recipient_bump,
+            issuance.last_day_index,
+            participant_ai.key,
+        )?;
+
+        let mut recipient = UserState::load(&recipient_state_ai.try_borrow_data()?)?;
+        if recipient.bump != recipient_bump {
+            return Err(LockrionError::InvalidPda.into());
+        }
+        if &recipient.issuance != issuance_ai.key || &recipient.participant != recipient_ai.key {
+            return Err(LockrionError::InvalidUserStateAccount.into());
+        }
+
+        // Finalize accumulators (global, then both users to the same current day)
+        let now = Self::now_ts();
+
+        // Terms gate: the recipient co-signs the hash now or acknowledged it on deposit
+        if let Some(terms_hash) = terms_hash {
+            recipient.terms_hash = terms_hash;
+            recipient.terms_ack_ts = now;
+        } else if issuance.is_terms_required() && recipient.terms_hash == [0u8; 32] {
+            return Err(LockrionError::TermsNotAcknowledged.into());
+        }
+        let current = Self::finalize_global(&mut issuance, now)?;
+        Self::update_user_with_current(&issuance, &mut source, current)?;
+        Self::update_user_with_current(&issuance, &mut recipient, current)?;
+
+        if source.locked_amount == 0 && source.user_weight_accum == 0 {
+            return Err(LockrionError::InvalidAmount.into());
+        }
+
+        let recipient_empty = recipient.locked_amount == 0
+            && recipient.user_weight_accum == 0
+            && !recipient.is_reward_claimed();
+        if recipient_empty {
+            recipient.reward_claimed = source.reward_claimed;
+        } else if recipient.reward_claimed != source.reward_claimed {
+            return Err(LockrionError::PositionMergeConflict.into());
+        }
+        // Locked principal keeps its tier (unlock gate + penalty)
+        if recipient.locked_amount == 0 {
+            recipient.lock_tier = source.lock_tier;
+        } else if source.locked_amount != 0 && recipient.lock_tier != source.lock_tier {
+            return Err(LockrionError::PositionMergeConflict.into());
+        }
+
+        recipient.locked_amount = recipient
+            .locked_amount
+            .checked_add(source.locked_amount)
+            .ok_or(LockrionError::ArithmeticOverflow)?;
+        recipient.user_weight_accum = recipient
+            .user_weight_accum
+            .checked_add(source.user_weight_accum)
+            .ok_or(LockrionError::ArithmeticOverflow)?;
+        recipient.weighted_locked = recipient
+            .weighted_locked
+            .checked_add(source.weighted_locked)
+            .ok_or(LockrionError::ArithmeticOverflow)?;
+
+        // Fixed entitlement (and its vesting progress) travels with the weight
+        recipient.reward_total = recipient
+            .reward_total
+            .checked_add(source.reward_total)
+            .ok_or(LockrionError::ArithmeticOverflow)?;
+        recipient.reward_withdrawn = recipient
+            .reward_withdrawn
+            .checked_add(source.reward_withdrawn)
+            .ok_or(LockrionError::ArithmeticOverflow)?;
+
+        source.locked_amount = 0;
+        source.user_weight_accum = 0;
+        source.weighted_locked = 0;
+        source.reward_total = 0;
+        source.reward_withdrawn = 0;
+
+        // A freshly created recipient position is a new participant, as on deposit
+        if new_holder {
+            issuance.participant_count = issuance
+                .participant_count
+                .checked_add(1)
+                .ok_or(LockrionError::ArithmeticOverflow)?;
+        }
+
+        issuance.pack(&mut issuance_ai.try_borrow_mut_data()?)?;
+        source.pack(&mut source_state_ai.try_borrow_mut_data()?)?;
+        recipient.pack(&mut recipient_state_ai.try_borrow_mut_data()?)?;
+
+        Ok(())
+    }
+    
+    // ---------------------------------------------------------------------
+    // sync_position()
+    // Accounts:
+    // 0 [writable] issuance_state (PDA)
+    // 1 [writable] from user_state (PDA of from_receipt owner)
+    // 2 []         from_receipt_ata
+    // 3 [writable] to user_state (PDA of to_receipt owner) (may be uninitialized; created here)
+    // 4 []         to_receipt_ata
+    // 5 []         receipt_mint (PDA)
+    // 6 [signer]   payer                   (rent for to user_state creation)
+    // 7 []         system_program
+    //
+    // Receipt mode weight attribution:
+    // - weight accrues to UserState.weighted_locked (== locked_amount here), never to raw token balances
+    // - receipts moved between holders count for the new holder only from the
+    //   day this instruction settles both sides (permissionless; the receiver
+    //   is the one incentivized to call it)
+    // - locked_amount of each side is set to its receipt balance; the pair sum
+    //   must be conserved, so each holder keeps receipts in one account
+    // - claim_reward pays on the UserState weight as usual
+    // ---------------------------------------------------------------------
+    fn sync_position(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let acc_iter = &mut accounts.iter();
+        let issuance_ai = next_account_info(acc_iter)?;
+        let from_state_ai = next_account_info(acc_iter)?;
+        let from_receipt_ai = next_account_info(acc_iter)?;
+        let to_state_ai = next_account_info(acc_iter)?;
+        let to_receipt_ai = next_account_info(acc_iter)?;
+        let receipt_mint_ai = next_account_info(acc_iter)?;
+        let payer_ai = next_account_info(acc_iter)?;
+        let system_program_ai = next_account_info(acc_iter)?;
+
+        if system_program_ai.key != &system_program::ID {
+            return Err(LockrionError::InvalidInstruction.into());
+        }
+
+        if issuance_ai.owner != program_id {
+            return Err(LockrionError::InvalidEscrowAccount.into());
+        }
+        if from_state_ai.owner != program_id {
+            return Err(LockrionError::InvalidUserStateAccount.into());
+        }
+        if !payer_ai.is_signer {
+            return Err(LockrionError::UnauthorizedCaller.into());
+        }
+
+        let mut issuance = IssuanceState::load(&issuance_ai.try_borrow_data()?)?;
+
+        // Validate issuance PDA
+        let (issuance_pda, bump) = pda::derive_issuance_pda(
+            program_id,
+            &issuance.issuer_address,
+            issuance.start_ts,
+            issuance.reserve_total,
+        );
+        if issuance_ai.key != &issuance_pda || issuance.bump != bump {
+            return Err(LockrionError::InvalidPda.into());
+        }
+        if !issuance.is_receipt_mode() {
+            return Err(LockrionError::ReceiptModeDisabled.into());
+        }
+
+        // Holders are the receipt account owners
+        let from_receipt = TokenAccount::unpack(&from_receipt_ai.try_borrow_data()?)?;
+        let to_receipt = TokenAccount::unpack(&to_receipt_ai.try_borrow_data()?)?;
+        Self::validate_receipt_accounts(program_id, &issuance, &issuance_pda, receipt_mint_ai, from_receipt_ai, &from_receipt.owner)?;
+        Self::validate_receipt_accounts(program_id, &issuance, &issuance_pda, receipt_mint_ai, to_receipt_ai, &to_receipt.owner)?;
+        if from_receipt.owner == to_receipt.owner {
+            return Err(LockrionError::PositionSelfTransfer.into());
+        }
+
+        // Validate from PDA + binding
+        let mut from = UserState::load(&from_state_ai.try_borrow_data()?)?;
+        let (from_pda, from_bump) = pda::derive_user_pda(program_id, &issuance_pda, &from_receipt.owner);
+        if from_state_ai.key != &from_pda || from.bump != from_bump {
+            return Err(LockrionError::InvalidPda.into());
+        }
+        if &from.issuance != issuance_ai.key || from.participant != from_receipt.owner {
+            return Err(LockrionError::InvalidUserStateAccount.into());
+        }
+
+        // Validate to PDA, create + initialize if needed (payer = caller)
+        let (to_pda, to_bump) = pda::derive_user_pda(program_id, &issuance_pda, &to_receipt.owner);
+        if to_state_ai.key != &to_pda {
+            return Err(LockrionError::InvalidPda.into());
+        }
+        Self::create_user_state_if_needed(
+            program_id,
+            &issuance_pda,
+            to_state_ai,
+            &to_receipt.owner,
+            payer_ai,
+            system_program_ai,
+            to_bump,
+        )?;
+        Self::init_user_state_if_needed(
+            to_state_ai,
+            issuance_ai.key,
+            &to_receipt.owner,
+            to_bump,
+            issuance.last_day_index,
+            payer_ai.key,
+        )?;
+
+        let mut to = UserState::load(&to_state_ai.try_borrow_data()?)?;
+        if to.bump != to_bump {
+            return Err(LockrionError::InvalidPda.into());
+        }
+        if &to.issuance != issuance_ai.key || to.participant != to_receipt.owner {
+            return Err(LockrionError::InvalidUserStateAccount.into());
+        }
+
+        // Settle weight up to today under the OLD attribution
+        let now = Self::now_ts();
+        let current = Self::finalize_global(&mut issuance, now)?;
+        Self::update_user_with_current(&issuance, &mut from, current)?;
+        Self::update_user_with_current(&issuance, &mut to, current)?;
+
+        // Conservation: the pair re-splits exactly what it already held
+        let locked_sum = from
+            .locked_amount
+            .checked_add(to.locked_amount)
+            .ok_or(LockrionError::ArithmeticOverflow)?;
+        let receipt_sum = u128::from(from_receipt.amount)
+            .checked_add(u128::from(to_receipt.amount))
+            .ok_or(LockrionError::ArithmeticOverflow)?;
+        if locked_sum != receipt_sum {
+            return Err(LockrionError::ReceiptBalanceMismatch.into());
+        }
+
+        // Receipt mode has no multiplier schedule: weighted_locked == locked_amount
+        from.locked_amount = u128::from(from_receipt.amount);
+        to.locked_amount = u128::from(to_receipt.amount);
+        from.weighted_locked = from.locked_amount;
+        to.weighted_locked = to.locked_amount;
+
+        issuance.pack(&mut issuance_ai.try_borrow_mut_data()?)?;
+        from.pack(&mut from_state_ai.try_borrow_mut_data()?)?;
+        to.pack(&mut to_state_ai.try_borrow_mut_data()?)?;
+
+        Ok(())
+    }
+    
+    // ---------------------------------------------------------------------
+    // sweep()
+    // Accounts:
+    // 0 [writable] issuance_state (PDA)
+    // 1 [writable] reward_escrow
+    // 2 [writable] platform_treasury
+    // 3 []         token_program
+    // same-mint (lock_mint == reward_mint) only:
+    // 4 []         deposit_escrow          (must back total_locked; a legacy shared escrow keeps it)
+    // ---------------------------------------------------------------------
+    fn sweep(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let acc_iter = &mut accounts.iter();
+        let issuance_ai = next_account_info(acc_iter)?;
+        let reward_escrow_ai = next_account_info(acc_iter)?;
+        let platform_treasury_ai = next_account_info(acc_iter)?;
+        let token_program_ai = next_account_info(acc_iter)?;
+    
+        Self::validate_token_program(token_program_ai)?;
+    
+        if issuance_ai.owner != program_id {
+            return Err(LockrionError::InvalidEscrowAccount.into());
+        }
+    
+        // Load state
+        let mut issuance = IssuanceState::load(&issuance_ai.try_borrow_data()?)?;
+    
+        // Validate issuance PDA
+        let (issuance_pda, bump) = pda::derive_issuance_pda(
+            program_id,
+            &issuance.issuer_address,
+            issuance.start_ts,
+            issuance.reserve_total,
+        );
+        if issuance_ai.key != &issuance_pda || issuance.bump != bump {
+            return Err(LockrionError::InvalidPda.into());
+        }
+    
+        // Validate platform treasury binding (must match stored immutable)
+        if platform_treasury_ai.key != &issuance.platform_treasury {
+            return Err(LockrionError::InvalidPlatformTreasury.into());
+        }
+    
+        // Validate reward escrow matches stored
+        if reward_escrow_ai.key != &issuance.reward_escrow {
+            return Err(LockrionError::InvalidEscrowAccount.into());
+        }
+    
+        // Mint checks (both must be reward_mint)
         Self::validate_token_account_mint(reward_escrow_ai, &issuance.reward_mint)?;
         Self::validate_token_account_mint(platform_treasury_ai, &issuance.reward_mint)?;
     
         // Authority of reward escrow MUST be issuance PDA
         Self::validate_token_account_authority(reward_escrow_ai, &issuance_pda)?;
+
+        let principal_held = if issuance.is_same_mint() {
+            let deposit_escrow_ai = next_account_info(acc_iter)?;
+            Self::principal_in_reward_escrow(&issuance, &issuance_pda, deposit_escrow_ai)?
+        } else {
+            0
+        };
     
         // Preconditions
         if issuance.total_weight_accum == 0 {
@@ -595,10 +1823,7 @@ impl Processor {
         }
     
         let now = Self::now_ts();
-        let sweep_start = issuance
-            .maturity_ts
-            .checked_add(issuance.claim_window)
-            .ok_or(LockrionError::ArithmeticOverflow)?;
+        let sweep_start = Self::claim_end_ts(&issuance)?;
         if now < sweep_start {
             return Err(LockrionError::ClaimWindowClosed.into()); // not ideal naming, but you already have it
             // If хочешь идеально: добавим Error::SweepWindowNotStarted, но это не требуется протоколом.
@@ -612,9 +1837,9 @@ impl Processor {
     
         let _current = Self::finalize_global(&mut issuance, now)?;
     
-        // Determine escrow balance and transfer entire balance
+        // Determine escrow balance and transfer entire balance (less principal held in a shared escrow)
         let escrow = TokenAccount::unpack(&reward_escrow_ai.try_borrow_data()?)?;
-        let bal = escrow.amount;
+        let bal = escrow.amount.checked_sub(principal_held).ok_or(LockrionError::InvariantViolation)?;
         if bal == 0 {
             return Ok(()); // spec says "reward escrow balance > 0" as precondition; returning Ok is harmless deterministic no-op
         }
@@ -651,6 +1876,17 @@ impl Processor {
         Ok(())
     }
     
+    // ---------------------------------------------------------------------
+    // zero_participation_reclaim()
+    // Accounts:
+    // 0 [writable] issuance_state (PDA)
+    // 1 [signer]   issuer
+    // 2 [writable] issuer_reward_ata
+    // 3 [writable] reward_escrow
+    // 4 []         token_program
+    // same-mint (lock_mint == reward_mint) only:
+    // 5 []         deposit_escrow          (must back total_locked; a legacy shared escrow keeps it)
+    // ---------------------------------------------------------------------
     fn reclaim(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
         let acc_iter = &mut accounts.iter();
         let issuance_ai = next_account_info(acc_iter)?;
@@ -669,7 +1905,7 @@ impl Processor {
         }
     
         // Load state
-        let mut issuance = IssuanceState::unpack(&issuance_ai.try_borrow_data()?)?;
+        let mut issuance = IssuanceState::load(&issuance_ai.try_borrow_data()?)?;
     
         // Validate issuance PDA (canonical)
         let (issuance_pda, bump) = pda::derive_issuance_pda(
@@ -701,6 +1937,13 @@ impl Processor {
     
         // Destination must be a token account with reward_mint (USDC)
         Self::validate_token_account_mint(issuer_reward_ata_ai, &issuance.reward_mint)?;
+
+        let principal_held = if issuance.is_same_mint() {
+            let deposit_escrow_ai = next_account_info(acc_iter)?;
+            Self::principal_in_reward_escrow(&issuance, &issuance_pda, deposit_escrow_ai)?
+        } else {
+            0
+        };
     
         // Time gate: only after maturity
         let now = Self::now_ts();
@@ -714,9 +1957,9 @@ impl Processor {
             return Err(LockrionError::NoParticipation.into());
         }
         
-        // Transfer entire escrow balance (must be > 0)
+        // Transfer entire escrow balance (must be > 0; less principal held in a shared escrow)
         let escrow = TokenAccount::unpack(&reward_escrow_ai.try_borrow_data()?)?;
-        let bal = escrow.amount;
+        let bal = escrow.amount.checked_sub(principal_held).ok_or(LockrionError::InvariantViolation)?;
         if bal == 0 {
             return Err(LockrionError::InvalidAmount.into());
         }
@@ -759,7 +2002,27 @@ impl Processor {
         reserve_total: u128,
         start_ts: i64,
         maturity_ts: i64,
+        params: IssuanceParams,
     ) -> ProgramResult {
+        // Accounts:
+        // 0 [signer, writable] payer (platform authority)
+        // 1 [writable] issuance_state (PDA, uninitialized)
+        // 2 []         lock_mint
+        // 3 []         reward_mint
+        // 4 []         deposit_escrow
+        // 5 []         reward_escrow
+        // 6 []         platform_treasury
+        // 7 []         system_program
+        // params.receipt_mint only:
+        // 8 [writable] receipt_mint (PDA, uninitialized)
+        // 9 []         token_program
+        // params.metadata only (after the receipt accounts, if any):
+        // +0 [writable] metadata (PDA [metadata, issuance], uninitialized)
+        // registry accounts (after the receipt / metadata accounts, if any):
+        // +0 [writable] platform registry      (PDA [registry, payer])
+        // +1 [writable] platform registry page (PDA [registry_page, payer, page_index])
+        // +2 [writable] issuer registry        (required when issuer != payer)
+        // +3 [writable] issuer registry page
         let acc_iter = &mut accounts.iter();
     
         let payer_ai = next_account_info(acc_iter)?;
@@ -770,92 +2033,524 @@ impl Processor {
         let reward_escrow_ai = next_account_info(acc_iter)?;
         let platform_treasury_ai = next_account_info(acc_iter)?;
         let system_program_ai = next_account_info(acc_iter)?;
-    
-        // --- Platform-only gate ---
+    
+        // --- Platform-only gate ---
+        if !payer_ai.is_signer {
+            return Err(LockrionError::UnauthorizedCaller.into());
+        }
+        if payer_ai.key != &PLATFORM_AUTHORITY {
+            return Err(LockrionError::UnauthorizedCaller.into());
+        }
+    
+        if system_program_ai.key != &solana_program::system_program::id() {
+            // keep existing error enum usage to avoid changing error surface
+            return Err(LockrionError::InvalidTokenProgram.into());
+        }
+
+        // Principal and reward never share an escrow (lock_mint may equal reward_mint)
+        if deposit_escrow_ai.key == reward_escrow_ai.key {
+            return Err(LockrionError::InvalidEscrowAccount.into());
+        }
+    
+        if reserve_total == 0 {
+            return Err(LockrionError::InvalidAmount.into());
+        }
+        if !(0..=accumulator::MAX_VESTING_DURATION).contains(&params.vesting_duration) {
+            return Err(LockrionError::InvalidIssuanceParams.into());
+        }
+        // claim_end_ts (maturity + vesting + claim window) must stay representable,
+        // or claim / sweep / reclaim would fail on every call
+        let claim_window: i64 = 90 * 86400;
+        maturity_ts
+            .checked_add(params.vesting_duration)
+            .and_then(|t| t.checked_add(claim_window))
+            .ok_or(LockrionError::InvalidIssuanceParams)?;
+        accumulator::validate_multiplier_schedule(&params.multiplier_schedule)?;
+        // Receipts carry 1:1 principal only; weighted positions cannot follow them
+        if params.receipt_mint && (!params.multiplier_schedule.is_empty() || !params.lock_tiers.is_empty()) {
+            return Err(LockrionError::InvalidIssuanceParams.into());
+        }
+        if let Some(metadata) = &params.metadata {
+            metadata.validate()?;
+        }
+        // Receipts move freely between wallets, bypassing a per-participant gate
+        if params.receipt_mint && params.attestation_program != Pubkey::default() {
+            return Err(LockrionError::InvalidIssuanceParams.into());
+        }
+
+        // Continuous mode = one-second periods through the same accumulator code path
+        if params.continuous && params.accounting_period != 0 {
+            return Err(LockrionError::InvalidIssuanceParams.into());
+        }
+        let accounting_period = if params.continuous {
+            accumulator::CONTINUOUS_ACCOUNTING_PERIOD
+        } else if params.accounting_period == 0 {
+            accumulator::DEFAULT_ACCOUNTING_PERIOD
+        } else {
+            params.accounting_period
+        };
+        let (final_day_index, prorated_tail) = if params.prorate_final_period {
+            accumulator::prorated_final_day_index(start_ts, maturity_ts, accounting_period)?
+        } else {
+            (accumulator::final_day_index(start_ts, maturity_ts, accounting_period)?, 0)
+        };
+        accumulator::validate_lock_tiers(&params.lock_tiers, final_day_index)?;
+    
+        // Issuer authority is separate from the platform key; seeds follow the issuer
+        let issuer = if params.issuer == Pubkey::default() {
+            *payer_ai.key
+        } else {
+            params.issuer
+        };
+
+        let (issuance_pda, bump) =
+            pda::derive_issuance_pda(program_id, &issuer, start_ts, reserve_total);
+    
+        if issuance_ai.key != &issuance_pda {
+            return Err(LockrionError::InvalidPda.into());
+        }
+    
+        // must be uninitialized before create_account
+        if issuance_ai.owner != &solana_program::system_program::id() || issuance_ai.data_len() != 0 {
+            return Err(LockrionError::InvalidEscrowAccount.into());
+        }
+    
+        Self::create_pda_account(
+            program_id,
+            issuance_ai,
+            payer_ai,
+            system_program_ai,
+            crate::state::ISSUANCE_STATE_SIZE,
+            &[
+                pda::SEED_ISSUANCE,
+                issuer.as_ref(),
+                &start_ts.to_le_bytes(),
+                &reserve_total.to_le_bytes(),
+                &[bump],
+            ],
+        )?;
+    
+        let mut issuance = IssuanceState {
+            version: crate::state::STATE_VERSION,
+            bump,
+            issuer_address: issuer,
+    
+            lock_mint: *lock_mint_ai.key,
+            reward_mint: *reward_mint_ai.key,
+            deposit_escrow: *deposit_escrow_ai.key,
+            reward_escrow: *reward_escrow_ai.key,
+            platform_treasury: *platform_treasury_ai.key,
+    
+            reserve_total,
+            start_ts,
+            maturity_ts,
+            claim_window,
+            final_day_index,
+    
+            total_locked: 0,
+            total_weight_accum: 0,
+            last_day_index: 0,
+    
+            reserve_funded: 0,
+            sweep_executed: 0,
+            reclaim_executed: 0,
+            receipt_mode: 0,
+            receipt_mint_bump: 0,
+            terms_required: u8::from(params.metadata.is_some()),
+            reserved_padding: [0u8; 4],
+            vesting_duration: params.vesting_duration,
+            total_weighted_locked: 0,
+            multiplier_schedule: [MultiplierTier::default(); MAX_MULTIPLIER_TIERS],
+            accounting_period,
+            prorated_tail,
+            total_claimed: 0,
+            total_weight_claimed: 0,
+            participant_count: 0,
+            claimed_count: 0,
+            withdrawn_count: 0,
+            total_withdrawn: 0,
+            attestation_program: params.attestation_program,
+            attestation_schema: params.attestation_schema,
+            lock_tiers: [LockTier::default(); MAX_LOCK_TIERS],
+        };
+        issuance.multiplier_schedule[..params.multiplier_schedule.len()]
+            .copy_from_slice(&params.multiplier_schedule);
+        issuance.lock_tiers[..params.lock_tiers.len()].copy_from_slice(&params.lock_tiers);
+
+        if params.receipt_mint {
+            let receipt_mint_ai = next_account_info(acc_iter)?;
+            let token_program_ai = next_account_info(acc_iter)?;
+            Self::validate_token_program(token_program_ai)?;
+
+            issuance.receipt_mint_bump = Self::create_receipt_mint(
+                program_id,
+                &issuance_pda,
+                payer_ai,
+                lock_mint_ai,
+                receipt_mint_ai,
+                system_program_ai,
+                token_program_ai,
+            )?;
+            issuance.receipt_mode = 1;
+        }
+
+        if let Some(metadata) = &params.metadata {
+            let metadata_ai = next_account_info(acc_iter)?;
+            let (metadata_pda, metadata_bump) = pda::derive_metadata_pda(program_id, &issuance_pda);
+            if metadata_ai.key != &metadata_pda {
+                return Err(LockrionError::InvalidPda.into());
+            }
+            if metadata_ai.owner != &system_program::ID || metadata_ai.data_len() != 0 {
+                return Err(LockrionError::InvalidAccountSize.into());
+            }
+
+            let bump_seed = [metadata_bump];
+            Self::create_pda_account(
+                program_id,
+                metadata_ai,
+                payer_ai,
+                system_program_ai,
+                crate::metadata::METADATA_SIZE,
+                &[pda::SEED_METADATA, issuance_pda.as_ref(), &bump_seed],
+            )?;
+            IssuanceMetadata::new(issuance_pda, metadata_bump, metadata)
+                .pack(&mut metadata_ai.try_borrow_mut_data()?)?;
+        }
+
+        // Discovery: every issuance is appended to the platform (and issuer) registry
+        let record = RegistryRecord {
+            issuance: issuance_pda,
+            lock_mint: *lock_mint_ai.key,
+            reward_mint: *reward_mint_ai.key,
+            start_ts,
+            maturity_ts,
+        };
+        let registry_ai = next_account_info(acc_iter)?;
+        let page_ai = next_account_info(acc_iter)?;
+        Self::register_issuance(program_id, payer_ai.key, registry_ai, page_ai, payer_ai, system_program_ai, &record)?;
+
+        if issuer != *payer_ai.key {
+            let issuer_registry_ai = next_account_info(acc_iter)?;
+            let issuer_page_ai = next_account_info(acc_iter)?;
+            Self::register_issuance(
+                program_id,
+                &issuer,
+                issuer_registry_ai,
+                issuer_page_ai,
+                payer_ai,
+                system_program_ai,
+                &record,
+            )?;
+        }
+    
+        issuance.pack(&mut issuance_ai.try_borrow_mut_data()?)?;
+    
+        Ok(())
+    }
+
+    // ---------------------------------------------------------------------
+    // register_issuance()
+    // Appends `record` to the registry of `scope`, creating the head on first
+    // use and a new page every RECORDS_PER_PAGE records; otherwise the current
+    // page grows by one record (payer tops up rent).
+    // ---------------------------------------------------------------------
+    #[allow(clippy::too_many_arguments)]
+    fn register_issuance<'a>(
+        program_id: &Pubkey,
+        scope: &Pubkey,
+        registry_ai: &AccountInfo<'a>,
+        page_ai: &AccountInfo<'a>,
+        payer_ai: &AccountInfo<'a>,
+        system_program_ai: &AccountInfo<'a>,
+        record: &RegistryRecord,
+    ) -> ProgramResult {
+        let (registry_pda, registry_bump) = pda::derive_registry_pda(program_id, scope);
+        if registry_ai.key != &registry_pda {
+            return Err(LockrionError::InvalidPda.into());
+        }
+
+        let mut head = if registry_ai.owner == &system_program::ID && registry_ai.data_len() == 0 {
+            let bump_seed = [registry_bump];
+            Self::create_pda_account(
+                program_id,
+                registry_ai,
+                payer_ai,
+                system_program_ai,
+                registry::REGISTRY_HEAD_SIZE,
+                &[pda::SEED_REGISTRY, scope.as_ref(), &bump_seed],
+            )?;
+            RegistryHead {
+                version: registry::REGISTRY_VERSION,
+                bump: registry_bump,
+                scope: *scope,
+                count: 0,
+                reserved_padding: [0u8; 6],
+            }
+        } else {
+            if registry_ai.owner != program_id {
+                return Err(LockrionError::InvalidPda.into());
+            }
+            RegistryHead::unpack(&registry_ai.try_borrow_data()?)?
+        };
+
+        // The page is fixed by the head count; any other page is rejected
+        let page_index = registry::page_index(head.count)?;
+        let slot = head.count % registry::RECORDS_PER_PAGE;
+        let (page_pda, page_bump) = pda::derive_registry_page_pda(program_id, scope, page_index);
+        if page_ai.key != &page_pda {
+            return Err(LockrionError::InvalidPda.into());
+        }
+
+        if slot == 0 {
+            if page_ai.owner != &system_program::ID || page_ai.data_len() != 0 {
+                return Err(LockrionError::InvalidAccountSize.into());
+            }
+            let page_le = page_index.to_le_bytes();
+            let bump_seed = [page_bump];
+            Self::create_pda_account(
+                program_id,
+                page_ai,
+                payer_ai,
+                system_program_ai,
+                registry::page_size(1),
+                &[pda::SEED_REGISTRY_PAGE, scope.as_ref(), &page_le, &bump_seed],
+            )?;
+            registry::pack_page_header(&mut page_ai.try_borrow_mut_data()?, page_bump, page_index)?;
+        } else {
+            if page_ai.owner != program_id {
+                return Err(LockrionError::InvalidPda.into());
+            }
+            if page_ai.data_len() != registry::page_size(slot) {
+                return Err(LockrionError::InvalidAccountSize.into());
+            }
+            Self::grow_state_account(page_ai, payer_ai, system_program_ai, registry::page_size(slot + 1))?;
+        }
+
+        let offset = registry::page_size(slot);
+        record.pack(&mut page_ai.try_borrow_mut_data()?[offset..offset + registry::REGISTRY_RECORD_SIZE])?;
+
+        head.count = head.count.checked_add(1).ok_or(LockrionError::ArithmeticOverflow)?;
+        head.pack(&mut registry_ai.try_borrow_mut_data()?)?;
+
+        Ok(())
+    }
+
+    // PDA addresses are predictable, so anyone can send lamports to one before it is
+    // created, which makes system create_account fail. A pre-funded address is topped
+    // up to rent exemption instead, then allocated and assigned under the PDA seeds.
+    fn create_pda_account<'a>(
+        owner: &Pubkey,
+        target_ai: &AccountInfo<'a>,
+        payer_ai: &AccountInfo<'a>,
+        system_program_ai: &AccountInfo<'a>,
+        size: usize,
+        seeds: &[&[u8]],
+    ) -> ProgramResult {
+        let required = Rent::get()?.minimum_balance(size);
+        let current = target_ai.lamports();
+
+        if current == 0 {
+            return invoke_signed(
+                &system_instruction::create_account(payer_ai.key, target_ai.key, required, size as u64, owner),
+                &[payer_ai.clone(), target_ai.clone(), system_program_ai.clone()],
+                &[seeds],
+            );
+        }
+
+        if current < required {
+            invoke(
+                &system_instruction::transfer(payer_ai.key, target_ai.key, required - current),
+                &[payer_ai.clone(), target_ai.clone(), system_program_ai.clone()],
+            )?;
+        }
+        invoke_signed(
+            &system_instruction::allocate(target_ai.key, size as u64),
+            &[target_ai.clone(), system_program_ai.clone()],
+            &[seeds],
+        )?;
+        invoke_signed(
+            &system_instruction::assign(target_ai.key, owner),
+            &[target_ai.clone(), system_program_ai.clone()],
+            &[seeds],
+        )
+    }
+
+    // ---------------------------------------------------------------------
+    // migrate_state()
+    // Accounts:
+    // 0 [writable]         state account (v1 issuance_state or v1 user_state PDA)
+    // 1 [signer, writable] payer (covers the rent-exempt difference)
+    // 2 []                 system_program
+    //
+    // Layout is detected by size + version byte; the PDA is re-derived from the
+    // stored seeds. Rent top-up, realloc and rewrite happen in one instruction.
+    // ---------------------------------------------------------------------
+    fn migrate_state(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let acc_iter = &mut accounts.iter();
+        let state_ai = next_account_info(acc_iter)?;
+        let payer_ai = next_account_info(acc_iter)?;
+        let system_program_ai = next_account_info(acc_iter)?;
+
+        if system_program_ai.key != &system_program::ID {
+            return Err(LockrionError::InvalidInstruction.into());
+        }
+        if state_ai.owner != program_id {
+            return Err(LockrionError::InvalidUserStateAccount.into());
+        }
         if !payer_ai.is_signer {
             return Err(LockrionError::UnauthorizedCaller.into());
         }
-        if payer_ai.key != &PLATFORM_AUTHORITY {
-            return Err(LockrionError::UnauthorizedCaller.into());
+
+        match state_ai.data_len() {
+            ISSUANCE_STATE_SIZE_V1 => {
+                let v1 = IssuanceStateV1::unpack(&state_ai.try_borrow_data()?)?;
+                let (issuance_pda, bump) = pda::derive_issuance_pda(
+                    program_id,
+                    &v1.issuer_address,
+                    v1.start_ts,
+                    v1.reserve_total,
+                );
+                if state_ai.key != &issuance_pda || v1.bump != bump {
+                    return Err(LockrionError::InvalidPda.into());
+                }
+                Self::grow_state_account(state_ai, payer_ai, system_program_ai, ISSUANCE_STATE_SIZE)?;
+                IssuanceState::from(v1).pack(&mut state_ai.try_borrow_mut_data()?)?;
+            }
+            USER_STATE_SIZE_V1 => {
+                let v1 = UserStateV1::unpack(&state_ai.try_borrow_data()?)?;
+                let (user_pda, user_bump) = pda::derive_user_pda(program_id, &v1.issuance, &v1.participant);
+                if state_ai.key != &user_pda || v1.bump != user_bump {
+                    return Err(LockrionError::InvalidPda.into());
+                }
+                Self::grow_state_account(state_ai, payer_ai, system_program_ai, USER_STATE_SIZE)?;
+                UserState::from(v1).pack(&mut state_ai.try_borrow_mut_data()?)?;
+            }
+            // already on the current layout
+            ISSUANCE_STATE_SIZE | USER_STATE_SIZE => return Err(LockrionError::InvalidStateVersion.into()),
+            _ => return Err(LockrionError::InvalidAccountSize.into()),
         }
-    
-        if system_program_ai.key != &solana_program::system_program::id() {
-            // keep existing error enum usage to avoid changing error surface
-            return Err(LockrionError::InvalidTokenProgram.into());
+
+        Ok(())
+    }
+
+    // Rent-exempt top-up for the larger size (payer -> state), then realloc (zero-filled)
+    fn grow_state_account<'a>(
+        state_ai: &AccountInfo<'a>,
+        payer_ai: &AccountInfo<'a>,
+        system_program_ai: &AccountInfo<'a>,
+        new_len: usize,
+    ) -> ProgramResult {
+        let required = Rent::get()?.minimum_balance(new_len);
+        let top_up = required.saturating_sub(state_ai.lamports());
+        if top_up > 0 {
+            invoke(
+                &system_instruction::transfer(payer_ai.key, state_ai.key, top_up),
+                &[payer_ai.clone(), state_ai.clone(), system_program_ai.clone()],
+            )?;
         }
-    
-        if reserve_total == 0 {
-            return Err(LockrionError::InvalidAmount.into());
+
+        state_ai.realloc(new_len, true)
+    }
+
+    // ---------------------------------------------------------------------
+    // quote()
+    // Accounts:
+    // 0 []  issuance_state (PDA)
+    // 1 []  user_state (PDA)
+    //
+    // Works on in-memory copies only; nothing is packed back.
+    // ---------------------------------------------------------------------
+    fn quote(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let acc_iter = &mut accounts.iter();
+        let issuance_ai = next_account_info(acc_iter)?;
+        let user_state_ai = next_account_info(acc_iter)?;
+
+        if issuance_ai.owner != program_id {
+            return Err(LockrionError::InvalidEscrowAccount.into());
         }
-    
-        let (issuance_pda, bump) =
-            pda::derive_issuance_pda(program_id, payer_ai.key, start_ts, reserve_total);
-    
-        if issuance_ai.key != &issuance_pda {
+        if user_state_ai.owner != program_id {
+            return Err(LockrionError::InvalidUserStateAccount.into());
+        }
+
+        let mut issuance = IssuanceState::load(&issuance_ai.try_borrow_data()?)?;
+        let mut user = UserState::load(&user_state_ai.try_borrow_data()?)?;
+
+        // Validate issuance PDA
+        let (issuance_pda, bump) = pda::derive_issuance_pda(
+            program_id,
+            &issuance.issuer_address,
+            issuance.start_ts,
+            issuance.reserve_total,
+        );
+        if issuance_ai.key != &issuance_pda || issuance.bump != bump {
             return Err(LockrionError::InvalidPda.into());
         }
-    
-        // must be uninitialized before create_account
-        if issuance_ai.owner != &solana_program::system_program::id() || issuance_ai.data_len() != 0 {
-            return Err(LockrionError::InvalidEscrowAccount.into());
+
+        // Validate user PDA + binding (participant taken from the state itself)
+        let (user_pda, user_bump) = pda::derive_user_pda(program_id, &issuance_pda, &user.participant);
+        if user_state_ai.key != &user_pda || user.bump != user_bump {
+            return Err(LockrionError::InvalidPda.into());
         }
-    
-        let rent = solana_program::rent::Rent::get()?;
-        let lamports = rent.minimum_balance(crate::state::ISSUANCE_STATE_SIZE);
-    
-        solana_program::program::invoke_signed(
-            &solana_program::system_instruction::create_account(
-                payer_ai.key,
-                issuance_ai.key,
-                lamports,
-                crate::state::ISSUANCE_STATE_SIZE as u64,
-                program_id,
-            ),
-            &[payer_ai.clone(), issuance_ai.clone(), system_program_ai.clone()],
-            &[&[
-                pda::SEED_ISSUANCE,
-                payer_ai.key.as_ref(),
-                &start_ts.to_le_bytes(),
-                &reserve_total.to_le_bytes(),
-                &[bump],
-            ]],
-        )?;
-    
-        let final_day_index = if maturity_ts > start_ts {
-            ((maturity_ts - start_ts) / 86400) as u64
+        if &user.issuance != issuance_ai.key {
+            return Err(LockrionError::InvalidUserStateAccount.into());
+        }
+
+        let now = Self::now_ts();
+        let claim_end = Self::claim_end_ts(&issuance)?;
+        let phase = if !issuance.is_reserve_funded() {
+            Phase::Unfunded
+        } else if now < issuance.start_ts {
+            Phase::NotStarted
+        } else if now < issuance.maturity_ts {
+            Phase::Deposit
+        } else if now < claim_end {
+            Phase::Claim
         } else {
-            0u64
+            Phase::Closed
         };
-    
-        let issuance = IssuanceState {
-            version: crate::state::STATE_VERSION,
-            bump,
-            issuer_address: *payer_ai.key,
-    
-            lock_mint: *lock_mint_ai.key,
-            reward_mint: *reward_mint_ai.key,
-            deposit_escrow: *deposit_escrow_ai.key,
-            reward_escrow: *reward_escrow_ai.key,
-            platform_treasury: *platform_treasury_ai.key,
-    
-            reserve_total,
-            start_ts,
-            maturity_ts,
-            claim_window: 90 * 86400,
-            final_day_index,
-    
-            total_locked: 0,
-            total_weight_accum: 0,
-            last_day_index: 0,
-    
-            reserve_funded: 0,
-            sweep_executed: 0,
-            reclaim_executed: 0,
-            reserved_padding: [0u8; 7],
+
+        // Before maturity this extrapolates current locked amounts to the final day
+        let projection_ts = now.max(issuance.maturity_ts);
+        Self::apply_accumulators(&mut issuance, &mut user, projection_ts)?;
+
+        let projected_reward = if user.is_reward_claimed() {
+            user.reward_total
+        } else if issuance.total_weight_accum == 0 {
+            0
+        } else {
+            Self::fix_entitlement(&mut issuance.clone(), &user)?
         };
-    
-        issuance.pack(&mut issuance_ai.try_borrow_mut_data()?)?;
-    
+
+        // Mirrors the claim_reward gates
+        let claimable = phase == Phase::Claim
+            && !user.is_reward_settled()
+            && issuance.total_weight_accum != 0
+            && {
+                let delta = Self::release_reward(&mut issuance, &mut user, now)?;
+                delta > 0 || user.is_reward_settled()
+            };
+
+        // Mirrors the withdraw_deposit time gate, net of any early-unlock penalty
+        let withdrawable = if now >= issuance.maturity_ts {
+            user.locked_amount
+        } else if user.locked_amount == 0 {
+            0
+        } else {
+            match Self::early_unlock_penalty(&issuance, &user, now)? {
+                Some(penalty) => user.locked_amount.checked_sub(penalty).ok_or(LockrionError::ArithmeticUnderflow)?,
+                None => 0,
+            }
+        };
+
+        let view = QuoteView {
+            phase,
+            projected_reward: u64::try_from(projected_reward).map_err(|_| LockrionError::ArithmeticOverflow)?,
+            claimable,
+            withdrawable: u64::try_from(withdrawable).map_err(|_| LockrionError::ArithmeticOverflow)?,
+        };
+        let data = view.try_to_vec().map_err(|_| LockrionError::InvalidInstruction)?;
+        set_return_data(&data);
+
         Ok(())
     }
 
@@ -881,7 +2576,9 @@ impl Processor {
         program_id: &Pubkey,
         issuance_pda: &Pubkey,
         user_state_ai: &AccountInfo<'a>,
-        participant_ai: &AccountInfo<'a>,
+        participant: &Pubkey,
+        payer_ai: &AccountInfo<'a>,
+        system_program_ai: &AccountInfo<'a>,
         user_bump: u8,
     ) -> ProgramResult {
         // already program-owned => exists
@@ -894,32 +2591,189 @@ impl Processor {
             return Err(LockrionError::InvalidUserStateAccount.into());
         }
     
-        // Create PDA account (rent-exempt) of exact size 112 bytes
-        let space: u64 = 112;
-        let lamports = Rent::get()?.minimum_balance(space as usize);
-    
-        let ix = system_instruction::create_account(
-            participant_ai.key,
-            user_state_ai.key,
-            lamports,
-            space,
-            program_id,
-        );
-    
+        // Create PDA account (rent-exempt) of exact size USER_STATE_SIZE
         let bump_seed = [user_bump];
         let seeds: &[&[u8]] = &[
             pda::SEED_USER,              // b"user"
             issuance_pda.as_ref(),
-            participant_ai.key.as_ref(),
+            participant.as_ref(),
             &bump_seed,
         ];
     
-        invoke_signed(
+        Self::create_pda_account(
+            program_id,
+            user_state_ai,
+            payer_ai,
+            system_program_ai,
+            crate::state::USER_STATE_SIZE,
+            seeds,
+        )
+    }
+
+    // If freshly created (all-zero): initialize EXACT bytes per UserStateV3 layout
+    // UserState layout offsets:
+    // 0 version(u8)=STATE_VERSION
+    // 1 bump(u8)
+    // 2 issuance(Pubkey)[32]
+    // 34 participant(Pubkey)[32]
+    // 66 locked_amount(u128)=0
+    // 82 user_weight_accum(u128)=0
+    // 98 user_last_day_index(u64)=last_day_index
+    // 106 reward_claimed(u8)=0
+    // 107..111 padding[5]=0
+    // 112 reward_total(u128)=0
+    // 128 reward_withdrawn(u128)=0
+    // 144 weighted_locked(u128)=0
+    // 160 rent_payer(Pubkey)=rent_payer
+    // Returns true if the bytes were initialized by this call.
+    fn init_user_state_if_needed(
+        user_state_ai: &AccountInfo,
+        issuance: &Pubkey,
+        participant: &Pubkey,
+        user_bump: u8,
+        last_day_index: u64,
+        rent_payer: &Pubkey,
+    ) -> Result<bool, ProgramError> {
+        let mut d = user_state_ai.try_borrow_mut_data()?;
+
+        // initialize only if version == 0 (fresh account will be all-zero;
+        // an existing v1 account keeps its bytes until MigrateState)
+        let fresh = d[0] == 0;
+        if fresh {
+            // version
+            d[0] = crate::state::STATE_VERSION;
+            // bump
+            d[1] = user_bump;
+
+            // issuance pubkey bytes
+            d[2..34].copy_from_slice(issuance.as_ref());
+
+            // participant pubkey bytes
+            d[34..66].copy_from_slice(participant.as_ref());
+
+            // locked_amount + user_weight_accum already zero (leave as-is)
+            // user_last_day_index = issuance.last_day_index
+            d[98..106].copy_from_slice(&last_day_index.to_le_bytes());
+
+            // reward_claimed = 0 (leave)
+            // padding = 0 (leave)
+            // reward_total + reward_withdrawn + weighted_locked = 0 (leave)
+
+            // rent_payer (refund target when the account is closed)
+            d[160..192].copy_from_slice(rent_payer.as_ref());
+        }
+
+        Ok(fresh)
+    }
+
+    fn create_receipt_mint<'a>(
+        program_id: &Pubkey,
+        issuance_pda: &Pubkey,
+        payer_ai: &AccountInfo<'a>,
+        lock_mint_ai: &AccountInfo<'a>,
+        receipt_mint_ai: &AccountInfo<'a>,
+        system_program_ai: &AccountInfo<'a>,
+        token_program_ai: &AccountInfo<'a>,
+    ) -> Result<u8, ProgramError> {
+        let (receipt_pda, receipt_bump) = pda::derive_receipt_mint_pda(program_id, issuance_pda);
+        if receipt_mint_ai.key != &receipt_pda {
+            return Err(LockrionError::InvalidPda.into());
+        }
+        if receipt_mint_ai.owner != &system_program::ID || receipt_mint_ai.data_len() != 0 {
+            return Err(LockrionError::InvalidMint.into());
+        }
+
+        // Receipts mirror lock_mint decimals (1:1 in base units)
+        if lock_mint_ai.owner != &spl_token::id() {
+            return Err(LockrionError::InvalidMint.into());
+        }
+        let lock_mint = Mint::unpack(&lock_mint_ai.try_borrow_data()?)?;
+
+        let bump_seed = [receipt_bump];
+        Self::create_pda_account(
+            &spl_token::id(),
+            receipt_mint_ai,
+            payer_ai,
+            system_program_ai,
+            Mint::LEN,
+            &[pda::SEED_RECEIPT, issuance_pda.as_ref(), &bump_seed],
+        )?;
+
+        // mint authority = issuance PDA, no freeze authority
+        let ix = spl_token::instruction::initialize_mint2(
+            token_program_ai.key,
+            receipt_mint_ai.key,
+            issuance_pda,
+            None,
+            lock_mint.decimals,
+        )?;
+        solana_program::program::invoke(&ix, &[receipt_mint_ai.clone(), token_program_ai.clone()])?;
+
+        Ok(receipt_bump)
+    }
+
+    fn validate_receipt_accounts(
+        program_id: &Pubkey,
+        issuance: &IssuanceState,
+        issuance_pda: &Pubkey,
+        receipt_mint_ai: &AccountInfo,
+        receipt_ata_ai: &AccountInfo,
+        expected_owner: &Pubkey,
+    ) -> ProgramResult {
+        let receipt_pda = Pubkey::create_program_address(
+            &[
+                pda::SEED_RECEIPT,
+                issuance_pda.as_ref(),
+                &[issuance.receipt_mint_bump],
+            ],
+            program_id,
+        )
+        .map_err(|_| LockrionError::InvalidPda)?;
+        if receipt_mint_ai.key != &receipt_pda {
+            return Err(LockrionError::InvalidPda.into());
+        }
+        Self::validate_token_account_mint(receipt_ata_ai, &receipt_pda)?;
+        Self::validate_token_account_authority(receipt_ata_ai, expected_owner)?;
+        Ok(())
+    }
+
+    fn burn_receipts<'a>(
+        program_id: &Pubkey,
+        issuance: &IssuanceState,
+        issuance_pda: &Pubkey,
+        acc_iter: &mut std::slice::Iter<AccountInfo<'a>>,
+        participant_ai: &AccountInfo<'a>,
+        token_program_ai: &AccountInfo<'a>,
+        amount: u64,
+    ) -> ProgramResult {
+        let receipt_mint_ai = next_account_info(acc_iter)?;
+        let participant_receipt_ata_ai = next_account_info(acc_iter)?;
+        Self::validate_receipt_accounts(
+            program_id,
+            issuance,
+            issuance_pda,
+            receipt_mint_ai,
+            participant_receipt_ata_ai,
+            participant_ai.key,
+        )?;
+
+        let ix = spl_token::instruction::burn(
+            token_program_ai.key,
+            participant_receipt_ata_ai.key,
+            receipt_mint_ai.key,
+            participant_ai.key,
+            &[] as &[&Pubkey],
+            amount,
+        )?;
+        solana_program::program::invoke(
             &ix,
-            &[participant_ai.clone(), user_state_ai.clone()],
-            &[seeds],
+            &[
+                participant_receipt_ata_ai.clone(),
+                receipt_mint_ai.clone(),
+                participant_ai.clone(),
+                token_program_ai.clone(),
+            ],
         )?;
-    
         Ok(())
     }
 
@@ -947,6 +2801,63 @@ impl Processor {
         Ok(())
     }
 
+    /// Same-mint issuances: `deposit_escrow_ai` is the stored deposit escrow and its
+    /// balance covers total_locked. Returns the principal a reward-escrow drain must
+    /// leave behind: total_locked for legacy issuances sharing one escrow, else 0.
+    fn principal_in_reward_escrow(
+        issuance: &IssuanceState,
+        issuance_pda: &Pubkey,
+        deposit_escrow_ai: &AccountInfo,
+    ) -> Result<u64, ProgramError> {
+        if deposit_escrow_ai.key != &issuance.deposit_escrow {
+            return Err(LockrionError::InvalidEscrowAccount.into());
+        }
+        Self::validate_token_account_authority(deposit_escrow_ai, issuance_pda)?;
+        let escrow = TokenAccount::unpack(&deposit_escrow_ai.try_borrow_data()?)?;
+        if u128::from(escrow.amount) < issuance.total_locked {
+            return Err(LockrionError::InvariantViolation.into());
+        }
+        if issuance.deposit_escrow == issuance.reward_escrow {
+            u64::try_from(issuance.total_locked).map_err(|_| LockrionError::ArithmeticOverflow.into())
+        } else {
+            Ok(0)
+        }
+    }
+
+    /// Native lock_mint: moves `amount` lamports from the funder into the wSOL
+    /// escrow, then sync_native credits them as token balance.
+    fn wrap_native<'a>(
+        token_program_ai: &AccountInfo<'a>,
+        system_program_ai: &AccountInfo<'a>,
+        funder_ai: &AccountInfo<'a>,
+        escrow_ai: &AccountInfo<'a>,
+        amount: u64,
+    ) -> ProgramResult {
+        invoke(
+            &system_instruction::transfer(funder_ai.key, escrow_ai.key, amount),
+            &[funder_ai.clone(), escrow_ai.clone(), system_program_ai.clone()],
+        )?;
+        let ix = spl_token::instruction::sync_native(token_program_ai.key, escrow_ai.key)?;
+        invoke(&ix, &[escrow_ai.clone(), token_program_ai.clone()])
+    }
+
+    /// Native lock_mint: closes the owner's wSOL account into the owner's wallet
+    /// (all its lamports, i.e. token balance + rent). The owner signs.
+    fn unwrap_native<'a>(
+        token_program_ai: &AccountInfo<'a>,
+        wsol_ai: &AccountInfo<'a>,
+        owner_ai: &AccountInfo<'a>,
+    ) -> ProgramResult {
+        let ix = spl_token::instruction::close_account(
+            token_program_ai.key,
+            wsol_ai.key,
+            owner_ai.key,
+            owner_ai.key,
+            &[] as &[&Pubkey],
+        )?;
+        invoke(&ix, &[wsol_ai.clone(), owner_ai.clone(), owner_ai.clone(), token_program_ai.clone()])
+    }
+
     fn spl_transfer<'a>(
         token_program_ai: &AccountInfo<'a>,
         source_ai: &AccountInfo<'a>,
@@ -991,18 +2902,22 @@ impl Processor {
     }
 
     fn finalize_global(issuance: &mut IssuanceState, now: i64) -> Result<u64, ProgramError> {
-        // Compute bounded current_day_index
-        let raw = accumulator::raw_day_index(now, issuance.start_ts).map_err(ProgramError::from)?;
-        let current = accumulator::bounded_day_index(raw, issuance.final_day_index);
+        // Compute bounded current_day_index (final_day_index from maturity on)
+        let current = accumulator::current_day_index(
+            now,
+            issuance.start_ts,
+            issuance.maturity_ts,
+            issuance.accounting_period,
+            issuance.final_day_index,
+        )
+        .map_err(ProgramError::from)?;
     
         // Global accumulator update
         if current > issuance.last_day_index {
-            let days_elapsed = current
-                .checked_sub(issuance.last_day_index)
-                .ok_or(LockrionError::ArithmeticUnderflow)?;
+            let units = Self::period_units(issuance, issuance.last_day_index, current)?;
             let inc = issuance
-                .total_locked
-                .checked_mul(days_elapsed as u128)
+                .total_weighted_locked
+                .checked_mul(units)
                 .ok_or(LockrionError::ArithmeticOverflow)?;
             issuance.total_weight_accum = issuance
                 .total_weight_accum
@@ -1018,14 +2933,16 @@ impl Processor {
         Ok(current)
     }
     
-    fn update_user_with_current(user: &mut UserState, current: u64) -> Result<(), ProgramError> {
+    fn update_user_with_current(
+        issuance: &IssuanceState,
+        user: &mut UserState,
+        current: u64,
+    ) -> Result<(), ProgramError> {
         if current > user.user_last_day_index {
-            let days_elapsed_user = current
-                .checked_sub(user.user_last_day_index)
-                .ok_or(LockrionError::ArithmeticUnderflow)?;
+            let units = Self::period_units(issuance, user.user_last_day_index, current)?;
             let inc = user
-                .locked_amount
-                .checked_mul(days_elapsed_user as u128)
+                .weighted_locked
+                .checked_mul(units)
                 .ok_or(LockrionError::ArithmeticOverflow)?;
             user.user_weight_accum = user
                 .user_weight_accum
@@ -1036,13 +2953,159 @@ impl Processor {
         Ok(())
     }    
 
+    fn period_units(issuance: &IssuanceState, from: u64, to: u64) -> Result<u128, ProgramError> {
+        let units = accumulator::period_units(
+            from,
+            to,
+            issuance.final_day_index,
+            issuance.accounting_period,
+            issuance.prorated_tail,
+        )?;
+        Ok(units)
+    }
+
+    fn compute_reward(issuance: &IssuanceState, user: &UserState) -> Result<u64, ProgramError> {
+        // SPL token amounts are u64; enforce representability deterministically
+        if issuance.reserve_total > (u64::MAX as u128) {
+            return Err(LockrionError::InvariantViolation.into());
+        }
+
+        // reward = reserve_total * user_weight_accum / total_weight_accum  (256-bit intermediate)
+        let reward_u128 = math::mul_div_floor(
+            issuance.reserve_total,
+            user.user_weight_accum,
+            issuance.total_weight_accum,
+        )?;
+        if reward_u128 > (u64::MAX as u128) {
+            return Err(LockrionError::ArithmeticOverflow.into());
+        }
+        Ok(reward_u128 as u64)
+    }
+
+    /// Exact distribution: floor share, except the claimant holding all remaining
+    /// weight gets reserve_total - total_claimed (the accumulated floor dust).
+    /// Sum of all entitlements == reserve_total once every weighted user claimed.
+    /// A zero-weight position (e.g. an emptied transfer source) is entitled to nothing
+    /// and does not count as a claim.
+    fn fix_entitlement(issuance: &mut IssuanceState, user: &UserState) -> Result<u128, ProgramError> {
+        if user.user_weight_accum == 0 {
+            return Ok(0);
+        }
+        let remaining_weight = issuance
+            .total_weight_accum
+            .checked_sub(issuance.total_weight_claimed)
+            .ok_or(LockrionError::InvariantViolation)?;
+        if user.user_weight_accum > remaining_weight {
+            return Err(LockrionError::InvariantViolation.into());
+        }
+
+        let reward = if user.user_weight_accum == remaining_weight {
+            issuance
+                .reserve_total
+                .checked_sub(issuance.total_claimed)
+                .ok_or(LockrionError::InvariantViolation)?
+        } else {
+            Self::compute_reward(issuance, user)? as u128
+        };
+
+        issuance.total_claimed = issuance
+            .total_claimed
+            .checked_add(reward)
+            .ok_or(LockrionError::ArithmeticOverflow)?;
+        issuance.total_weight_claimed = issuance
+            .total_weight_claimed
+            .checked_add(user.user_weight_accum)
+            .ok_or(LockrionError::ArithmeticOverflow)?;
+        issuance.claimed_count = issuance
+            .claimed_count
+            .checked_add(1)
+            .ok_or(LockrionError::ArithmeticOverflow)?;
+        if issuance.total_claimed > issuance.reserve_total {
+            return Err(LockrionError::InvariantViolation.into());
+        }
+        Ok(reward)
+    }
+
+    /// Settlement statistics for a principal withdrawal.
+    /// Penalty on withdrawing before maturity, or None while the position's lock
+    /// tier keeps principal locked (unlock_day == 0 or not reached yet).
+    fn early_unlock_penalty(
+        issuance: &IssuanceState,
+        user: &UserState,
+        now: i64,
+    ) -> Result<Option<u128>, ProgramError> {
+        let tier = accumulator::lock_tier(&issuance.lock_tiers, user.lock_tier)?;
+        let day = accumulator::current_day_index(
+            now,
+            issuance.start_ts,
+            issuance.maturity_ts,
+            issuance.accounting_period,
+            issuance.final_day_index,
+        )?;
+        if tier.unlock_day == 0 || day < tier.unlock_day {
+            return Ok(None);
+        }
+        Ok(Some(accumulator::penalty_amount(user.locked_amount, tier.penalty_bps)?))
+    }
+
+    fn record_withdrawal(issuance: &mut IssuanceState, amount: u128) -> Result<(), ProgramError> {
+        issuance.total_withdrawn = issuance
+            .total_withdrawn
+            .checked_add(amount)
+            .ok_or(LockrionError::ArithmeticOverflow)?;
+        issuance.withdrawn_count = issuance
+            .withdrawn_count
+            .checked_add(1)
+            .ok_or(LockrionError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// End of the claim window (exclusive) = start of sweep: maturity + vesting tail + claim_window.
+    fn claim_end_ts(issuance: &IssuanceState) -> Result<i64, ProgramError> {
+        let end = issuance
+            .maturity_ts
+            .checked_add(issuance.vesting_duration)
+            .and_then(|t| t.checked_add(issuance.claim_window))
+            .ok_or(LockrionError::ArithmeticOverflow)?;
+        Ok(end)
+    }
+
+    /// Fixes the entitlement on first call (reward_claimed = 1), then advances
+    /// reward_withdrawn to the vested amount. Returns the delta to transfer.
+    /// Caller must have finalized accumulators and persist both states before CPI.
+    fn release_reward(
+        issuance: &mut IssuanceState,
+        user: &mut UserState,
+        now: i64,
+    ) -> Result<u64, ProgramError> {
+        if !user.is_reward_claimed() {
+            user.reward_total = Self::fix_entitlement(issuance, user)?;
+            user.reward_withdrawn = 0;
+            user.reward_claimed = 1;
+        }
+
+        let vested = vesting::vested_amount(
+            user.reward_total,
+            issuance.maturity_ts,
+            issuance.vesting_duration,
+            now,
+        )?;
+        let delta = vested
+            .checked_sub(user.reward_withdrawn)
+            .ok_or(LockrionError::InvariantViolation)?;
+        user.reward_withdrawn = vested;
+
+        // reward_total <= reserve_total <= u64::MAX (enforced in compute_reward)
+        Ok(delta as u64)
+    }
+
     fn apply_accumulators(
         issuance: &mut IssuanceState,
         user: &mut UserState,
         now: i64,
     ) -> Result<(), ProgramError> {
         let current = Self::finalize_global(issuance, now)?;
-        Self::update_user_with_current(user, current)?;
+        Self::update_user_with_current(issuance, user, current)?;
         Ok(())
     }    
 
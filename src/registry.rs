@@ -0,0 +1,169 @@
+// ==============================
+// src/registry.rs (issuance registry: head + paginated record pages)
+// BYTE-EXACT layouts:
+//   head   = 48 bytes  (PDA [registry, scope])
+//   page   = 8-byte header + n * 112-byte records (PDA [registry_page, scope, page_index])
+// scope = platform authority (every InitIssuance) or issuer (when distinct).
+// Pages grow by one record per InitIssuance via realloc, up to RECORDS_PER_PAGE.
+// ==============================
+#![forbid(unsafe_code)]
+
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{error::LockrionError, pda};
+
+pub const REGISTRY_VERSION: u8 = 1;
+pub const REGISTRY_HEAD_SIZE: usize = 48;
+pub const REGISTRY_PAGE_HEADER_SIZE: usize = 8;
+pub const REGISTRY_RECORD_SIZE: usize = 112;
+pub const RECORDS_PER_PAGE: u64 = 64;
+
+// ============================================================
+// HEAD (48 bytes exact)
+// ============================================================
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RegistryHead {
+    pub version: u8,                // 0
+    pub bump: u8,                   // 1
+    pub scope: Pubkey,              // 2..34
+    pub count: u64,                 // 34..42 (records across all pages)
+    pub reserved_padding: [u8; 6],  // 42..48
+}
+
+impl RegistryHead {
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        if input.len() != REGISTRY_HEAD_SIZE {
+            return Err(LockrionError::InvalidAccountSize.into());
+        }
+        if input[0] != REGISTRY_VERSION {
+            return Err(LockrionError::InvalidStateVersion.into());
+        }
+
+        Ok(Self {
+            version: input[0],
+            bump: input[1],
+            scope: Pubkey::new_from_array(input[2..34].try_into().unwrap()),
+            count: u64::from_le_bytes(input[34..42].try_into().unwrap()),
+            reserved_padding: input[42..48].try_into().unwrap(),
+        })
+    }
+
+    pub fn pack(&self, output: &mut [u8]) -> Result<(), ProgramError> {
+        if output.len() != REGISTRY_HEAD_SIZE {
+            return Err(LockrionError::InvalidAccountSize.into());
+        }
+
+        output[0] = self.version;
+        output[1] = self.bump;
+        output[2..34].copy_from_slice(self.scope.as_ref());
+        output[34..42].copy_from_slice(&self.count.to_le_bytes());
+        output[42..48].copy_from_slice(&self.reserved_padding);
+
+        Ok(())
+    }
+}
+
+// ============================================================
+// PAGE HEADER (8 bytes: version, bump, padding[2], page_index u32)
+// ============================================================
+
+pub fn pack_page_header(output: &mut [u8], bump: u8, page_index: u32) -> Result<(), ProgramError> {
+    if output.len() < REGISTRY_PAGE_HEADER_SIZE {
+        return Err(LockrionError::InvalidAccountSize.into());
+    }
+
+    output[0] = REGISTRY_VERSION;
+    output[1] = bump;
+    output[2..4].copy_from_slice(&[0u8; 2]);
+    output[4..8].copy_from_slice(&page_index.to_le_bytes());
+
+    Ok(())
+}
+
+// ============================================================
+// RECORD (112 bytes exact)
+// ============================================================
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RegistryRecord {
+    pub issuance: Pubkey,           // 0..32
+    pub lock_mint: Pubkey,          // 32..64
+    pub reward_mint: Pubkey,        // 64..96
+    pub start_ts: i64,              // 96..104
+    pub maturity_ts: i64,           // 104..112
+}
+
+impl RegistryRecord {
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        if input.len() != REGISTRY_RECORD_SIZE {
+            return Err(LockrionError::InvalidAccountSize.into());
+        }
+
+        Ok(Self {
+            issuance: Pubkey::new_from_array(input[0..32].try_into().unwrap()),
+            lock_mint: Pubkey::new_from_array(input[32..64].try_into().unwrap()),
+            reward_mint: Pubkey::new_from_array(input[64..96].try_into().unwrap()),
+            start_ts: i64::from_le_bytes(input[96..104].try_into().unwrap()),
+            maturity_ts: i64::from_le_bytes(input[104..112].try_into().unwrap()),
+        })
+    }
+
+    pub fn pack(&self, output: &mut [u8]) -> Result<(), ProgramError> {
+        if output.len() != REGISTRY_RECORD_SIZE {
+            return Err(LockrionError::InvalidAccountSize.into());
+        }
+
+        output[0..32].copy_from_slice(self.issuance.as_ref());
+        output[32..64].copy_from_slice(self.lock_mint.as_ref());
+        output[64..96].copy_from_slice(self.reward_mint.as_ref());
+        output[96..104].copy_from_slice(&self.start_ts.to_le_bytes());
+        output[104..112].copy_from_slice(&self.maturity_ts.to_le_bytes());
+
+        Ok(())
+    }
+}
+
+// ============================================================
+// PAGINATION
+// ============================================================
+
+/// Page that holds the record at `position` (0-based across the registry).
+pub fn page_index(position: u64) -> Result<u32, ProgramError> {
+    u32::try_from(position / RECORDS_PER_PAGE).map_err(|_| LockrionError::ArithmeticOverflow.into())
+}
+
+/// Exact page account size for `records` entries.
+pub fn page_size(records: u64) -> usize {
+    REGISTRY_PAGE_HEADER_SIZE + records as usize * REGISTRY_RECORD_SIZE
+}
+
+// ============================================================
+// CLIENT HELPERS
+// Fetch the head, then every page from page_addresses(), and decode each page
+// with page_records(); concatenated in page order this is InitIssuance order.
+// ============================================================
+
+/// All page PDAs currently in use for a registry holding `count` records.
+pub fn page_addresses(program_id: &Pubkey, scope: &Pubkey, count: u64) -> Vec<Pubkey> {
+    let pages = count.div_ceil(RECORDS_PER_PAGE) as u32;
+    (0..pages)
+        .map(|i| pda::derive_registry_page_pda(program_id, scope, i).0)
+        .collect()
+}
+
+/// Decodes the records of one page account.
+pub fn page_records(page: &[u8]) -> Result<Vec<RegistryRecord>, ProgramError> {
+    if page.len() < REGISTRY_PAGE_HEADER_SIZE {
+        return Err(LockrionError::InvalidAccountSize.into());
+    }
+    let records = page[REGISTRY_PAGE_HEADER_SIZE..].chunks_exact(REGISTRY_RECORD_SIZE);
+    if !records.remainder().is_empty() {
+        return Err(LockrionError::InvalidAccountSize.into());
+    }
+    if page[0] != REGISTRY_VERSION {
+        return Err(LockrionError::InvalidStateVersion.into());
+    }
+
+    records.map(RegistryRecord::unpack).collect()
+}
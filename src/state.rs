@@ -1,9 +1,14 @@
 // ==============================
 // src/state.rs
-// BYTE-EXACT layout per State Layout v1.1
+// BYTE-EXACT layouts:
+//   v1 = State Layout v1.1 (IssuanceStateV1 292 / UserStateV1 112 bytes)
+//   v3 = current layout    (IssuanceStateV3 556 / UserStateV3 232 bytes)
+// v2 never reached a deployment; v1 accounts migrate straight to v3.
+// v1 accounts are readable through load(); writing requires MigrateState.
 // ==============================
 #![forbid(unsafe_code)]
 
+use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{
     program_error::ProgramError,
     pubkey::Pubkey,
@@ -11,16 +16,56 @@ use solana_program::{
 
 use crate::error::LockrionError;
 
-pub const ISSUANCE_STATE_SIZE: usize = 292;
-pub const USER_STATE_SIZE: usize = 112;
-pub const STATE_VERSION: u8 = 1;
+pub const ISSUANCE_STATE_SIZE: usize = 556;
+pub const USER_STATE_SIZE: usize = 232;
+pub const STATE_VERSION: u8 = 3;
+
+pub const ISSUANCE_STATE_SIZE_V1: usize = 292;
+pub const USER_STATE_SIZE_V1: usize = 112;
+pub const STATE_VERSION_V1: u8 = 1;
+
+/// Current layouts; the processor only ever writes these.
+pub type IssuanceState = IssuanceStateV3;
+pub type UserState = UserStateV3;
+
+pub const MAX_MULTIPLIER_TIERS: usize = 4;
+const MULTIPLIER_TIER_SIZE: usize = 10;
+pub const MAX_LOCK_TIERS: usize = 4;
+const LOCK_TIER_SIZE: usize = 12;
+
+// ============================================================
+// EARLY-BIRD MULTIPLIER TIER (10 bytes: end_day u64, mult_bps u16)
+// ============================================================
+
+/// Deposits made while day_index < end_day get weight * mult_bps / 10_000.
+/// end_day == 0 marks an unused slot.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct MultiplierTier {
+    pub end_day: u64,
+    pub mult_bps: u16,
+}
+
+// ============================================================
+// LOCK TIER (12 bytes: unlock_day u64, mult_bps u16, penalty_bps u16)
+// ============================================================
+
+/// Commitment chosen at deposit. The position weighs mult_bps / 10_000 on top of
+/// the early-bird multiplier. unlock_day == 0 locks principal until maturity;
+/// otherwise it may be withdrawn from day_index unlock_day on, less penalty_bps.
+/// mult_bps == 0 marks an unused slot.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct LockTier {
+    pub unlock_day: u64,
+    pub mult_bps: u16,
+    pub penalty_bps: u16,
+}
 
 // ============================================================
-// ISSUANCE STATE (292 bytes exact)
+// ISSUANCE STATE V3 (556 bytes exact)
 // ============================================================
 
 #[derive(Clone, Debug, PartialEq, Eq)]
-pub struct IssuanceState {
+pub struct IssuanceStateV3 {
     pub version: u8,                // 0
     pub bump: u8,                   // 1
     pub issuer_address: Pubkey,     // 2..34
@@ -40,10 +85,36 @@ pub struct IssuanceState {
     pub reserve_funded: u8,         // 282
     pub sweep_executed: u8,         // 283
     pub reclaim_executed: u8,       // 284
-    pub reserved_padding: [u8; 7],  // 285..292
+    pub receipt_mode: u8,           // 285
+    pub receipt_mint_bump: u8,      // 286
+    pub terms_required: u8,         // 287 (deposits must acknowledge the metadata terms_hash)
+    pub reserved_padding: [u8; 4],  // 288..292
+    pub vesting_duration: i64,      // 292..300
+    pub total_weighted_locked: u128, // 300..316 (sum of user weighted_locked)
+    pub multiplier_schedule: [MultiplierTier; MAX_MULTIPLIER_TIERS], // 316..356
+    pub accounting_period: i64,     // 356..364 (seconds per day_index step)
+    pub prorated_tail: i64,         // 364..372 (seconds in the partial final period; 0 = whole periods)
+    pub total_claimed: u128,        // 372..388 (sum of fixed reward entitlements)
+    pub total_weight_claimed: u128, // 388..404 (sum of user_weight_accum behind them)
+    // settlement statistics (informational; never feed reward math)
+    pub participant_count: u64,     // 404..412 (user states opened by a first deposit)
+    pub claimed_count: u64,         // 412..420 (entitlements fixed)
+    pub withdrawn_count: u64,       // 420..428 (principal withdrawals)
+    pub total_withdrawn: u128,      // 428..444 (principal released, early-unlock penalties included)
+    pub attestation_program: Pubkey, // 444..476 (deposit gate owner; default = no gate)
+    pub attestation_schema: Pubkey, // 476..508
+    pub lock_tiers: [LockTier; MAX_LOCK_TIERS], // 508..556 (empty = single implicit 1x tier)
 }
 
-impl IssuanceState {
+impl IssuanceStateV3 {
+
+    /// Unified accessor: decodes either layout (by size + version byte) into v3.
+    pub fn load(input: &[u8]) -> Result<Self, ProgramError> {
+        if input.len() == ISSUANCE_STATE_SIZE_V1 {
+            return Ok(IssuanceStateV1::unpack(input)?.into());
+        }
+        Self::unpack(input)
+    }
 
     pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
         if input.len() != ISSUANCE_STATE_SIZE {
@@ -80,11 +151,49 @@ impl IssuanceState {
             reserve_funded: input[282],
             sweep_executed: input[283],
             reclaim_executed: input[284],
-            reserved_padding: input[285..292].try_into().unwrap(),
+            receipt_mode: input[285],
+            receipt_mint_bump: input[286],
+            terms_required: input[287],
+            reserved_padding: input[288..292].try_into().unwrap(),
+
+            vesting_duration: i64::from_le_bytes(input[292..300].try_into().unwrap()),
+
+            total_weighted_locked: u128::from_le_bytes(input[300..316].try_into().unwrap()),
+            multiplier_schedule: core::array::from_fn(|i| {
+                let o = 316 + i * MULTIPLIER_TIER_SIZE;
+                MultiplierTier {
+                    end_day: u64::from_le_bytes(input[o..o + 8].try_into().unwrap()),
+                    mult_bps: u16::from_le_bytes(input[o + 8..o + 10].try_into().unwrap()),
+                }
+            }),
+
+            accounting_period: i64::from_le_bytes(input[356..364].try_into().unwrap()),
+            prorated_tail: i64::from_le_bytes(input[364..372].try_into().unwrap()),
+
+            total_claimed: u128::from_le_bytes(input[372..388].try_into().unwrap()),
+            total_weight_claimed: u128::from_le_bytes(input[388..404].try_into().unwrap()),
+
+            participant_count: u64::from_le_bytes(input[404..412].try_into().unwrap()),
+            claimed_count: u64::from_le_bytes(input[412..420].try_into().unwrap()),
+            withdrawn_count: u64::from_le_bytes(input[420..428].try_into().unwrap()),
+            total_withdrawn: u128::from_le_bytes(input[428..444].try_into().unwrap()),
+            attestation_program: Pubkey::new_from_array(input[444..476].try_into().unwrap()),
+            attestation_schema: Pubkey::new_from_array(input[476..508].try_into().unwrap()),
+            lock_tiers: core::array::from_fn(|i| {
+                let o = 508 + i * LOCK_TIER_SIZE;
+                LockTier {
+                    unlock_day: u64::from_le_bytes(input[o..o + 8].try_into().unwrap()),
+                    mult_bps: u16::from_le_bytes(input[o + 8..o + 10].try_into().unwrap()),
+                    penalty_bps: u16::from_le_bytes(input[o + 10..o + 12].try_into().unwrap()),
+                }
+            }),
         })
     }
 
     pub fn pack(&self, output: &mut [u8]) -> Result<(), ProgramError> {
+        if output.len() == ISSUANCE_STATE_SIZE_V1 {
+            return Err(LockrionError::StateMigrationRequired.into());
+        }
         if output.len() != ISSUANCE_STATE_SIZE {
             return Err(LockrionError::InvalidAccountSize.into());
         }
@@ -116,8 +225,39 @@ impl IssuanceState {
         output[282] = self.reserve_funded;
         output[283] = self.sweep_executed;
         output[284] = self.reclaim_executed;
+        output[285] = self.receipt_mode;
+        output[286] = self.receipt_mint_bump;
 
-        output[285..292].copy_from_slice(&self.reserved_padding);
+        output[287] = self.terms_required;
+        output[288..292].copy_from_slice(&self.reserved_padding);
+
+        output[292..300].copy_from_slice(&self.vesting_duration.to_le_bytes());
+
+        output[300..316].copy_from_slice(&self.total_weighted_locked.to_le_bytes());
+        for (i, tier) in self.multiplier_schedule.iter().enumerate() {
+            let o = 316 + i * MULTIPLIER_TIER_SIZE;
+            output[o..o + 8].copy_from_slice(&tier.end_day.to_le_bytes());
+            output[o + 8..o + 10].copy_from_slice(&tier.mult_bps.to_le_bytes());
+        }
+
+        output[356..364].copy_from_slice(&self.accounting_period.to_le_bytes());
+        output[364..372].copy_from_slice(&self.prorated_tail.to_le_bytes());
+
+        output[372..388].copy_from_slice(&self.total_claimed.to_le_bytes());
+        output[388..404].copy_from_slice(&self.total_weight_claimed.to_le_bytes());
+
+        output[404..412].copy_from_slice(&self.participant_count.to_le_bytes());
+        output[412..420].copy_from_slice(&self.claimed_count.to_le_bytes());
+        output[420..428].copy_from_slice(&self.withdrawn_count.to_le_bytes());
+        output[428..444].copy_from_slice(&self.total_withdrawn.to_le_bytes());
+        output[444..476].copy_from_slice(self.attestation_program.as_ref());
+        output[476..508].copy_from_slice(self.attestation_schema.as_ref());
+        for (i, tier) in self.lock_tiers.iter().enumerate() {
+            let o = 508 + i * LOCK_TIER_SIZE;
+            output[o..o + 8].copy_from_slice(&tier.unlock_day.to_le_bytes());
+            output[o + 8..o + 10].copy_from_slice(&tier.mult_bps.to_le_bytes());
+            output[o + 10..o + 12].copy_from_slice(&tier.penalty_bps.to_le_bytes());
+        }
 
         Ok(())
     }
@@ -125,14 +265,175 @@ impl IssuanceState {
     #[inline] pub fn is_reserve_funded(&self) -> bool { self.reserve_funded == 1 }
     #[inline] pub fn is_sweep_executed(&self) -> bool { self.sweep_executed == 1 }
     #[inline] pub fn is_reclaim_executed(&self) -> bool { self.reclaim_executed == 1 }
+    #[inline] pub fn is_receipt_mode(&self) -> bool { self.receipt_mode == 1 }
+    #[inline] pub fn is_terms_required(&self) -> bool { self.terms_required == 1 }
+    #[inline] pub fn is_attestation_required(&self) -> bool { self.attestation_program != Pubkey::default() }
+    /// Lock tiers configured (otherwise one implicit 1x tier held to maturity).
+    #[inline] pub fn has_lock_tiers(&self) -> bool { self.lock_tiers.iter().any(|t| t.mult_bps != 0) }
+    /// "Lock TOKEN, earn TOKEN": principal and reward share a mint (never an escrow).
+    #[inline] pub fn is_same_mint(&self) -> bool { self.lock_mint == self.reward_mint }
+    /// lock_mint is wrapped SOL: deposits may wrap lamports, withdrawals unwrap them.
+    #[inline] pub fn is_native_lock(&self) -> bool { self.lock_mint == spl_token::native_mint::id() }
+}
+
+// ============================================================
+// ISSUANCE STATE V1 (292 bytes exact, read + migrate only)
+// ============================================================
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IssuanceStateV1 {
+    pub version: u8,                // 0
+    pub bump: u8,                   // 1
+    pub issuer_address: Pubkey,     // 2..34
+    pub lock_mint: Pubkey,          // 34..66
+    pub reward_mint: Pubkey,        // 66..98
+    pub deposit_escrow: Pubkey,     // 98..130
+    pub reward_escrow: Pubkey,      // 130..162
+    pub platform_treasury: Pubkey,  // 162..194
+    pub reserve_total: u128,        // 194..210
+    pub start_ts: i64,              // 210..218
+    pub maturity_ts: i64,           // 218..226
+    pub claim_window: i64,          // 226..234
+    pub final_day_index: u64,       // 234..242
+    pub total_locked: u128,         // 242..258
+    pub total_weight_accum: u128,   // 258..274
+    pub last_day_index: u64,        // 274..282
+    pub reserve_funded: u8,         // 282
+    pub sweep_executed: u8,         // 283
+    pub reclaim_executed: u8,       // 284
+    pub reserved_padding: [u8; 7],  // 285..292
+}
+
+impl IssuanceStateV1 {
+
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        if input.len() != ISSUANCE_STATE_SIZE_V1 {
+            return Err(LockrionError::InvalidAccountSize.into());
+        }
+
+        let version = input[0];
+        if version != STATE_VERSION_V1 {
+            return Err(LockrionError::InvalidStateVersion.into());
+        }
+
+        Ok(Self {
+            version,
+            bump: input[1],
+            issuer_address: Pubkey::new_from_array(input[2..34].try_into().unwrap()),
+            lock_mint: Pubkey::new_from_array(input[34..66].try_into().unwrap()),
+            reward_mint: Pubkey::new_from_array(input[66..98].try_into().unwrap()),
+            deposit_escrow: Pubkey::new_from_array(input[98..130].try_into().unwrap()),
+            reward_escrow: Pubkey::new_from_array(input[130..162].try_into().unwrap()),
+            platform_treasury: Pubkey::new_from_array(input[162..194].try_into().unwrap()),
+
+            reserve_total: u128::from_le_bytes(input[194..210].try_into().unwrap()),
+            start_ts: i64::from_le_bytes(input[210..218].try_into().unwrap()),
+            maturity_ts: i64::from_le_bytes(input[218..226].try_into().unwrap()),
+            claim_window: i64::from_le_bytes(input[226..234].try_into().unwrap()),
+            final_day_index: u64::from_le_bytes(input[234..242].try_into().unwrap()),
+
+            total_locked: u128::from_le_bytes(input[242..258].try_into().unwrap()),
+            total_weight_accum: u128::from_le_bytes(input[258..274].try_into().unwrap()),
+            last_day_index: u64::from_le_bytes(input[274..282].try_into().unwrap()),
+
+            reserve_funded: input[282],
+            sweep_executed: input[283],
+            reclaim_executed: input[284],
+            reserved_padding: input[285..292].try_into().unwrap(),
+        })
+    }
+
+    pub fn pack(&self, output: &mut [u8]) -> Result<(), ProgramError> {
+        if output.len() != ISSUANCE_STATE_SIZE_V1 {
+            return Err(LockrionError::InvalidAccountSize.into());
+        }
+
+        if self.version != STATE_VERSION_V1 {
+            return Err(LockrionError::InvalidStateVersion.into());
+        }
+
+        output[0] = self.version;
+        output[1] = self.bump;
+
+        output[2..34].copy_from_slice(self.issuer_address.as_ref());
+        output[34..66].copy_from_slice(self.lock_mint.as_ref());
+        output[66..98].copy_from_slice(self.reward_mint.as_ref());
+        output[98..130].copy_from_slice(self.deposit_escrow.as_ref());
+        output[130..162].copy_from_slice(self.reward_escrow.as_ref());
+        output[162..194].copy_from_slice(self.platform_treasury.as_ref());
+
+        output[194..210].copy_from_slice(&self.reserve_total.to_le_bytes());
+        output[210..218].copy_from_slice(&self.start_ts.to_le_bytes());
+        output[218..226].copy_from_slice(&self.maturity_ts.to_le_bytes());
+        output[226..234].copy_from_slice(&self.claim_window.to_le_bytes());
+        output[234..242].copy_from_slice(&self.final_day_index.to_le_bytes());
+
+        output[242..258].copy_from_slice(&self.total_locked.to_le_bytes());
+        output[258..274].copy_from_slice(&self.total_weight_accum.to_le_bytes());
+        output[274..282].copy_from_slice(&self.last_day_index.to_le_bytes());
+
+        output[282] = self.reserve_funded;
+        output[283] = self.sweep_executed;
+        output[284] = self.reclaim_executed;
+        output[285..292].copy_from_slice(&self.reserved_padding);
+
+        Ok(())
+    }
+}
+
+/// v1 semantics expressed in v3 fields: weight 1x, daily periods, no vesting,
+/// no receipts. Statistics start at zero. total_weight_claimed = 0 also keeps the
+/// exact-remainder rule off while any v1-era claimant's weight is still counted.
+impl From<IssuanceStateV1> for IssuanceStateV3 {
+    fn from(v1: IssuanceStateV1) -> Self {
+        Self {
+            version: STATE_VERSION,
+            bump: v1.bump,
+            issuer_address: v1.issuer_address,
+            lock_mint: v1.lock_mint,
+            reward_mint: v1.reward_mint,
+            deposit_escrow: v1.deposit_escrow,
+            reward_escrow: v1.reward_escrow,
+            platform_treasury: v1.platform_treasury,
+            reserve_total: v1.reserve_total,
+            start_ts: v1.start_ts,
+            maturity_ts: v1.maturity_ts,
+            claim_window: v1.claim_window,
+            final_day_index: v1.final_day_index,
+            total_locked: v1.total_locked,
+            total_weight_accum: v1.total_weight_accum,
+            last_day_index: v1.last_day_index,
+            reserve_funded: v1.reserve_funded,
+            sweep_executed: v1.sweep_executed,
+            reclaim_executed: v1.reclaim_executed,
+            receipt_mode: 0,
+            receipt_mint_bump: 0,
+            terms_required: 0,
+            reserved_padding: [0u8; 4],
+            vesting_duration: 0,
+            total_weighted_locked: v1.total_locked,
+            multiplier_schedule: Default::default(),
+            accounting_period: crate::accumulator::DEFAULT_ACCOUNTING_PERIOD,
+            prorated_tail: 0,
+            total_claimed: 0,
+            total_weight_claimed: 0,
+            participant_count: 0,
+            claimed_count: 0,
+            withdrawn_count: 0,
+            total_withdrawn: 0,
+            attestation_program: Pubkey::default(),
+            attestation_schema: Pubkey::default(),
+            lock_tiers: Default::default(),
+        }
+    }
 }
 
 // ============================================================
-// USER STATE (112 bytes exact)
+// USER STATE V3 (232 bytes exact)
 // ============================================================
 
 #[derive(Clone, Debug, PartialEq, Eq)]
-pub struct UserState {
+pub struct UserStateV3 {
     pub version: u8,                // 0
     pub bump: u8,                   // 1
     pub issuance: Pubkey,           // 2..34
@@ -141,10 +442,25 @@ pub struct UserState {
     pub user_weight_accum: u128,    // 82..98
     pub user_last_day_index: u64,   // 98..106
     pub reward_claimed: u8,         // 106
-    pub reserved_padding: [u8; 5],  // 107..112
+    pub lock_tier: u8,              // 107 (index into IssuanceState.lock_tiers)
+    pub reserved_padding: [u8; 4],  // 108..112
+    pub reward_total: u128,         // 112..128
+    pub reward_withdrawn: u128,     // 128..144
+    pub weighted_locked: u128,      // 144..160 (locked_amount with deposit-day multipliers)
+    pub rent_payer: Pubkey,         // 160..192 (funded create_account; rent refund target)
+    pub terms_hash: [u8; 32],       // 192..224 (last acknowledged terms hash; zero = none)
+    pub terms_ack_ts: i64,          // 224..232 (time of that acknowledgment)
 }
 
-impl UserState {
+impl UserStateV3 {
+
+    /// Unified accessor: decodes either layout (by size + version byte) into v3.
+    pub fn load(input: &[u8]) -> Result<Self, ProgramError> {
+        if input.len() == USER_STATE_SIZE_V1 {
+            return Ok(UserStateV1::unpack(input)?.into());
+        }
+        Self::unpack(input)
+    }
 
     pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
         if input.len() != USER_STATE_SIZE {
@@ -165,11 +481,21 @@ impl UserState {
             user_weight_accum: u128::from_le_bytes(input[82..98].try_into().unwrap()),
             user_last_day_index: u64::from_le_bytes(input[98..106].try_into().unwrap()),
             reward_claimed: input[106],
-            reserved_padding: input[107..112].try_into().unwrap(),
+            lock_tier: input[107],
+            reserved_padding: input[108..112].try_into().unwrap(),
+            reward_total: u128::from_le_bytes(input[112..128].try_into().unwrap()),
+            reward_withdrawn: u128::from_le_bytes(input[128..144].try_into().unwrap()),
+            weighted_locked: u128::from_le_bytes(input[144..160].try_into().unwrap()),
+            rent_payer: Pubkey::new_from_array(input[160..192].try_into().unwrap()),
+            terms_hash: input[192..224].try_into().unwrap(),
+            terms_ack_ts: i64::from_le_bytes(input[224..232].try_into().unwrap()),
         })
     }
 
     pub fn pack(&self, output: &mut [u8]) -> Result<(), ProgramError> {
+        if output.len() == USER_STATE_SIZE_V1 {
+            return Err(LockrionError::StateMigrationRequired.into());
+        }
         if output.len() != USER_STATE_SIZE {
             return Err(LockrionError::InvalidAccountSize.into());
         }
@@ -189,10 +515,113 @@ impl UserState {
         output[98..106].copy_from_slice(&self.user_last_day_index.to_le_bytes());
 
         output[106] = self.reward_claimed;
-        output[107..112].copy_from_slice(&self.reserved_padding);
+        output[107] = self.lock_tier;
+        output[108..112].copy_from_slice(&self.reserved_padding);
+        output[112..128].copy_from_slice(&self.reward_total.to_le_bytes());
+        output[128..144].copy_from_slice(&self.reward_withdrawn.to_le_bytes());
+        output[144..160].copy_from_slice(&self.weighted_locked.to_le_bytes());
+        output[160..192].copy_from_slice(self.rent_payer.as_ref());
+        output[192..224].copy_from_slice(&self.terms_hash);
+        output[224..232].copy_from_slice(&self.terms_ack_ts.to_le_bytes());
 
         Ok(())
     }
 
     #[inline] pub fn is_reward_claimed(&self) -> bool { self.reward_claimed == 1 }
-}
\ No newline at end of file
+    /// Entitlement fixed and fully paid out (always true right after a non-vesting claim).
+    #[inline] pub fn is_reward_settled(&self) -> bool { self.is_reward_claimed() && self.reward_withdrawn == self.reward_total }
+}
+
+// ============================================================
+// USER STATE V1 (112 bytes exact, read + migrate only)
+// ============================================================
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UserStateV1 {
+    pub version: u8,                // 0
+    pub bump: u8,                   // 1
+    pub issuance: Pubkey,           // 2..34
+    pub participant: Pubkey,        // 34..66
+    pub locked_amount: u128,        // 66..82
+    pub user_weight_accum: u128,    // 82..98
+    pub user_last_day_index: u64,   // 98..106
+    pub reward_claimed: u8,         // 106
+    pub reserved_padding: [u8; 5],  // 107..112
+}
+
+impl UserStateV1 {
+
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        if input.len() != USER_STATE_SIZE_V1 {
+            return Err(LockrionError::InvalidAccountSize.into());
+        }
+
+        let version = input[0];
+        if version != STATE_VERSION_V1 {
+            return Err(LockrionError::InvalidStateVersion.into());
+        }
+
+        Ok(Self {
+            version,
+            bump: input[1],
+            issuance: Pubkey::new_from_array(input[2..34].try_into().unwrap()),
+            participant: Pubkey::new_from_array(input[34..66].try_into().unwrap()),
+            locked_amount: u128::from_le_bytes(input[66..82].try_into().unwrap()),
+            user_weight_accum: u128::from_le_bytes(input[82..98].try_into().unwrap()),
+            user_last_day_index: u64::from_le_bytes(input[98..106].try_into().unwrap()),
+            reward_claimed: input[106],
+            reserved_padding: input[107..112].try_into().unwrap(),
+        })
+    }
+
+    pub fn pack(&self, output: &mut [u8]) -> Result<(), ProgramError> {
+        if output.len() != USER_STATE_SIZE_V1 {
+            return Err(LockrionError::InvalidAccountSize.into());
+        }
+
+        if self.version != STATE_VERSION_V1 {
+            return Err(LockrionError::InvalidStateVersion.into());
+        }
+
+        output[0] = self.version;
+        output[1] = self.bump;
+
+        output[2..34].copy_from_slice(self.issuance.as_ref());
+        output[34..66].copy_from_slice(self.participant.as_ref());
+
+        output[66..82].copy_from_slice(&self.locked_amount.to_le_bytes());
+        output[82..98].copy_from_slice(&self.user_weight_accum.to_le_bytes());
+        output[98..106].copy_from_slice(&self.user_last_day_index.to_le_bytes());
+
+        output[106] = self.reward_claimed;
+        output[107..112].copy_from_slice(&self.reserved_padding);
+
+        Ok(())
+    }
+}
+
+/// A v1 claim paid the full reward at once: reward_total = reward_withdrawn = 0
+/// keeps is_reward_settled() true for claimed users. v1 deposits were self-funded,
+/// so the participant is the rent payer.
+impl From<UserStateV1> for UserStateV3 {
+    fn from(v1: UserStateV1) -> Self {
+        Self {
+            version: STATE_VERSION,
+            bump: v1.bump,
+            issuance: v1.issuance,
+            participant: v1.participant,
+            locked_amount: v1.locked_amount,
+            user_weight_accum: v1.user_weight_accum,
+            user_last_day_index: v1.user_last_day_index,
+            reward_claimed: v1.reward_claimed,
+            lock_tier: 0,
+            reserved_padding: [0u8; 4],
+            reward_total: 0,
+            reward_withdrawn: 0,
+            weighted_locked: v1.locked_amount,
+            rent_payer: v1.participant,
+            terms_hash: [0u8; 32],
+            terms_ack_ts: 0,
+        }
+    }
+}
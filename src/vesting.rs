@@ -0,0 +1,39 @@
+// ==============================
+// src/vesting.rs (linear post-maturity reward vesting)
+// ==============================
+#![forbid(unsafe_code)]
+
+use crate::error::LockrionError;
+
+/// Portion of `reward_total` released at `now`.
+/// Linear over [maturity_ts, maturity_ts + vesting_duration); vesting_duration == 0 => all at maturity.
+pub fn vested_amount(
+    reward_total: u128,
+    maturity_ts: i64,
+    vesting_duration: i64,
+    now: i64,
+) -> Result<u128, LockrionError> {
+    if vesting_duration < 0 {
+        return Err(LockrionError::InvariantViolation);
+    }
+    if now < maturity_ts {
+        return Ok(0);
+    }
+    if vesting_duration == 0 {
+        return Ok(reward_total);
+    }
+
+    let elapsed = now
+        .checked_sub(maturity_ts)
+        .ok_or(LockrionError::ArithmeticUnderflow)?;
+    if elapsed >= vesting_duration {
+        return Ok(reward_total);
+    }
+
+    // floor(reward_total * elapsed / vesting_duration), elapsed in [0, vesting_duration)
+    let num = reward_total
+        .checked_mul(elapsed as u128)
+        .ok_or(LockrionError::ArithmeticOverflow)?;
+    num.checked_div(vesting_duration as u128)
+        .ok_or(LockrionError::DivisionByZero)
+}
@@ -0,0 +1,35 @@
+// ==============================
+// src/view.rs (read-only views returned via return data)
+// ==============================
+#![forbid(unsafe_code)]
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Lifecycle phase of an issuance at the queried timestamp.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum Phase {
+    /// Reserve not funded yet (deposits rejected).
+    Unfunded,
+    /// Funded, now < start_ts.
+    NotStarted,
+    /// start_ts <= now < maturity_ts.
+    Deposit,
+    /// maturity_ts <= now < maturity_ts + vesting_duration + claim_window.
+    Claim,
+    /// Claim window over; only withdraw / sweep remain.
+    Closed,
+}
+
+/// Quote return data (Borsh).
+#[derive(Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct QuoteView {
+    pub phase: Phase,
+    /// Total entitlement: the fixed reward_total once claimed; otherwise the
+    /// share at the final day assuming current locked amounts stay unchanged.
+    pub projected_reward: u64,
+    /// ClaimReward would succeed now.
+    pub claimable: bool,
+    /// Principal WithdrawDeposit would return now: 0 before maturity unless the
+    /// lock tier has unlocked, then locked_amount less the tier penalty.
+    pub withdrawable: u64,
+}
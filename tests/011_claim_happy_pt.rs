@@ -12,7 +12,7 @@ use solana_sdk::{
 };
 use spl_token::state::{Account as TokenAccount, Mint};
 
-use lockrion_issuance_v1_1::{instruction::LockrionInstruction, pda};
+use lockrion_issuance_v1_1::{instruction::{IssuanceParams, LockrionInstruction}, pda};
 use solana_sdk::signature::read_keypair_file;
 
 async fn send_tx(
@@ -220,6 +220,7 @@ async fn claim_happy_program_test() {
         reserve_total,
         start_ts,
         maturity_ts,
+        params: IssuanceParams::default(),
     }
     .try_to_vec()
     .unwrap();
@@ -236,6 +237,8 @@ async fn claim_happy_program_test() {
             AccountMeta::new_readonly(reward_escrow.pubkey(), false),
             AccountMeta::new_readonly(platform.pubkey(), false),
             AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new(pda::derive_registry_pda(&program_id, &platform.pubkey()).0, false),
+            AccountMeta::new(pda::derive_registry_page_pda(&program_id, &platform.pubkey(), 0).0, false),
         ],
     );
     send_tx(&mut ctx, vec![init_ix], &[&platform]).await;
@@ -17,7 +17,7 @@ use spl_token::state::{Account as TokenAccount, Mint};
 
 use lockrion_issuance_v1_1::{
     error::LockrionError,
-    instruction::LockrionInstruction,
+    instruction::{IssuanceParams, LockrionInstruction},
     pda,
 };
 
@@ -218,7 +218,7 @@ async fn claim_double_rejected_program_test() {
     mint_to(&mut ctx, &lock_mint.pubkey(), &participant_lock.pubkey(), &mint_auth, deposit_amount).await;
 
     // init_issuance (signer = PLATFORM)
-    let init_data = LockrionInstruction::InitIssuance { reserve_total, start_ts, maturity_ts }
+    let init_data = LockrionInstruction::InitIssuance { reserve_total, start_ts, maturity_ts, params: IssuanceParams::default() }
         .try_to_vec()
         .unwrap();
 
@@ -234,6 +234,8 @@ async fn claim_double_rejected_program_test() {
             AccountMeta::new_readonly(reward_escrow.pubkey(), false),
             AccountMeta::new_readonly(platform.pubkey(), false), // platform_treasury
             AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new(pda::derive_registry_pda(&program_id, &platform.pubkey()).0, false),
+            AccountMeta::new(pda::derive_registry_page_pda(&program_id, &platform.pubkey(), 0).0, false),
         ],
     );
     send_tx_ok(&mut ctx, vec![init_ix], &[&platform]).await;
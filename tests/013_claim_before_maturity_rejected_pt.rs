@@ -15,7 +15,7 @@ use spl_token::state::{Account as TokenAccount, Mint};
 
 use lockrion_issuance_v1_1::{
     error::LockrionError,
-    instruction::LockrionInstruction,
+    instruction::{IssuanceParams, LockrionInstruction},
     pda,
 };
 
@@ -212,7 +212,7 @@ send_tx_ok(&mut ctx, vec![airdrop_ix], &[]).await;
     mint_to(&mut ctx, &lock_mint.pubkey(), &participant_lock.pubkey(), &mint_auth, deposit_amount).await;
 
     // init_issuance
-    let init_data = LockrionInstruction::InitIssuance { reserve_total, start_ts, maturity_ts }
+    let init_data = LockrionInstruction::InitIssuance { reserve_total, start_ts, maturity_ts, params: IssuanceParams::default() }
         .try_to_vec()
         .unwrap();
 
@@ -228,6 +228,8 @@ send_tx_ok(&mut ctx, vec![airdrop_ix], &[]).await;
                 AccountMeta::new_readonly(reward_escrow.pubkey(), false),
                 AccountMeta::new_readonly(payer_pk, false),
                 AccountMeta::new_readonly(solana_program::system_program::id(), false),
+                AccountMeta::new(pda::derive_registry_pda(&program_id, &platform.pubkey()).0, false),
+                AccountMeta::new(pda::derive_registry_page_pda(&program_id, &platform.pubkey(), 0).0, false),
             ],
     );
     send_tx_ok(&mut ctx, vec![init_ix], &[&platform]).await;
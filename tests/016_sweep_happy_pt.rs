@@ -13,7 +13,7 @@ use solana_sdk::{
 };
 use spl_token::state::{Account as TokenAccount, Mint};
 
-use lockrion_issuance_v1_1::{instruction::LockrionInstruction, pda};
+use lockrion_issuance_v1_1::{instruction::{IssuanceParams, LockrionInstruction}, pda};
 
 async fn send_tx_ok(ctx: &mut ProgramTestContext, ixs: Vec<Instruction>, extra_signers: &[&Keypair]) {
     let payer_pk = ctx.payer.pubkey();
@@ -165,7 +165,7 @@ async fn sweep_happy_program_test() {
     mint_to(&mut ctx, &lock_mint.pubkey(), &participant_lock.pubkey(), &mint_auth, deposit_amount).await;
 
     // init_issuance (signer = PLATFORM)
-    let init_data = LockrionInstruction::InitIssuance { reserve_total, start_ts, maturity_ts }
+    let init_data = LockrionInstruction::InitIssuance { reserve_total, start_ts, maturity_ts, params: IssuanceParams::default() }
         .try_to_vec()
         .unwrap();
 
@@ -181,6 +181,8 @@ async fn sweep_happy_program_test() {
             AccountMeta::new_readonly(reward_escrow.pubkey(), false),
             AccountMeta::new_readonly(platform_treasury.pubkey(), false),
             AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new(pda::derive_registry_pda(&program_id, &platform.pubkey()).0, false),
+            AccountMeta::new(pda::derive_registry_page_pda(&program_id, &platform.pubkey(), 0).0, false),
         ],
     );
     send_tx_ok(&mut ctx, vec![init_ix], &[&platform]).await;
@@ -15,7 +15,7 @@ use spl_token::state::{Account as TokenAccount, Mint};
 
 use lockrion_issuance_v1_1::{
     error::LockrionError,
-    instruction::LockrionInstruction,
+    instruction::{IssuanceParams, LockrionInstruction},
     pda,
 };
 
@@ -195,7 +195,7 @@ async fn reclaim_double_rejected_pt() {
     // init issuance (signer = PLATFORM)
     let init_ix = mk_ix(
         program_id,
-        LockrionInstruction::InitIssuance { reserve_total, start_ts, maturity_ts }
+        LockrionInstruction::InitIssuance { reserve_total, start_ts, maturity_ts, params: IssuanceParams::default() }
             .try_to_vec().unwrap(),
         vec![
             AccountMeta::new(platform.pubkey(), true),
@@ -206,6 +206,8 @@ async fn reclaim_double_rejected_pt() {
             AccountMeta::new_readonly(reward_escrow.pubkey(), false),
             AccountMeta::new_readonly(platform_treasury.pubkey(), false),
             AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new(pda::derive_registry_pda(&program_id, &platform.pubkey()).0, false),
+            AccountMeta::new(pda::derive_registry_page_pda(&program_id, &platform.pubkey(), 0).0, false),
         ],
     );
     send_ok(&mut ctx, vec![init_ix], &[&platform]).await;
@@ -14,7 +14,7 @@ use solana_sdk::{
 use spl_token::state::{Account as TokenAccount, Mint};
 
 use lockrion_issuance_v1_1::{
-    instruction::LockrionInstruction,
+    instruction::{IssuanceParams, LockrionInstruction},
     pda,
     state::{IssuanceState, UserState},
 };
@@ -198,7 +198,7 @@ async fn deposit_atomicity_cpi_fail_pt() {
     // init (signer = PLATFORM)
     let init_ix = mk_ix(
         program_id,
-        LockrionInstruction::InitIssuance { reserve_total, start_ts, maturity_ts }
+        LockrionInstruction::InitIssuance { reserve_total, start_ts, maturity_ts, params: IssuanceParams::default() }
             .try_to_vec()
             .unwrap(),
         vec![
@@ -210,6 +210,8 @@ async fn deposit_atomicity_cpi_fail_pt() {
             AccountMeta::new_readonly(reward_escrow.pubkey(), false),
             AccountMeta::new_readonly(platform_treasury.pubkey(), false),
             AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new(pda::derive_registry_pda(&program_id, &platform.pubkey()).0, false),
+            AccountMeta::new(pda::derive_registry_page_pda(&program_id, &platform.pubkey(), 0).0, false),
         ],
     );
     send_ok(&mut ctx, vec![init_ix], &[&platform]).await;
@@ -158,7 +158,7 @@ async fn arithmetic_overflow_rejected_pt() {
     let final_day_index: u64 = ((maturity_ts - start_ts) / 86_400) as u64;
 
     let issuance = IssuanceState {
-        version: 1,
+        version: lockrion_issuance_v1_1::state::STATE_VERSION,
         bump,
         issuer_address: issuer_pubkey,
 
@@ -181,7 +181,24 @@ async fn arithmetic_overflow_rejected_pt() {
         reserve_funded: 1, // allow deposit
         sweep_executed: 0,
         reclaim_executed: 0,
-        reserved_padding: [0u8; 7],
+        receipt_mode: 0,
+        receipt_mint_bump: 0,
+        terms_required: 0,
+        reserved_padding: [0u8; 4],
+        vesting_duration: 0,
+        total_weighted_locked: u128::MAX,
+        multiplier_schedule: Default::default(),
+        accounting_period: 86_400,
+        prorated_tail: 0,
+        total_claimed: 0,
+        total_weight_claimed: 0,
+        participant_count: 0,
+        claimed_count: 0,
+        withdrawn_count: 0,
+        total_withdrawn: 0,
+        attestation_program: Default::default(),
+        attestation_schema: Default::default(),
+        lock_tiers: Default::default(),
     };
 
     let mut issuance_data = vec![0u8; lockrion_issuance_v1_1::state::ISSUANCE_STATE_SIZE];
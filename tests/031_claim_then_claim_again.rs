@@ -14,7 +14,7 @@ use spl_token::state::{Account as TokenAccount, Mint};
 
 use lockrion_issuance_v1_1::{
     error::LockrionError,
-    instruction::LockrionInstruction,
+    instruction::{IssuanceParams, LockrionInstruction},
     pda,
 };
 
@@ -272,6 +272,7 @@ async fn claim_then_claim_again_pt() {
             reserve_total,
             start_ts,
             maturity_ts,
+            params: IssuanceParams::default(),
         }
         .try_to_vec()
         .unwrap(),
@@ -285,6 +286,8 @@ async fn claim_then_claim_again_pt() {
             AccountMeta::new_readonly(reward_escrow.pubkey(), false),
             AccountMeta::new_readonly(platform_treasury.pubkey(), false),
             AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new(pda::derive_registry_pda(&program_id, &platform.pubkey()).0, false),
+            AccountMeta::new(pda::derive_registry_page_pda(&program_id, &platform.pubkey(), 0).0, false),
         ],
     );
     send_tx(&mut ctx, vec![init_ix], &[&platform]).await;
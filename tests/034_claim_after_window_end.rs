@@ -15,7 +15,7 @@ use solana_sdk::{
 use spl_token::state::{Account as TokenAccount, Mint};
 use solana_program::program_pack::Pack;
 
-use lockrion_issuance_v1_1::{instruction::LockrionInstruction, pda};
+use lockrion_issuance_v1_1::{instruction::{IssuanceParams, LockrionInstruction}, pda};
 
 async fn send_tx(ctx: &mut ProgramTestContext, ixs: Vec<Instruction>, extra_signers: &[&Keypair]) {
     let payer_pk = ctx.payer.pubkey();
@@ -223,6 +223,7 @@ async fn claim_after_window_end() {
             reserve_total,
             start_ts,
             maturity_ts,
+            params: IssuanceParams::default(),
         }
         .try_to_vec()
         .unwrap(),
@@ -235,6 +236,8 @@ async fn claim_after_window_end() {
             AccountMeta::new_readonly(reward_escrow.pubkey(), false),
             AccountMeta::new_readonly(platform_treasury.pubkey(), false),
             AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new(pda::derive_registry_pda(&program_id, &platform.pubkey()).0, false),
+            AccountMeta::new(pda::derive_registry_page_pda(&program_id, &platform.pubkey(), 0).0, false),
         ],
     );
     send_tx(&mut ctx, vec![init_ix], &[&platform]).await;
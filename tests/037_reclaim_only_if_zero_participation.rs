@@ -15,7 +15,7 @@ use solana_sdk::{
 };
 use spl_token::state::{Account as TokenAccount, Mint};
 
-use lockrion_issuance_v1_1::{instruction::LockrionInstruction, pda};
+use lockrion_issuance_v1_1::{instruction::{IssuanceParams, LockrionInstruction}, pda};
 
 async fn send_tx(ctx: &mut ProgramTestContext, ixs: Vec<Instruction>, extra_signers: &[&Keypair]) {
     let payer_pk = ctx.payer.pubkey();
@@ -206,6 +206,7 @@ async fn reclaim_only_if_zero_participation() {
             reserve_total,
             start_ts,
             maturity_ts,
+            params: IssuanceParams::default(),
         }
         .try_to_vec()
         .unwrap(),
@@ -218,6 +219,8 @@ async fn reclaim_only_if_zero_participation() {
             AccountMeta::new_readonly(reward_escrow.pubkey(), false),
             AccountMeta::new_readonly(platform_treasury.pubkey(), false),
             AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new(pda::derive_registry_pda(&program_id, &issuer_pk).0, false),
+            AccountMeta::new(pda::derive_registry_page_pda(&program_id, &issuer_pk, 0).0, false),
         ],
     );
     send_tx(&mut ctx, vec![init_ix], &[&platform]).await;
@@ -303,6 +306,7 @@ async fn reclaim_only_if_zero_participation() {
             reserve_total: reserve_total2,
             start_ts: start_ts2,
             maturity_ts: maturity_ts2,
+            params: IssuanceParams::default(),
         }
         .try_to_vec()
         .unwrap(),
@@ -315,6 +319,8 @@ async fn reclaim_only_if_zero_participation() {
             AccountMeta::new_readonly(reward_escrow2.pubkey(), false),
             AccountMeta::new_readonly(platform_treasury.pubkey(), false),
             AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new(pda::derive_registry_pda(&program_id, &issuer_pk).0, false),
+            AccountMeta::new(pda::derive_registry_page_pda(&program_id, &issuer_pk, 0).0, false),
         ],
     );
     send_tx(&mut ctx, vec![init2_ix], &[&platform]).await;
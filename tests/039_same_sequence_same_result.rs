@@ -13,7 +13,7 @@ use solana_sdk::{
 use spl_token::state::{Account as TokenAccount, Mint};
 
 use lockrion_issuance_v1_1::{
-    instruction::LockrionInstruction,
+    instruction::{IssuanceParams, LockrionInstruction},
     pda,
     state::{IssuanceState, UserState},
 };
@@ -234,6 +234,7 @@ async fn run_scenario() -> (IssuanceSig, UserSig, u64, u64) {
             reserve_total,
             start_ts,
             maturity_ts,
+            params: IssuanceParams::default(),
         }
         .try_to_vec()
         .unwrap(),
@@ -246,6 +247,8 @@ async fn run_scenario() -> (IssuanceSig, UserSig, u64, u64) {
             AccountMeta::new_readonly(reward_escrow.pubkey(), false),
             AccountMeta::new_readonly(platform_treasury.pubkey(), false),
             AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new(pda::derive_registry_pda(&program_id, &issuer_pk).0, false),
+            AccountMeta::new(pda::derive_registry_page_pda(&program_id, &issuer_pk, 0).0, false),
         ],
     );
     send_tx(&mut ctx, vec![init_ix], &[&platform]).await;
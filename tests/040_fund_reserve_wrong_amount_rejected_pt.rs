@@ -17,7 +17,7 @@ use spl_token::state::{Account as TokenAccount, Mint};
 
 use lockrion_issuance_v1_1::{
     error::LockrionError,
-    instruction::LockrionInstruction,
+    instruction::{IssuanceParams, LockrionInstruction},
     pda,
     state::IssuanceState,
 };
@@ -224,6 +224,7 @@ async fn fund_reserve_wrong_amount_rejected_pt() {
         reserve_total,
         start_ts,
         maturity_ts,
+        params: IssuanceParams::default(),
     }
     .try_to_vec()
     .unwrap();
@@ -240,6 +241,8 @@ async fn fund_reserve_wrong_amount_rejected_pt() {
             AccountMeta::new_readonly(reward_escrow.pubkey(), false),
             AccountMeta::new_readonly(payer_pk, false), // platform_treasury (ok for this test)
             AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new(pda::derive_registry_pda(&program_id, &issuer_pk).0, false),
+            AccountMeta::new(pda::derive_registry_page_pda(&program_id, &issuer_pk, 0).0, false),
         ],
     );
     send_tx_ok(&mut ctx, vec![init_ix], &[&platform]).await;
@@ -18,7 +18,7 @@ use spl_token::state::{Account as TokenAccount, Mint};
 
 use lockrion_issuance_v1_1::{
     error::LockrionError,
-    instruction::LockrionInstruction,
+    instruction::{IssuanceParams, LockrionInstruction},
     pda,
     state::IssuanceState,
 };
@@ -220,6 +220,7 @@ async fn fund_reserve_non_issuer_rejected_pt() {
         reserve_total,
         start_ts,
         maturity_ts,
+        params: IssuanceParams::default(),
     }
     .try_to_vec()
     .unwrap();
@@ -236,6 +237,8 @@ async fn fund_reserve_non_issuer_rejected_pt() {
             AccountMeta::new_readonly(reward_escrow.pubkey(), false),
             AccountMeta::new_readonly(platform_treasury.pubkey(), false),
             AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new(pda::derive_registry_pda(&program_id, &platform_pk).0, false),
+            AccountMeta::new(pda::derive_registry_page_pda(&program_id, &platform_pk, 0).0, false),
         ],
     );
     send_tx_ok(&mut ctx, vec![init_ix], &[&platform]).await;
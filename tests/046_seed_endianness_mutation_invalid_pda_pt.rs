@@ -15,7 +15,8 @@ use spl_token::state::{Account as TokenAccount, Mint};
 
 use lockrion_issuance_v1_1::{
     error::LockrionError,
-    instruction::LockrionInstruction,
+    instruction::{IssuanceParams, LockrionInstruction},
+    pda,
 };
 
 async fn send_tx_ok(ctx: &mut ProgramTestContext, ixs: Vec<Instruction>, extra_signers: &[&Keypair]) {
@@ -180,6 +181,7 @@ async fn seed_endianness_mutation_invalid_pda_pt() {
         reserve_total,
         start_ts,
         maturity_ts,
+        params: IssuanceParams::default(),
     }
     .try_to_vec()
     .unwrap();
@@ -196,6 +198,8 @@ async fn seed_endianness_mutation_invalid_pda_pt() {
             AccountMeta::new_readonly(reward_escrow.pubkey(), false),
             AccountMeta::new_readonly(platform_treasury.pubkey(), false),
             AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new(pda::derive_registry_pda(&program_id, &platform_pk).0, false),
+            AccountMeta::new(pda::derive_registry_page_pda(&program_id, &platform_pk, 0).0, false),
         ],
     );
 
@@ -0,0 +1,96 @@
+#![forbid(unsafe_code)]
+
+mod common;
+
+use borsh::BorshSerialize;
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+
+use common::*;
+use lockrion_issuance_v1_1::instruction::LockrionInstruction;
+
+const START_TS: i64 = 1_000_000;
+const MATURITY_TS: i64 = START_TS + DAY * 10;
+const RESERVE_TOTAL: u128 = 1000;
+
+fn batch_ix(iss: &Issuance, pairs: &[(&Participant, solana_sdk::pubkey::Pubkey)]) -> Instruction {
+    let mut metas = vec![
+        AccountMeta::new(iss.pda, false),
+        AccountMeta::new(iss.reward_escrow, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+    ];
+    for (p, dst) in pairs {
+        metas.push(AccountMeta::new(p.user, false));
+        metas.push(AccountMeta::new(*dst, false));
+    }
+    mk_ix(lockrion_issuance_v1_1::id(), LockrionInstruction::ClaimRewardBatch.try_to_vec().unwrap(), metas)
+}
+
+#[tokio::test]
+async fn claim_reward_batch_pt() {
+    let mut ctx = program_test().start_with_context().await;
+    let platform = platform();
+    let iss = funded_issuance(&mut ctx, &platform, Spec::new(RESERVE_TOTAL, START_TS, MATURITY_TS)).await;
+
+    // Weights A:B:C = 10:30:60, all deposited on day 0
+    let a = participant(&mut ctx, &iss, 10).await;
+    let b = participant(&mut ctx, &iss, 30).await;
+    let c = participant(&mut ctx, &iss, 60).await;
+    set_now(&mut ctx, START_TS).await;
+    deposit(&mut ctx, &iss, &a, 10).await;
+    deposit(&mut ctx, &iss, &b, 30).await;
+    deposit(&mut ctx, &iss, &c, 60).await;
+
+    // C claims on its own first
+    set_now(&mut ctx, MATURITY_TS + 10).await;
+    claim(&mut ctx, &iss, &c).await.unwrap();
+    assert_eq!(token_balance(&mut ctx, &c.reward_ata).await, 600);
+
+    // Permissionless crank, signed only by the fee payer
+    let ix = batch_ix(&iss, &[(&a, a.reward_ata), (&c, c.reward_ata), (&b, b.reward_ata)]);
+    let payer = ctx.payer.pubkey();
+    let bh = ctx.get_new_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(&[ix], Some(&payer), &[&ctx.payer], bh);
+
+    let res = ctx.banks_client.process_transaction_with_metadata(tx).await.unwrap();
+    assert!(res.result.is_ok(), "batch claim failed: {:?}", res.result);
+
+    // bit0 = A paid, bit1 = C skipped, bit2 = B paid
+    let return_data = res.metadata.unwrap().return_data.unwrap();
+    assert_eq!(return_data.program_id, lockrion_issuance_v1_1::id());
+    assert_eq!(return_data.data, vec![0b0000_0101]);
+
+    assert_eq!(token_balance(&mut ctx, &a.reward_ata).await, 100);
+    assert_eq!(token_balance(&mut ctx, &b.reward_ata).await, 300);
+    assert_eq!(token_balance(&mut ctx, &c.reward_ata).await, 600);
+    assert_eq!(token_balance(&mut ctx, &iss.reward_escrow).await, 0);
+
+    assert!(read_user(&mut ctx, &a.user).await.is_reward_claimed());
+    assert!(read_user(&mut ctx, &b.user).await.is_reward_claimed());
+}
+
+#[tokio::test]
+async fn claim_reward_batch_foreign_destination_rejected_pt() {
+    let mut ctx = program_test().start_with_context().await;
+    let platform = platform();
+    let iss = funded_issuance(&mut ctx, &platform, Spec::new(RESERVE_TOTAL, START_TS, MATURITY_TS)).await;
+
+    let a = participant(&mut ctx, &iss, 100).await;
+    set_now(&mut ctx, START_TS).await;
+    deposit(&mut ctx, &iss, &a, 100).await;
+
+    // Destination owned by someone else than the bound participant
+    let attacker_ata = create_token_account(&mut ctx, &iss.reward_mint, &Keypair::new().pubkey()).await;
+
+    set_now(&mut ctx, MATURITY_TS + 10).await;
+    let res = send(&mut ctx, &[batch_ix(&iss, &[(&a, attacker_ata)])], &[]).await;
+    assert!(res.is_err(), "batch claim to foreign destination unexpectedly succeeded");
+
+    assert!(!read_user(&mut ctx, &a.user).await.is_reward_claimed());
+    assert_eq!(token_balance(&mut ctx, &iss.reward_escrow).await, 1000);
+    assert_eq!(token_balance(&mut ctx, &attacker_ata).await, 0);
+}
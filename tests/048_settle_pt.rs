@@ -0,0 +1,86 @@
+#![forbid(unsafe_code)]
+
+mod common;
+
+use solana_program_test::*;
+
+use common::*;
+use lockrion_issuance_v1_1::error::LockrionError;
+
+const START_TS: i64 = 1_000_000;
+const MATURITY_TS: i64 = START_TS + DAY * 10;
+const RESERVE_TOTAL: u128 = 1000;
+
+struct Setup {
+    ctx: ProgramTestContext,
+    iss: Issuance,
+    p: Participant,
+}
+
+enum Done {
+    Nothing,
+    Claim,
+    Withdraw,
+}
+
+// Matured issuance: participant holds 40 of 100 principal (400 of 1000 weight).
+async fn setup(done: Done) -> Setup {
+    let mut ctx = program_test().start_with_context().await;
+    let platform = platform();
+    let iss = funded_issuance(&mut ctx, &platform, Spec::new(RESERVE_TOTAL, START_TS, MATURITY_TS)).await;
+
+    let p = participant(&mut ctx, &iss, 40).await;
+    let other = participant(&mut ctx, &iss, 60).await;
+    set_now(&mut ctx, START_TS).await;
+    deposit(&mut ctx, &iss, &p, 40).await;
+    deposit(&mut ctx, &iss, &other, 60).await;
+
+    set_now(&mut ctx, MATURITY_TS + 10).await;
+    match done {
+        Done::Nothing => {}
+        Done::Claim => claim(&mut ctx, &iss, &p).await.unwrap(),
+        Done::Withdraw => withdraw(&mut ctx, &iss, &p).await.unwrap(),
+    }
+
+    Setup { ctx, iss, p }
+}
+
+#[tokio::test]
+async fn settle_claims_and_withdraws_pt() {
+    let mut s = setup(Done::Nothing).await;
+
+    settle(&mut s.ctx, &s.iss, &s.p).await.unwrap();
+
+    assert_eq!(token_balance(&mut s.ctx, &s.p.reward_ata).await, 400);
+    assert_eq!(token_balance(&mut s.ctx, &s.p.lock_ata).await, 40);
+
+    let user = read_user(&mut s.ctx, &s.p.user).await;
+    assert!(user.is_reward_claimed());
+    assert_eq!(user.locked_amount, 0);
+    assert_eq!(read_issuance(&mut s.ctx, &s.iss.pda).await.total_locked, 60);
+
+    // Second settle: both halves done
+    assert_custom(settle(&mut s.ctx, &s.iss, &s.p).await, LockrionError::NothingToSettle);
+}
+
+#[tokio::test]
+async fn settle_after_claim_only_withdraws_pt() {
+    let mut s = setup(Done::Claim).await;
+
+    settle(&mut s.ctx, &s.iss, &s.p).await.unwrap();
+
+    assert_eq!(token_balance(&mut s.ctx, &s.p.reward_ata).await, 400);
+    assert_eq!(token_balance(&mut s.ctx, &s.p.lock_ata).await, 40);
+    assert_eq!(token_balance(&mut s.ctx, &s.iss.reward_escrow).await, 600);
+}
+
+#[tokio::test]
+async fn settle_after_withdraw_only_claims_pt() {
+    let mut s = setup(Done::Withdraw).await;
+
+    settle(&mut s.ctx, &s.iss, &s.p).await.unwrap();
+
+    assert_eq!(token_balance(&mut s.ctx, &s.p.reward_ata).await, 400);
+    assert_eq!(token_balance(&mut s.ctx, &s.p.lock_ata).await, 40);
+    assert_eq!(token_balance(&mut s.ctx, &s.iss.deposit_escrow).await, 60);
+}
@@ -0,0 +1,201 @@
+#![forbid(unsafe_code)]
+
+mod common;
+
+use borsh::BorshSerialize;
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+use common::*;
+use lockrion_issuance_v1_1::{
+    cpi,
+    error::LockrionError,
+    instruction::{IssuanceParams, LockrionInstruction},
+    metadata::MetadataArgs,
+};
+
+const START_TS: i64 = 1_000_000;
+const MATURITY_TS: i64 = START_TS + DAY * 10;
+const DAY_5: i64 = START_TS + DAY * 5 + 1;
+const RESERVE_TOTAL: u128 = 1000;
+const TERMS_HASH: [u8; 32] = [0x5a; 32];
+
+struct Setup {
+    ctx: ProgramTestContext,
+    iss: Issuance,
+    source: Participant,
+    recipient: Participant,
+}
+
+// Source deposits 40 on day 0; the recipient deposits `recipient_amount` on day 0 (if any).
+async fn setup(recipient_amount: u64) -> Setup {
+    let mut ctx = program_test().start_with_context().await;
+    let platform = platform();
+    let iss = funded_issuance(&mut ctx, &platform, Spec::new(RESERVE_TOTAL, START_TS, MATURITY_TS)).await;
+
+    let source = participant(&mut ctx, &iss, 40).await;
+    let recipient = participant(&mut ctx, &iss, recipient_amount).await;
+    set_now(&mut ctx, START_TS).await;
+    deposit(&mut ctx, &iss, &source, 40).await;
+    if recipient_amount > 0 {
+        deposit(&mut ctx, &iss, &recipient, recipient_amount).await;
+    }
+
+    Setup { ctx, iss, source, recipient }
+}
+
+async fn transfer(s: &mut Setup, to: Pubkey) -> Result<(), BanksClientError> {
+    let ix = transfer_position_ix(&s.iss, &s.source, &to);
+    send(&mut s.ctx, &[ix], &[&s.source.kp]).await
+}
+
+#[tokio::test]
+async fn transfer_position_to_new_owner_pt() {
+    let mut s = setup(0).await;
+    set_now(&mut s.ctx, DAY_5).await;
+
+    let recipient = s.recipient.pubkey();
+    transfer(&mut s, recipient).await.unwrap();
+
+    // accrued to day 5 before the move: 40 * 5 = 200
+    let moved = read_user(&mut s.ctx, &s.recipient.user).await;
+    assert_eq!(moved.participant, recipient);
+    assert_eq!(moved.locked_amount, 40);
+    assert_eq!(moved.user_weight_accum, 200);
+    assert_eq!(moved.user_last_day_index, 5);
+    assert!(!moved.is_reward_claimed());
+
+    let source = read_user(&mut s.ctx, &s.source.user).await;
+    assert_eq!(source.locked_amount, 0);
+    assert_eq!(source.user_weight_accum, 0);
+
+    let issuance = read_issuance(&mut s.ctx, &s.iss.pda).await;
+    assert_eq!(issuance.total_locked, 40);
+    assert_eq!(issuance.participant_count, 2);
+    assert_eq!(issuance.total_weight_accum, moved.user_weight_accum + source.user_weight_accum);
+}
+
+#[tokio::test]
+async fn transfer_position_merges_into_existing_pt() {
+    let mut s = setup(10).await;
+    set_now(&mut s.ctx, DAY_5).await;
+
+    let recipient = s.recipient.pubkey();
+    transfer(&mut s, recipient).await.unwrap();
+
+    // source 40 * 5 = 200, recipient 10 * 5 = 50
+    let merged = read_user(&mut s.ctx, &s.recipient.user).await;
+    assert_eq!(merged.locked_amount, 50);
+    assert_eq!(merged.user_weight_accum, 250);
+
+    let issuance = read_issuance(&mut s.ctx, &s.iss.pda).await;
+    assert_eq!(issuance.total_locked, 50);
+    assert_eq!(issuance.participant_count, 2);
+    assert_eq!(issuance.total_weight_accum, 250);
+}
+
+#[tokio::test]
+async fn transfer_position_claimed_recipient_conflict_pt() {
+    let mut s = setup(10).await;
+
+    // Recipient claims at maturity; the unclaimed source cannot merge into it
+    set_now(&mut s.ctx, MATURITY_TS + 10).await;
+    claim(&mut s.ctx, &s.iss, &s.recipient).await.unwrap();
+
+    let recipient = s.recipient.pubkey();
+    assert_custom(transfer(&mut s, recipient).await, LockrionError::PositionMergeConflict);
+
+    let source = read_user(&mut s.ctx, &s.source.user).await;
+    assert_eq!(source.locked_amount, 40);
+}
+
+#[tokio::test]
+async fn transfer_position_to_self_rejected_pt() {
+    let mut s = setup(0).await;
+    set_now(&mut s.ctx, DAY_5).await;
+
+    let own = s.source.pubkey();
+    assert_custom(transfer(&mut s, own).await, LockrionError::PositionSelfTransfer);
+
+    // Unrelated recipient still works afterwards
+    let other = Keypair::new().pubkey();
+    transfer(&mut s, other).await.unwrap();
+}
+
+// transfer_position_with_terms: the recipient co-signs, metadata PDA last
+fn transfer_with_terms_ix(iss: &Issuance, from: &Participant, to: &Participant, terms_hash: [u8; 32]) -> Instruction {
+    let mut ix = transfer_position_ix(iss, from, &to.pubkey());
+    ix.data = LockrionInstruction::TransferPositionWithTerms { terms_hash }.try_to_vec().unwrap();
+    ix.accounts[3] = AccountMeta::new_readonly(to.pubkey(), true);
+    ix.accounts.push(AccountMeta::new_readonly(iss.metadata.unwrap(), false));
+    ix
+}
+
+async fn deposit_with_terms(ctx: &mut ProgramTestContext, iss: &Issuance, p: &Participant, amount: u64) {
+    let ix = cpi::deposit_with_terms_instruction(
+        &lockrion_issuance_v1_1::id(),
+        &iss.pda,
+        &p.user,
+        &p.pubkey(),
+        &p.lock_ata,
+        &iss.deposit_escrow,
+        &spl_token::id(),
+        amount,
+        TERMS_HASH,
+    )
+    .unwrap();
+    send(ctx, &[ix], &[&p.kp]).await.unwrap();
+}
+
+#[tokio::test]
+async fn transfer_position_terms_acknowledgment_pt() {
+    let mut ctx = program_test().start_with_context().await;
+    let platform = platform();
+    let args = MetadataArgs {
+        name: "Terms test".to_string(),
+        symbol: "TT".to_string(),
+        uri: "https://example.org/terms.json".to_string(),
+        terms_hash: TERMS_HASH,
+    };
+    let params = IssuanceParams { metadata: Some(args), ..IssuanceParams::default() };
+    let iss =
+        funded_issuance(&mut ctx, &platform, Spec::new(RESERVE_TOTAL, START_TS, MATURITY_TS).params(params)).await;
+
+    let source = participant(&mut ctx, &iss, 40).await;
+    let acked = participant(&mut ctx, &iss, 10).await;
+    let fresh = participant(&mut ctx, &iss, 0).await;
+    set_now(&mut ctx, START_TS).await;
+    deposit_with_terms(&mut ctx, &iss, &source, 30).await;
+    deposit_with_terms(&mut ctx, &iss, &acked, 10).await;
+    set_now(&mut ctx, DAY_5).await;
+
+    // A recipient that never acknowledged the terms cannot receive a plain transfer
+    let ix = transfer_position_ix(&iss, &source, &fresh.pubkey());
+    assert_custom(send(&mut ctx, &[ix], &[&source.kp]).await, LockrionError::TermsNotAcknowledged);
+
+    // Co-signing path: the recipient must sign, with the stored hash
+    let mut ix = transfer_with_terms_ix(&iss, &source, &fresh, TERMS_HASH);
+    ix.accounts[3].is_signer = false;
+    assert_custom(send(&mut ctx, &[ix], &[&source.kp]).await, LockrionError::UnauthorizedCaller);
+    let ix = transfer_with_terms_ix(&iss, &source, &fresh, [0x11; 32]);
+    assert_custom(send(&mut ctx, &[ix], &[&source.kp, &fresh.kp]).await, LockrionError::TermsHashMismatch);
+    let ix = transfer_with_terms_ix(&iss, &source, &fresh, [0u8; 32]);
+    assert_custom(send(&mut ctx, &[ix], &[&source.kp, &fresh.kp]).await, LockrionError::TermsNotAcknowledged);
+    assert!(account(&mut ctx, &fresh.user).await.is_none());
+
+    let ix = transfer_with_terms_ix(&iss, &source, &fresh, TERMS_HASH);
+    send(&mut ctx, &[ix], &[&source.kp, &fresh.kp]).await.unwrap();
+    let moved = read_user(&mut ctx, &fresh.user).await;
+    assert_eq!(moved.locked_amount, 30);
+    assert_eq!(moved.terms_hash, TERMS_HASH);
+    assert_eq!(moved.terms_ack_ts, DAY_5);
+
+    // Acknowledged on deposit: a plain transfer goes through
+    let ix = transfer_position_ix(&iss, &fresh, &acked.pubkey());
+    send(&mut ctx, &[ix], &[&fresh.kp]).await.unwrap();
+    assert_eq!(read_user(&mut ctx, &acked.user).await.locked_amount, 40);
+}
@@ -0,0 +1,206 @@
+#![forbid(unsafe_code)]
+
+mod common;
+
+use borsh::BorshSerialize;
+use solana_program::program_pack::Pack;
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    program_option::COption,
+    pubkey::Pubkey,
+    signature::Signer,
+    system_program,
+};
+use spl_token::state::Mint;
+
+use common::*;
+use lockrion_issuance_v1_1::{
+    error::LockrionError,
+    instruction::{IssuanceParams, LockrionInstruction},
+    pda,
+    state::{LockTier, MultiplierTier},
+};
+
+const START_TS: i64 = 1_000_000;
+const MATURITY_TS: i64 = START_TS + DAY * 10;
+const RESERVE_TOTAL: u128 = 1000;
+
+struct Setup {
+    ctx: ProgramTestContext,
+    iss: Issuance,
+    receipt_mint: Pubkey,
+    a: Participant,
+    b: Participant,
+    receipt_a: Pubkey,
+    receipt_b: Pubkey,
+}
+
+// Funded receipt-mode issuance, nobody deposited yet; A holds 100 lock tokens.
+async fn setup() -> Setup {
+    let mut ctx = program_test().start_with_context().await;
+    let platform = platform();
+    let spec = Spec::new(RESERVE_TOTAL, START_TS, MATURITY_TS)
+        .params(IssuanceParams { receipt_mint: true, ..IssuanceParams::default() });
+    let iss = funded_issuance(&mut ctx, &platform, spec).await;
+    let receipt_mint = iss.receipt_mint.unwrap();
+
+    let a = participant(&mut ctx, &iss, 100).await;
+    let b = participant(&mut ctx, &iss, 0).await;
+    let receipt_a = create_token_account(&mut ctx, &receipt_mint, &a.pubkey()).await;
+    let receipt_b = create_token_account(&mut ctx, &receipt_mint, &b.pubkey()).await;
+
+    Setup { ctx, iss, receipt_mint, a, b, receipt_a, receipt_b }
+}
+
+async fn deposit_a(s: &mut Setup, amount: u64) {
+    let mut ix = deposit_ix(&s.iss, &s.a, amount);
+    ix.accounts.push(AccountMeta::new(s.receipt_mint, false));
+    ix.accounts.push(AccountMeta::new(s.receipt_a, false));
+    send(&mut s.ctx, &[ix], &[&s.a.kp]).await.unwrap();
+}
+
+async fn move_receipts(s: &mut Setup, dst: Pubkey, amount: u64) {
+    let (src, owner) = (s.receipt_a, s.a.kp.insecure_clone());
+    token_transfer(&mut s.ctx, &src, &dst, &owner, amount).await;
+}
+
+fn sync_ix(s: &Setup, payer: Pubkey) -> Instruction {
+    mk_ix(
+        lockrion_issuance_v1_1::id(),
+        LockrionInstruction::SyncPosition.try_to_vec().unwrap(),
+        vec![
+            AccountMeta::new(s.iss.pda, false),
+            AccountMeta::new(s.a.user, false),
+            AccountMeta::new_readonly(s.receipt_a, false),
+            AccountMeta::new(s.b.user, false),
+            AccountMeta::new_readonly(s.receipt_b, false),
+            AccountMeta::new_readonly(s.receipt_mint, false),
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    )
+}
+
+async fn sync_a_to_b(s: &mut Setup) -> Result<(), BanksClientError> {
+    let ix = sync_ix(s, s.ctx.payer.pubkey());
+    send(&mut s.ctx, &[ix], &[]).await
+}
+
+#[tokio::test]
+async fn receipt_mint_deposit_sync_withdraw_pt() {
+    let mut s = setup().await;
+
+    // day 1: A deposits 100, receives 100 receipts
+    set_now(&mut s.ctx, START_TS + DAY + 1).await;
+    deposit_a(&mut s, 100).await;
+    assert_eq!(token_balance(&mut s.ctx, &s.receipt_a).await, 100);
+
+    // day 4: A sells 40 receipts to B, B syncs
+    set_now(&mut s.ctx, START_TS + 4 * DAY + 1).await;
+    let receipt_b = s.receipt_b;
+    move_receipts(&mut s, receipt_b, 40).await;
+    sync_a_to_b(&mut s).await.unwrap();
+
+    let user_a = read_user(&mut s.ctx, &s.a.user).await;
+    let user_b = read_user(&mut s.ctx, &s.b.user).await;
+    assert_eq!(user_a.locked_amount, 60);
+    assert_eq!(user_a.user_weight_accum, 300); // 100 * 3 days, all before the sync
+    assert_eq!(user_b.locked_amount, 40);
+    assert_eq!(user_b.user_weight_accum, 0);
+    assert_eq!(user_b.user_last_day_index, 4);
+
+    // after maturity: B withdraws principal by burning receipts
+    set_now(&mut s.ctx, MATURITY_TS + 1).await;
+    let mut ix = withdraw_ix(&s.iss, &s.b);
+    ix.accounts.push(AccountMeta::new(s.receipt_mint, false));
+    ix.accounts.push(AccountMeta::new(s.receipt_b, false));
+    send(&mut s.ctx, &[ix], &[&s.b.kp]).await.unwrap();
+
+    assert_eq!(token_balance(&mut s.ctx, &s.b.lock_ata).await, 40);
+    assert_eq!(token_balance(&mut s.ctx, &s.receipt_b).await, 0);
+
+    let user_b = read_user(&mut s.ctx, &s.b.user).await;
+    assert_eq!(user_b.locked_amount, 0);
+    assert_eq!(user_b.user_weight_accum, 240); // 40 * days 4..10
+
+    let issuance = read_issuance(&mut s.ctx, &s.iss.pda).await;
+    assert_eq!(issuance.total_locked, 60);
+    assert_eq!(issuance.total_weight_accum, 900); // A: 300 + 60*6, B: 240
+
+    let mint = account(&mut s.ctx, &s.receipt_mint).await.unwrap();
+    assert_eq!(Mint::unpack(&mint.data).unwrap().supply, 60);
+}
+
+#[tokio::test]
+async fn receipt_mint_unsynced_weight_stays_with_sender_pt() {
+    let mut s = setup().await;
+
+    set_now(&mut s.ctx, START_TS + DAY + 1).await;
+    deposit_a(&mut s, 100).await;
+
+    // By design the move is only seen on sync: days 2..4 still accrue to A
+    set_now(&mut s.ctx, START_TS + 2 * DAY + 1).await;
+    let receipt_b = s.receipt_b;
+    move_receipts(&mut s, receipt_b, 40).await;
+    set_now(&mut s.ctx, START_TS + 4 * DAY + 1).await;
+    sync_a_to_b(&mut s).await.unwrap();
+
+    let user_a = read_user(&mut s.ctx, &s.a.user).await;
+    let user_b = read_user(&mut s.ctx, &s.b.user).await;
+    assert_eq!(user_a.user_weight_accum, 300); // 100 * 3 days, 2 of them after the move
+    assert_eq!((user_a.locked_amount, user_b.locked_amount), (60, 40));
+    assert_eq!(user_b.user_weight_accum, 0);
+}
+
+#[tokio::test]
+async fn receipt_mint_sync_balance_mismatch_rejected_pt() {
+    let mut s = setup().await;
+
+    set_now(&mut s.ctx, START_TS + DAY + 1).await;
+    deposit_a(&mut s, 100).await;
+
+    // A parks 10 receipts in a second account, 40 go to B: pair no longer sums up
+    let second = create_token_account(&mut s.ctx, &s.receipt_mint, &s.a.pubkey()).await;
+    move_receipts(&mut s, second, 10).await;
+    let receipt_b = s.receipt_b;
+    move_receipts(&mut s, receipt_b, 40).await;
+
+    assert_custom(sync_a_to_b(&mut s).await, LockrionError::ReceiptBalanceMismatch);
+}
+
+#[tokio::test]
+async fn init_receipt_mint_pt() {
+    let mut ctx = program_test().start_with_context().await;
+    let platform = platform();
+    let receipt = IssuanceParams { receipt_mint: true, ..IssuanceParams::default() };
+
+    // Receipts carry 1:1 principal only: no weighted positions
+    let spec = Spec::new(RESERVE_TOTAL, START_TS, MATURITY_TS).params(IssuanceParams {
+        multiplier_schedule: vec![MultiplierTier { end_day: 2, mult_bps: 15_000 }],
+        ..receipt.clone()
+    });
+    assert_init_rejected(&mut ctx, &platform, spec, LockrionError::InvalidIssuanceParams).await;
+    let spec = Spec::new(RESERVE_TOTAL, START_TS, MATURITY_TS).params(IssuanceParams {
+        lock_tiers: vec![LockTier { unlock_day: 0, mult_bps: 20_000, penalty_bps: 0 }],
+        ..receipt.clone()
+    });
+    assert_init_rejected(&mut ctx, &platform, spec, LockrionError::InvalidIssuanceParams).await;
+
+    let lock_mint = create_mint_with_decimals(&mut ctx, 6).await;
+    let spec = Spec::new(RESERVE_TOTAL, START_TS, MATURITY_TS).params(receipt).lock_mint(lock_mint);
+    let iss = create_issuance(&mut ctx, &platform, spec).await;
+
+    let (receipt_mint, bump) = pda::derive_receipt_mint_pda(&lockrion_issuance_v1_1::id(), &iss.pda);
+    assert_eq!(iss.receipt_mint, Some(receipt_mint));
+    let issuance = read_issuance(&mut ctx, &iss.pda).await;
+    assert_eq!((issuance.receipt_mode, issuance.receipt_mint_bump), (1, bump));
+
+    // Authority = issuance PDA, no freeze authority, lock mint decimals, nothing minted
+    let acc = account(&mut ctx, &receipt_mint).await.unwrap();
+    assert_eq!(acc.owner, spl_token::id());
+    let mint = Mint::unpack(&acc.data).unwrap();
+    assert_eq!(mint.mint_authority, COption::Some(iss.pda));
+    assert_eq!(mint.freeze_authority, COption::None);
+    assert_eq!((mint.decimals, mint.supply), (6, 0));
+}
@@ -0,0 +1,115 @@
+#![forbid(unsafe_code)]
+
+mod common;
+
+use solana_program_test::*;
+
+use common::*;
+use lockrion_issuance_v1_1::{accumulator::MAX_VESTING_DURATION, error::LockrionError, instruction::IssuanceParams};
+
+const START_TS: i64 = 1_000_000;
+const MATURITY_TS: i64 = START_TS + DAY * 10;
+const RESERVE_TOTAL: u128 = 1000;
+const CLAIM_WINDOW: i64 = 90 * DAY;
+const VESTING: i64 = 1000;
+
+struct Setup {
+    ctx: ProgramTestContext,
+    iss: Issuance,
+    p: Participant,
+}
+
+// Issuance with a 1000s vesting tail; single participant holds all weight.
+async fn setup() -> Setup {
+    let mut ctx = program_test().start_with_context().await;
+    let platform = platform();
+    let spec = Spec::new(RESERVE_TOTAL, START_TS, MATURITY_TS)
+        .params(IssuanceParams { vesting_duration: VESTING, ..IssuanceParams::default() });
+    let iss = funded_issuance(&mut ctx, &platform, spec).await;
+
+    let p = participant(&mut ctx, &iss, 100).await;
+    set_now(&mut ctx, START_TS).await;
+    deposit(&mut ctx, &iss, &p, 100).await;
+
+    Setup { ctx, iss, p }
+}
+
+#[tokio::test]
+async fn vesting_claim_releases_linearly_pt() {
+    let mut s = setup().await;
+
+    // At maturity nothing is vested yet
+    set_now(&mut s.ctx, MATURITY_TS).await;
+    assert_custom(claim(&mut s.ctx, &s.iss, &s.p).await, LockrionError::NothingVested);
+
+    // 25% of the tail elapsed
+    set_now(&mut s.ctx, MATURITY_TS + VESTING / 4).await;
+    claim(&mut s.ctx, &s.iss, &s.p).await.unwrap();
+    assert_eq!(token_balance(&mut s.ctx, &s.p.reward_ata).await, 250);
+
+    let user = read_user(&mut s.ctx, &s.p.user).await;
+    assert!(user.is_reward_claimed());
+    assert!(!user.is_reward_settled());
+    assert_eq!(user.reward_total, 1000);
+    assert_eq!(user.reward_withdrawn, 250);
+
+    // Same instant again: nothing new
+    assert_custom(claim(&mut s.ctx, &s.iss, &s.p).await, LockrionError::NothingVested);
+
+    // Past the un-vested claim window: still claimable, window shifted by the tail
+    set_now(&mut s.ctx, MATURITY_TS + CLAIM_WINDOW + VESTING / 2).await;
+    claim(&mut s.ctx, &s.iss, &s.p).await.unwrap();
+    assert_eq!(token_balance(&mut s.ctx, &s.p.reward_ata).await, 1000);
+    assert_eq!(token_balance(&mut s.ctx, &s.iss.reward_escrow).await, 0);
+    assert!(read_user(&mut s.ctx, &s.p.user).await.is_reward_settled());
+
+    assert_custom(claim(&mut s.ctx, &s.iss, &s.p).await, LockrionError::AlreadyClaimed);
+}
+
+#[tokio::test]
+async fn vesting_shifts_sweep_start_pt() {
+    let mut s = setup().await;
+
+    // A later deposit rolls total_weight_accum forward; sweep needs participation on record
+    let late = participant(&mut s.ctx, &s.iss, 100).await;
+    set_now(&mut s.ctx, START_TS + DAY).await;
+    deposit(&mut s.ctx, &s.iss, &late, 100).await;
+
+    // Un-vested sweep start is too early
+    set_now(&mut s.ctx, MATURITY_TS + CLAIM_WINDOW).await;
+    assert_custom(send(&mut s.ctx, &[sweep_ix(&s.iss)], &[]).await, LockrionError::ClaimWindowClosed);
+
+    // After maturity + vesting + claim_window: sweep proceeds
+    set_now(&mut s.ctx, MATURITY_TS + VESTING + CLAIM_WINDOW).await;
+    send(&mut s.ctx, &[sweep_ix(&s.iss)], &[]).await.unwrap();
+
+    assert_eq!(token_balance(&mut s.ctx, &s.iss.platform_treasury).await, RESERVE_TOTAL as u64);
+}
+
+#[tokio::test]
+async fn init_vesting_duration_range_pt() {
+    let mut ctx = program_test().start_with_context().await;
+    let platform = platform();
+    let spec = |start_ts: i64, vesting_duration| {
+        Spec::new(RESERVE_TOTAL, start_ts, start_ts + DAY * 10)
+            .params(IssuanceParams { vesting_duration, ..IssuanceParams::default() })
+    };
+
+    // Negative or above MAX_VESTING_DURATION: rejected before the issuance account exists
+    assert_init_rejected(&mut ctx, &platform, spec(START_TS, -1), LockrionError::InvalidIssuanceParams).await;
+    assert_init_rejected(
+        &mut ctx,
+        &platform,
+        spec(START_TS, MAX_VESTING_DURATION + 1),
+        LockrionError::InvalidIssuanceParams,
+    )
+    .await;
+
+    // In range, but maturity + vesting + claim window overflows: claims could never be priced
+    let late = i64::MAX - DAY * 20;
+    assert_init_rejected(&mut ctx, &platform, spec(late, DAY * 30), LockrionError::InvalidIssuanceParams).await;
+
+    let iss = create_issuance(&mut ctx, &platform, spec(START_TS, MAX_VESTING_DURATION)).await;
+    let issuance = read_issuance(&mut ctx, &iss.pda).await;
+    assert_eq!(issuance.vesting_duration, MAX_VESTING_DURATION);
+}
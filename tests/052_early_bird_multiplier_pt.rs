@@ -0,0 +1,68 @@
+#![forbid(unsafe_code)]
+
+mod common;
+
+use solana_program_test::*;
+
+use common::*;
+use lockrion_issuance_v1_1::{instruction::IssuanceParams, state::MultiplierTier};
+
+const START_TS: i64 = 1_000_000;
+const MATURITY_TS: i64 = START_TS + DAY * 10;
+const RESERVE_TOTAL: u128 = 1000;
+
+#[tokio::test]
+async fn early_bird_multiplier_weights_pt() {
+    let mut ctx = program_test().start_with_context().await;
+    let platform = platform();
+    let params = IssuanceParams {
+        multiplier_schedule: vec![
+            MultiplierTier { end_day: 2, mult_bps: 15_000 },
+            MultiplierTier { end_day: 5, mult_bps: 12_000 },
+        ],
+        ..IssuanceParams::default()
+    };
+    let iss =
+        funded_issuance(&mut ctx, &platform, Spec::new(RESERVE_TOTAL, START_TS, MATURITY_TS).params(params)).await;
+
+    let mut ps = Vec::new();
+    for _ in 0..3 {
+        ps.push(participant(&mut ctx, &iss, 100).await);
+    }
+
+    // day 1 (1.5x), day 3 (1.2x), day 7 (1x)
+    set_now(&mut ctx, START_TS + DAY + 1).await;
+    deposit(&mut ctx, &iss, &ps[0], 100).await;
+    set_now(&mut ctx, START_TS + 3 * DAY + 1).await;
+    deposit(&mut ctx, &iss, &ps[1], 100).await;
+    set_now(&mut ctx, START_TS + 7 * DAY + 1).await;
+    deposit(&mut ctx, &iss, &ps[2], 100).await;
+
+    assert_eq!(read_user(&mut ctx, &ps[0].user).await.weighted_locked, 150);
+    assert_eq!(read_user(&mut ctx, &ps[1].user).await.weighted_locked, 120);
+    assert_eq!(read_user(&mut ctx, &ps[2].user).await.weighted_locked, 100);
+
+    let issuance = read_issuance(&mut ctx, &iss.pda).await;
+    assert_eq!(issuance.total_locked, 300);
+    assert_eq!(issuance.total_weighted_locked, 370);
+
+    // after maturity every participant withdraws (finalizes both accumulators)
+    set_now(&mut ctx, MATURITY_TS + 1).await;
+    for p in &ps {
+        withdraw(&mut ctx, &iss, p).await.unwrap();
+    }
+
+    let w0 = read_user(&mut ctx, &ps[0].user).await;
+    let w1 = read_user(&mut ctx, &ps[1].user).await;
+    let w2 = read_user(&mut ctx, &ps[2].user).await;
+    assert_eq!(w0.user_weight_accum, 150 * 9);
+    assert_eq!(w1.user_weight_accum, 120 * 7);
+    assert_eq!(w2.user_weight_accum, 100 * 3);
+    assert_eq!(w0.locked_amount + w1.locked_amount + w2.locked_amount, 0);
+    assert_eq!(w0.weighted_locked + w1.weighted_locked + w2.weighted_locked, 0);
+
+    let issuance = read_issuance(&mut ctx, &iss.pda).await;
+    assert_eq!(issuance.total_weight_accum, w0.user_weight_accum + w1.user_weight_accum + w2.user_weight_accum);
+    assert_eq!(issuance.total_locked, 0);
+    assert_eq!(issuance.total_weighted_locked, 0);
+}
@@ -0,0 +1,77 @@
+#![forbid(unsafe_code)]
+
+mod common;
+
+use solana_program_test::*;
+
+use common::*;
+use lockrion_issuance_v1_1::{
+    accumulator::{DEFAULT_ACCOUNTING_PERIOD, MAX_ACCOUNTING_PERIOD},
+    error::LockrionError,
+    instruction::IssuanceParams,
+};
+
+const START_TS: i64 = 1_000_000;
+const HOUR: i64 = 3_600;
+const MATURITY_TS: i64 = START_TS + HOUR * 24;
+const RESERVE_TOTAL: u128 = 1000;
+
+#[tokio::test]
+async fn hourly_accounting_period_pt() {
+    let mut ctx = program_test().start_with_context().await;
+    let platform = platform();
+    let params = IssuanceParams { accounting_period: HOUR, ..IssuanceParams::default() };
+    let iss =
+        funded_issuance(&mut ctx, &platform, Spec::new(RESERVE_TOTAL, START_TS, MATURITY_TS).params(params)).await;
+
+    let issuance = read_issuance(&mut ctx, &iss.pda).await;
+    assert_eq!(issuance.accounting_period, HOUR);
+    assert_eq!(issuance.final_day_index, 24);
+
+    let p0 = participant(&mut ctx, &iss, 100).await;
+    let p1 = participant(&mut ctx, &iss, 100).await;
+
+    // hour 2 and hour 20 (same calendar day: would be day 0 for both with 86_400)
+    set_now(&mut ctx, START_TS + 2 * HOUR + 1).await;
+    deposit(&mut ctx, &iss, &p0, 100).await;
+    set_now(&mut ctx, START_TS + 20 * HOUR + 1).await;
+    deposit(&mut ctx, &iss, &p1, 100).await;
+
+    let issuance = read_issuance(&mut ctx, &iss.pda).await;
+    assert_eq!(issuance.last_day_index, 20);
+    assert_eq!(issuance.total_weight_accum, 100 * 18);
+
+    set_now(&mut ctx, MATURITY_TS + 1).await;
+    withdraw(&mut ctx, &iss, &p0).await.unwrap();
+    withdraw(&mut ctx, &iss, &p1).await.unwrap();
+
+    let w0 = read_user(&mut ctx, &p0.user).await;
+    let w1 = read_user(&mut ctx, &p1.user).await;
+    assert_eq!(w0.user_weight_accum, 100 * 22);
+    assert_eq!(w1.user_weight_accum, 100 * 4);
+    assert_eq!(w0.user_last_day_index, 24);
+
+    let issuance = read_issuance(&mut ctx, &iss.pda).await;
+    assert_eq!(issuance.last_day_index, 24);
+    assert_eq!(issuance.total_weight_accum, w0.user_weight_accum + w1.user_weight_accum);
+}
+
+#[tokio::test]
+async fn init_accounting_period_range_pt() {
+    let mut ctx = program_test().start_with_context().await;
+    let platform = platform();
+    let spec = |accounting_period| {
+        Spec::new(RESERVE_TOTAL, START_TS, MATURITY_TS)
+            .params(IssuanceParams { accounting_period, ..IssuanceParams::default() })
+    };
+
+    // Outside [MIN, MAX]_ACCOUNTING_PERIOD: rejected before the issuance account exists
+    assert_init_rejected(&mut ctx, &platform, spec(-HOUR), LockrionError::InvalidIssuanceParams).await;
+    assert_init_rejected(&mut ctx, &platform, spec(MAX_ACCOUNTING_PERIOD + 1), LockrionError::InvalidIssuanceParams)
+        .await;
+
+    // 0 = default daily periods
+    let iss = create_issuance(&mut ctx, &platform, spec(0)).await;
+    let issuance = read_issuance(&mut ctx, &iss.pda).await;
+    assert_eq!((issuance.accounting_period, issuance.final_day_index), (DEFAULT_ACCOUNTING_PERIOD, 1));
+}
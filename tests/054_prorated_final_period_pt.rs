@@ -0,0 +1,75 @@
+#![forbid(unsafe_code)]
+
+mod common;
+
+use solana_program_test::*;
+
+use common::*;
+use lockrion_issuance_v1_1::{accumulator::MAX_FINAL_DAY_INDEX, error::LockrionError, instruction::IssuanceParams};
+
+const START_TS: i64 = 1_000_000;
+const TAIL: i64 = 6 * 3_600;
+const MATURITY_TS: i64 = START_TS + DAY * 10 + TAIL;
+const RESERVE_TOTAL: u128 = 1000;
+
+#[tokio::test]
+async fn prorated_final_period_pt() {
+    let mut ctx = program_test().start_with_context().await;
+    let platform = platform();
+    let params = IssuanceParams { prorate_final_period: true, ..IssuanceParams::default() };
+    let iss =
+        funded_issuance(&mut ctx, &platform, Spec::new(RESERVE_TOTAL, START_TS, MATURITY_TS).params(params)).await;
+
+    let issuance = read_issuance(&mut ctx, &iss.pda).await;
+    assert_eq!(issuance.final_day_index, 11);
+    assert_eq!(issuance.prorated_tail, TAIL);
+
+    let p0 = participant(&mut ctx, &iss, 100).await;
+    let p1 = participant(&mut ctx, &iss, 100).await;
+
+    // day 0 deposit, and a deposit inside the partial tail (day index 10)
+    set_now(&mut ctx, START_TS + 1).await;
+    deposit(&mut ctx, &iss, &p0, 100).await;
+    set_now(&mut ctx, START_TS + 10 * DAY + 1).await;
+    deposit(&mut ctx, &iss, &p1, 100).await;
+
+    let issuance = read_issuance(&mut ctx, &iss.pda).await;
+    assert_eq!(issuance.last_day_index, 10);
+
+    // at maturity the tail closes (raw index would still be 10)
+    set_now(&mut ctx, MATURITY_TS).await;
+    withdraw(&mut ctx, &iss, &p0).await.unwrap();
+    withdraw(&mut ctx, &iss, &p1).await.unwrap();
+
+    let w0 = read_user(&mut ctx, &p0.user).await;
+    let w1 = read_user(&mut ctx, &p1.user).await;
+    assert_eq!(w0.user_weight_accum, 100 * (10 * DAY + TAIL) as u128);
+    assert_eq!(w1.user_weight_accum, 100 * TAIL as u128);
+    assert_eq!(w1.user_last_day_index, 11);
+
+    let issuance = read_issuance(&mut ctx, &iss.pda).await;
+    assert_eq!(issuance.last_day_index, 11);
+    assert_eq!(issuance.total_weight_accum, w0.user_weight_accum + w1.user_weight_accum);
+}
+
+#[tokio::test]
+async fn init_prorated_final_period_pt() {
+    let mut ctx = program_test().start_with_context().await;
+    let platform = platform();
+    let params = |accounting_period| IssuanceParams {
+        accounting_period,
+        prorate_final_period: true,
+        ..IssuanceParams::default()
+    };
+
+    // The extra partial period must still fit MAX_FINAL_DAY_INDEX
+    let maturity_ts = START_TS + 2 * MAX_FINAL_DAY_INDEX as i64 + 1;
+    let spec = Spec::new(RESERVE_TOTAL, START_TS, maturity_ts).params(params(2));
+    assert_init_rejected(&mut ctx, &platform, spec, LockrionError::InvalidIssuanceParams).await;
+
+    // Aligned maturity: whole periods only, no tail
+    let spec = Spec::new(RESERVE_TOTAL, START_TS, START_TS + DAY * 10).params(params(0));
+    let iss = create_issuance(&mut ctx, &platform, spec).await;
+    let issuance = read_issuance(&mut ctx, &iss.pda).await;
+    assert_eq!((issuance.final_day_index, issuance.prorated_tail), (10, 0));
+}
@@ -0,0 +1,117 @@
+#![forbid(unsafe_code)]
+
+mod common;
+
+use solana_program_test::*;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, SeedDerivable},
+};
+
+use common::*;
+use lockrion_issuance_v1_1::{
+    accumulator::{CONTINUOUS_ACCOUNTING_PERIOD, MAX_FINAL_DAY_INDEX},
+    error::LockrionError,
+    instruction::IssuanceParams,
+    state::{IssuanceState, UserState},
+};
+
+// Continuous mode: accounting_period = 1, day_index counts seconds since start.
+
+const START_TS: i64 = 1_000_000;
+const MATURITY_TS: i64 = START_TS + 86_400;
+const RESERVE_TOTAL: u128 = 1000;
+
+fn kp(n: u8) -> Keypair {
+    Keypair::from_seed(&[n; 32]).unwrap()
+}
+
+async fn run_scenario() -> (IssuanceState, Vec<UserState>, Vec<u64>) {
+    let mut ctx = program_test().start_with_context().await;
+    let platform = platform();
+    let params = IssuanceParams { continuous: true, ..IssuanceParams::default() };
+    let iss =
+        funded_issuance(&mut ctx, &platform, Spec::new(RESERVE_TOTAL, START_TS, MATURITY_TS).params(params)).await;
+
+    // Participants are seeded keys, so both runs address identical user states
+    let mut ps = Vec::new();
+    for i in 0..2u8 {
+        ps.push(participant_with(&mut ctx, &iss, kp(10 + i), 50, 1_000_000_000).await);
+    }
+
+    // 23:59:59 vs 00:00:00 of the next day differ by one second of weight
+    set_now(&mut ctx, START_TS + 13).await;
+    deposit(&mut ctx, &iss, &ps[0], 7).await;
+    set_now(&mut ctx, START_TS + 3_601).await;
+    deposit(&mut ctx, &iss, &ps[1], 5).await;
+    set_now(&mut ctx, START_TS + 50_000).await;
+    deposit(&mut ctx, &iss, &ps[0], 2).await;
+
+    set_now(&mut ctx, MATURITY_TS + 1).await;
+    claim(&mut ctx, &iss, &ps[0]).await.unwrap();
+    claim(&mut ctx, &iss, &ps[1]).await.unwrap();
+
+    // Mints and escrows are fresh keys per run; everything else must match
+    let mut issuance = read_issuance(&mut ctx, &iss.pda).await;
+    issuance.lock_mint = Pubkey::default();
+    issuance.reward_mint = Pubkey::default();
+    issuance.deposit_escrow = Pubkey::default();
+    issuance.reward_escrow = Pubkey::default();
+    issuance.platform_treasury = Pubkey::default();
+
+    let mut users = Vec::new();
+    let mut balances = Vec::new();
+    for p in &ps {
+        users.push(read_user(&mut ctx, &p.user).await);
+        balances.push(token_balance(&mut ctx, &p.reward_ata).await);
+    }
+    balances.push(token_balance(&mut ctx, &iss.reward_escrow).await);
+
+    (issuance, users, balances)
+}
+
+#[tokio::test]
+async fn continuous_same_sequence_same_result_pt() {
+    let a = run_scenario().await;
+    let b = run_scenario().await;
+    assert_eq!(a, b);
+
+    let (issuance, users, balances) = a;
+    assert_eq!(issuance.accounting_period, CONTINUOUS_ACCOUNTING_PERIOD);
+    assert_eq!(issuance.final_day_index, 86_400);
+
+    // weight = amount x seconds
+    let w0 = 7 * (86_400 - 13) + 2 * (86_400 - 50_000);
+    let w1 = 5 * (86_400 - 3_601);
+    assert_eq!(users[0].user_weight_accum, w0 as u128);
+    assert_eq!(users[1].user_weight_accum, w1 as u128);
+    assert_eq!(issuance.total_weight_accum, (w0 + w1) as u128);
+    assert_eq!(issuance.last_day_index, 86_400);
+
+    let r0 = (RESERVE_TOTAL * w0 as u128 / (w0 + w1) as u128) as u64;
+    // Last claimant holds the remaining weight and takes the floor dust
+    let r1 = RESERVE_TOTAL as u64 - r0;
+    assert_eq!(balances, vec![r0, r1, 0]);
+}
+
+#[tokio::test]
+async fn init_continuous_validation_pt() {
+    let mut ctx = program_test().start_with_context().await;
+    let platform = platform();
+    let continuous = IssuanceParams { continuous: true, ..IssuanceParams::default() };
+
+    // Continuous mode fixes the period itself
+    let spec = Spec::new(RESERVE_TOTAL, START_TS, MATURITY_TS)
+        .params(IssuanceParams { accounting_period: 3_600, ..continuous.clone() });
+    assert_init_rejected(&mut ctx, &platform, spec, LockrionError::InvalidIssuanceParams).await;
+
+    // One index per second: the term must stay within MAX_FINAL_DAY_INDEX seconds
+    let spec = Spec::new(RESERVE_TOTAL, START_TS, START_TS + MAX_FINAL_DAY_INDEX as i64 + 1).params(continuous.clone());
+    assert_init_rejected(&mut ctx, &platform, spec, LockrionError::InvalidIssuanceParams).await;
+
+    let spec = Spec::new(RESERVE_TOTAL, START_TS, START_TS + MAX_FINAL_DAY_INDEX as i64).params(continuous);
+    let iss = create_issuance(&mut ctx, &platform, spec).await;
+    let issuance = read_issuance(&mut ctx, &iss.pda).await;
+    assert_eq!(issuance.accounting_period, CONTINUOUS_ACCOUNTING_PERIOD);
+    assert_eq!(issuance.final_day_index, MAX_FINAL_DAY_INDEX);
+}
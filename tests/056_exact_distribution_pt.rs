@@ -0,0 +1,93 @@
+#![forbid(unsafe_code)]
+
+mod common;
+
+use borsh::BorshSerialize;
+use solana_program_test::*;
+use solana_sdk::instruction::AccountMeta;
+
+use common::*;
+use lockrion_issuance_v1_1::instruction::LockrionInstruction;
+
+const START_TS: i64 = 1_000_000;
+const MATURITY_TS: i64 = START_TS + DAY * 10;
+const RESERVE_TOTAL: u128 = 1000;
+
+#[tokio::test]
+async fn exact_distribution_pt() {
+    let mut ctx = program_test().start_with_context().await;
+    let platform = platform();
+    let iss = funded_issuance(&mut ctx, &platform, Spec::new(RESERVE_TOTAL, START_TS, MATURITY_TS)).await;
+
+    // Three equal weights: floor share 333 each, 1 unit of dust
+    let mut ps = Vec::new();
+    for _ in 0..3 {
+        ps.push(participant(&mut ctx, &iss, 1).await);
+    }
+    set_now(&mut ctx, START_TS).await;
+    for p in &ps {
+        deposit(&mut ctx, &iss, p, 1).await;
+    }
+
+    let mut metas = vec![
+        AccountMeta::new(iss.pda, false),
+        AccountMeta::new(iss.reward_escrow, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+    ];
+    for p in &ps {
+        metas.push(AccountMeta::new(p.user, false));
+        metas.push(AccountMeta::new(p.reward_ata, false));
+    }
+
+    set_now(&mut ctx, MATURITY_TS + 10).await;
+    let ix = mk_ix(lockrion_issuance_v1_1::id(), LockrionInstruction::ClaimRewardBatch.try_to_vec().unwrap(), metas);
+    send(&mut ctx, &[ix], &[]).await.unwrap();
+
+    let mut paid = Vec::new();
+    for p in &ps {
+        paid.push(token_balance(&mut ctx, &p.reward_ata).await);
+    }
+    assert_eq!(paid, vec![333, 333, 334]);
+    assert_eq!(paid.iter().sum::<u64>(), RESERVE_TOTAL as u64);
+    assert_eq!(token_balance(&mut ctx, &iss.reward_escrow).await, 0);
+
+    let issuance = read_issuance(&mut ctx, &iss.pda).await;
+    assert_eq!(issuance.total_claimed, RESERVE_TOTAL);
+    assert_eq!(issuance.total_weight_claimed, issuance.total_weight_accum);
+}
+
+#[tokio::test]
+async fn zero_weight_claim_gets_nothing_pt() {
+    let mut ctx = program_test().start_with_context().await;
+    let platform = platform();
+    let iss = funded_issuance(&mut ctx, &platform, Spec::new(RESERVE_TOTAL, START_TS, MATURITY_TS)).await;
+
+    // p0 hands its position to p1 before any weight accrues: weights 0 / 20 / 10
+    let mut ps = Vec::new();
+    for _ in 0..3 {
+        ps.push(participant(&mut ctx, &iss, 1).await);
+    }
+    set_now(&mut ctx, START_TS).await;
+    for p in &ps {
+        deposit(&mut ctx, &iss, p, 1).await;
+    }
+    let ix = transfer_position_ix(&iss, &ps[0], &ps[1].pubkey());
+    send(&mut ctx, &[ix], &[&ps[0].kp]).await.unwrap();
+
+    // The emptied source is paid nothing and is not counted, before or after the others
+    set_now(&mut ctx, MATURITY_TS + 10).await;
+    claim(&mut ctx, &iss, &ps[0]).await.unwrap();
+    claim(&mut ctx, &iss, &ps[1]).await.unwrap();
+    claim(&mut ctx, &iss, &ps[2]).await.unwrap();
+
+    let mut paid = Vec::new();
+    for p in &ps {
+        paid.push(token_balance(&mut ctx, &p.reward_ata).await);
+    }
+    assert_eq!(paid, vec![0, 666, 334]);
+
+    let issuance = read_issuance(&mut ctx, &iss.pda).await;
+    assert_eq!(issuance.total_claimed, RESERVE_TOTAL);
+    assert_eq!(issuance.claimed_count, 2);
+    assert!(read_user(&mut ctx, &ps[0].user).await.is_reward_claimed());
+}
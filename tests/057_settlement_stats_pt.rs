@@ -0,0 +1,56 @@
+#![forbid(unsafe_code)]
+
+mod common;
+
+use solana_program_test::*;
+
+use common::*;
+
+const START_TS: i64 = 1_000_000;
+const MATURITY_TS: i64 = START_TS + DAY * 10;
+const RESERVE_TOTAL: u128 = 1000;
+
+#[tokio::test]
+async fn settlement_stats_pt() {
+    let mut ctx = program_test().start_with_context().await;
+    let platform = platform();
+    let iss = funded_issuance(&mut ctx, &platform, Spec::new(RESERVE_TOTAL, START_TS, MATURITY_TS)).await;
+
+    let p0 = participant(&mut ctx, &iss, 60).await;
+    let p1 = participant(&mut ctx, &iss, 30).await;
+
+    // participant 0 deposits twice: counted once
+    set_now(&mut ctx, START_TS + 1).await;
+    deposit(&mut ctx, &iss, &p0, 40).await;
+    deposit(&mut ctx, &iss, &p0, 20).await;
+    set_now(&mut ctx, START_TS + DAY * 5 + 1).await;
+    deposit(&mut ctx, &iss, &p1, 30).await;
+
+    let issuance = read_issuance(&mut ctx, &iss.pda).await;
+    assert_eq!(issuance.participant_count, 2);
+    assert_eq!(issuance.claimed_count, 0);
+    assert_eq!(issuance.withdrawn_count, 0);
+
+    set_now(&mut ctx, MATURITY_TS + 1).await;
+    claim(&mut ctx, &iss, &p0).await.unwrap();
+
+    let issuance = read_issuance(&mut ctx, &iss.pda).await;
+    assert_eq!(issuance.claimed_count, 1);
+    assert_eq!(issuance.withdrawn_count, 0);
+    assert_eq!(issuance.total_withdrawn, 0);
+
+    withdraw(&mut ctx, &iss, &p0).await.unwrap();
+    let issuance = read_issuance(&mut ctx, &iss.pda).await;
+    assert_eq!(issuance.withdrawn_count, 1);
+    assert_eq!(issuance.total_withdrawn, 60);
+
+    // settle: claim half + withdraw half in one instruction
+    settle(&mut ctx, &iss, &p1).await.unwrap();
+    let issuance = read_issuance(&mut ctx, &iss.pda).await;
+    assert_eq!(issuance.participant_count, 2);
+    assert_eq!(issuance.claimed_count, 2);
+    assert_eq!(issuance.withdrawn_count, 2);
+    assert_eq!(issuance.total_withdrawn, 90);
+    assert_eq!(issuance.total_claimed, RESERVE_TOTAL);
+    assert_eq!(issuance.total_locked, 0);
+}
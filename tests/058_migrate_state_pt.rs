@@ -0,0 +1,160 @@
+#![forbid(unsafe_code)]
+
+mod common;
+
+use borsh::BorshSerialize;
+use solana_program_test::*;
+use solana_sdk::{
+    account::Account,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    rent::Rent,
+    signature::{Keypair, Signer},
+    system_program,
+};
+
+use common::*;
+use lockrion_issuance_v1_1::{
+    error::LockrionError,
+    instruction::LockrionInstruction,
+    pda,
+    state::{
+        IssuanceState, IssuanceStateV1, UserState, UserStateV1, ISSUANCE_STATE_SIZE, ISSUANCE_STATE_SIZE_V1,
+        STATE_VERSION, STATE_VERSION_V1, USER_STATE_SIZE, USER_STATE_SIZE_V1,
+    },
+};
+
+// v1 accounts are seeded directly (292 / 112 bytes), as left by a v1.1 deployment.
+
+const START_TS: i64 = 1_000_000;
+const MATURITY_TS: i64 = START_TS + 86_400 * 10;
+const RESERVE_TOTAL: u128 = 1000;
+
+// rent-exempt for the legacy size only, so migration needs a top-up
+fn legacy_account(program_id: &Pubkey, data: Vec<u8>) -> Account {
+    let lamports = Rent::default().minimum_balance(data.len());
+    Account { lamports, data, owner: *program_id, executable: false, rent_epoch: 0 }
+}
+
+fn migrate_ix(state: Pubkey, payer: Pubkey) -> Instruction {
+    mk_ix(
+        lockrion_issuance_v1_1::id(),
+        LockrionInstruction::MigrateState.try_to_vec().unwrap(),
+        vec![
+            AccountMeta::new(state, false),
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    )
+}
+
+#[tokio::test]
+async fn migrate_state_v1_to_v3_pt() {
+    let program_id = lockrion_issuance_v1_1::id();
+
+    let mut pt = program_test();
+
+    let issuer = Pubkey::new_unique();
+    let (issuance_pda, bump) = pda::derive_issuance_pda(&program_id, &issuer, START_TS, RESERVE_TOTAL);
+
+    let lock_mint = Pubkey::new_unique();
+    let deposit_escrow = Pubkey::new_unique();
+
+    let issuance_v1 = IssuanceStateV1 {
+        version: STATE_VERSION_V1,
+        bump,
+        issuer_address: issuer,
+        lock_mint,
+        reward_mint: Pubkey::new_unique(),
+        deposit_escrow,
+        reward_escrow: Pubkey::new_unique(),
+        platform_treasury: Pubkey::new_unique(),
+        reserve_total: RESERVE_TOTAL,
+        start_ts: START_TS,
+        maturity_ts: MATURITY_TS,
+        claim_window: 90 * 86_400,
+        final_day_index: 10,
+        total_locked: 100,
+        total_weight_accum: 1_000,
+        last_day_index: 10,
+        reserve_funded: 1,
+        sweep_executed: 0,
+        reclaim_executed: 0,
+        reserved_padding: [0u8; 7],
+    };
+    let mut issuance_data = vec![0u8; ISSUANCE_STATE_SIZE_V1];
+    issuance_v1.pack(&mut issuance_data).unwrap();
+    pt.add_account(issuance_pda, legacy_account(&program_id, issuance_data));
+
+    let participant = Keypair::new();
+    let (user_pda, user_bump) = pda::derive_user_pda(&program_id, &issuance_pda, &participant.pubkey());
+    let user_v1 = UserStateV1 {
+        version: STATE_VERSION_V1,
+        bump: user_bump,
+        issuance: issuance_pda,
+        participant: participant.pubkey(),
+        locked_amount: 100,
+        user_weight_accum: 1_000,
+        user_last_day_index: 10,
+        reward_claimed: 1,
+        reserved_padding: [0u8; 5],
+    };
+    let mut user_data = vec![0u8; USER_STATE_SIZE_V1];
+    user_v1.pack(&mut user_data).unwrap();
+    pt.add_account(user_pda, legacy_account(&program_id, user_data));
+
+    let participant_lock = Pubkey::new_unique();
+    pt.add_account(lock_mint, mint_account(100));
+    pt.add_account(deposit_escrow, token_account(&lock_mint, &issuance_pda, 100));
+    pt.add_account(participant_lock, token_account(&lock_mint, &participant.pubkey(), 0));
+
+    let mut ctx = pt.start_with_context().await;
+    set_now(&mut ctx, MATURITY_TS + 1).await;
+
+    let withdraw_ix = mk_ix(
+        program_id,
+        LockrionInstruction::WithdrawDeposit.try_to_vec().unwrap(),
+        vec![
+            AccountMeta::new(issuance_pda, false),
+            AccountMeta::new(user_pda, false),
+            AccountMeta::new(participant.pubkey(), true),
+            AccountMeta::new(participant_lock, false),
+            AccountMeta::new(deposit_escrow, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+    );
+
+    // legacy accounts are readable but not writable
+    let err = send(&mut ctx, std::slice::from_ref(&withdraw_ix), &[&participant]).await.unwrap_err();
+    assert_eq!(custom_code(&err), Some(LockrionError::StateMigrationRequired as u32));
+
+    // migrate both (payer = ctx.payer covers the rent difference)
+    let payer = ctx.payer.pubkey();
+    send(&mut ctx, &[migrate_ix(issuance_pda, payer)], &[]).await.unwrap();
+    send(&mut ctx, &[migrate_ix(user_pda, payer)], &[]).await.unwrap();
+
+    let rent = ctx.banks_client.get_rent().await.unwrap();
+    let iss_acc = ctx.banks_client.get_account(issuance_pda).await.unwrap().unwrap();
+    assert_eq!(iss_acc.data.len(), ISSUANCE_STATE_SIZE);
+    assert!(iss_acc.lamports >= rent.minimum_balance(ISSUANCE_STATE_SIZE));
+    let iss = IssuanceState::unpack(&iss_acc.data).unwrap();
+    assert_eq!(iss, IssuanceState::from(issuance_v1));
+    assert_eq!(iss.version, STATE_VERSION);
+
+    let user_acc = ctx.banks_client.get_account(user_pda).await.unwrap().unwrap();
+    assert_eq!(user_acc.data.len(), USER_STATE_SIZE);
+    assert!(user_acc.lamports >= rent.minimum_balance(USER_STATE_SIZE));
+    assert_eq!(UserState::unpack(&user_acc.data).unwrap(), UserState::from(user_v1));
+
+    // second migration is rejected
+    let err = send(&mut ctx, &[migrate_ix(user_pda, payer)], &[]).await.unwrap_err();
+    assert_eq!(custom_code(&err), Some(LockrionError::InvalidStateVersion as u32));
+
+    // migrated position behaves as before
+    send(&mut ctx, &[withdraw_ix], &[&participant]).await.unwrap();
+    assert_eq!(token_balance(&mut ctx, &participant_lock).await, 100);
+
+    let iss = read_issuance(&mut ctx, &issuance_pda).await;
+    assert_eq!(iss.total_locked, 0);
+    assert_eq!(iss.total_withdrawn, 100);
+}
@@ -0,0 +1,138 @@
+#![forbid(unsafe_code)]
+
+mod common;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::Signer,
+    transaction::Transaction,
+};
+
+use common::*;
+use lockrion_issuance_v1_1::{
+    instruction::{IssuanceParams, LockrionInstruction},
+    state::LockTier,
+    view::{Phase, QuoteView},
+};
+
+const START_TS: i64 = 1_000_000;
+const MATURITY_TS: i64 = START_TS + DAY * 10;
+const RESERVE_TOTAL: u128 = 1500;
+
+fn quote_ix(issuance: Pubkey, user: Pubkey) -> Instruction {
+    mk_ix(
+        lockrion_issuance_v1_1::id(),
+        LockrionInstruction::Quote.try_to_vec().unwrap(),
+        vec![AccountMeta::new_readonly(issuance, false), AccountMeta::new_readonly(user, false)],
+    )
+}
+
+async fn quote(ctx: &mut ProgramTestContext, issuance: Pubkey, user: Pubkey) -> QuoteView {
+    let payer = ctx.payer.pubkey();
+    let bh = ctx.get_new_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(&[quote_ix(issuance, user)], Some(&payer), &[&ctx.payer], bh);
+
+    let res = ctx.banks_client.simulate_transaction(tx).await.unwrap();
+    assert!(res.result.unwrap().is_ok(), "quote failed");
+    let return_data = res.simulation_details.unwrap().return_data.unwrap();
+    assert_eq!(return_data.program_id, lockrion_issuance_v1_1::id());
+    QuoteView::try_from_slice(&return_data.data).unwrap()
+}
+
+async fn account_data(ctx: &mut ProgramTestContext, key: &Pubkey) -> Vec<u8> {
+    account(ctx, key).await.unwrap().data
+}
+
+#[tokio::test]
+async fn quote_pt() {
+    let mut ctx = program_test().start_with_context().await;
+    let platform = platform();
+    let iss = funded_issuance(&mut ctx, &platform, Spec::new(RESERVE_TOTAL, START_TS, MATURITY_TS)).await;
+
+    // Day 5: A locked 100 since day 0 (weight 500), B locks 100 on day 5 (weight 0)
+    let a = participant(&mut ctx, &iss, 100).await;
+    let b = participant(&mut ctx, &iss, 100).await;
+    set_now(&mut ctx, START_TS).await;
+    deposit(&mut ctx, &iss, &a, 100).await;
+    set_now(&mut ctx, START_TS + DAY * 5 + 1).await;
+    deposit(&mut ctx, &iss, &b, 100).await;
+
+    let issuance_before = account_data(&mut ctx, &iss.pda).await;
+    let user_a_before = account_data(&mut ctx, &a.user).await;
+
+    // Deposit phase: projection to day 10 with current locks -> A 1000, B 500 of 1500 weight
+    let qa = quote(&mut ctx, iss.pda, a.user).await;
+    assert_eq!(qa, QuoteView { phase: Phase::Deposit, projected_reward: 1000, claimable: false, withdrawable: 0 });
+    let qb = quote(&mut ctx, iss.pda, b.user).await;
+    assert_eq!(qb.projected_reward, 500);
+
+    // Claim phase: same totals, now claimable + withdrawable
+    set_now(&mut ctx, MATURITY_TS + 1).await;
+    let qa = quote(&mut ctx, iss.pda, a.user).await;
+    assert_eq!(qa, QuoteView { phase: Phase::Claim, projected_reward: 1000, claimable: true, withdrawable: 100 });
+
+    // After the claim window
+    set_now(&mut ctx, MATURITY_TS + 90 * DAY).await;
+    let qa = quote(&mut ctx, iss.pda, a.user).await;
+    assert_eq!(qa.phase, Phase::Closed);
+    assert!(!qa.claimable);
+    assert_eq!(qa.withdrawable, 100);
+
+    // Executed (not simulated) quote leaves both accounts byte-identical
+    let mut ix = quote_ix(iss.pda, a.user);
+    ix.accounts = vec![AccountMeta::new(iss.pda, false), AccountMeta::new(a.user, false)];
+    send(&mut ctx, &[ix], &[]).await.unwrap();
+
+    assert_eq!(account_data(&mut ctx, &iss.pda).await, issuance_before);
+    assert_eq!(account_data(&mut ctx, &a.user).await, user_a_before);
+}
+
+#[tokio::test]
+async fn quote_early_unlock_pt() {
+    let mut ctx = program_test().start_with_context().await;
+    let platform = platform();
+    // Tier 0: held to maturity. Tier 1: withdrawable from day 3 less 10%.
+    let params = IssuanceParams {
+        lock_tiers: vec![
+            LockTier { unlock_day: 0, mult_bps: 20_000, penalty_bps: 0 },
+            LockTier { unlock_day: 3, mult_bps: 10_000, penalty_bps: 1_000 },
+        ],
+        ..IssuanceParams::default()
+    };
+    let iss =
+        funded_issuance(&mut ctx, &platform, Spec::new(RESERVE_TOTAL, START_TS, MATURITY_TS).params(params)).await;
+
+    let holder = participant(&mut ctx, &iss, 100).await;
+    let early = participant(&mut ctx, &iss, 100).await;
+    set_now(&mut ctx, START_TS + 1).await;
+    deposit(&mut ctx, &iss, &holder, 100).await;
+    let ix = mk_ix(
+        lockrion_issuance_v1_1::id(),
+        LockrionInstruction::DepositWithTier { amount: 100, lock_tier: 1, terms_hash: None }.try_to_vec().unwrap(),
+        deposit_metas(&iss, &early),
+    );
+    send(&mut ctx, &[ix], &[&early.kp]).await.unwrap();
+
+    // Before the unlock day nothing is withdrawable
+    set_now(&mut ctx, START_TS + 2 * DAY).await;
+    assert_eq!(quote(&mut ctx, iss.pda, early.user).await.withdrawable, 0);
+
+    // From the unlock day: principal less the penalty; tier 0 stays locked
+    set_now(&mut ctx, START_TS + 5 * DAY).await;
+    assert_eq!(quote(&mut ctx, iss.pda, early.user).await.withdrawable, 90);
+    assert_eq!(quote(&mut ctx, iss.pda, holder.user).await.withdrawable, 0);
+
+    // ... which is what WithdrawDeposit pays out
+    let issuer_lock = create_token_account(&mut ctx, &iss.lock_mint, &iss.issuer).await;
+    let mut ix = withdraw_ix(&iss, &early);
+    ix.accounts.push(AccountMeta::new(issuer_lock, false));
+    send(&mut ctx, &[ix], &[&early.kp]).await.unwrap();
+    assert_eq!(token_balance(&mut ctx, &early.lock_ata).await, 90);
+    assert_eq!(quote(&mut ctx, iss.pda, early.user).await.withdrawable, 0);
+
+    set_now(&mut ctx, MATURITY_TS + 1).await;
+    assert_eq!(quote(&mut ctx, iss.pda, holder.user).await.withdrawable, 100);
+}
@@ -0,0 +1,118 @@
+#![forbid(unsafe_code)]
+
+mod common;
+
+use borsh::BorshSerialize;
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::Keypair,
+};
+
+use common::*;
+use lockrion_issuance_v1_1::{
+    error::LockrionError,
+    instruction::{IssuanceParams, LockrionInstruction},
+    state::LockTier,
+};
+
+const START_TS: i64 = 1_000_000;
+const MATURITY_TS: i64 = START_TS + DAY * 10;
+const RESERVE_TOTAL: u128 = 1000;
+
+fn deposit_for_ix(iss: &Issuance, user: Pubkey, funder: &Participant, beneficiary: Pubkey, amount: u64) -> Instruction {
+    let mut metas = deposit_metas(iss, funder);
+    metas[1] = AccountMeta::new(user, false);
+    mk_ix(
+        lockrion_issuance_v1_1::id(),
+        LockrionInstruction::DepositFor { beneficiary, amount }.try_to_vec().unwrap(),
+        metas,
+    )
+}
+
+#[tokio::test]
+async fn deposit_for_beneficiary_pt() {
+    let mut ctx = program_test().start_with_context().await;
+    let platform = platform();
+    let iss = funded_issuance(&mut ctx, &platform, Spec::new(RESERVE_TOTAL, START_TS, MATURITY_TS)).await;
+
+    // Funder holds tokens + SOL; beneficiary has neither SOL nor a prior position
+    let funder = participant(&mut ctx, &iss, 100).await;
+    let beneficiary = participant_with(&mut ctx, &iss, Keypair::new(), 0, 0).await;
+
+    set_now(&mut ctx, START_TS + 1).await;
+
+    // user_state must be the beneficiary's PDA
+    let bad = deposit_for_ix(&iss, funder.user, &funder, beneficiary.pubkey(), 60);
+    assert_custom(send(&mut ctx, &[bad], &[&funder.kp]).await, LockrionError::InvalidPda);
+
+    let ix = deposit_for_ix(&iss, beneficiary.user, &funder, beneficiary.pubkey(), 60);
+    send(&mut ctx, &[ix], &[&funder.kp]).await.unwrap();
+
+    let user = read_user(&mut ctx, &beneficiary.user).await;
+    assert_eq!(user.participant, beneficiary.pubkey());
+    assert_eq!(user.rent_payer, funder.pubkey());
+    assert_eq!(user.locked_amount, 60);
+    assert!(account(&mut ctx, &funder.user).await.is_none());
+    assert_eq!(token_balance(&mut ctx, &funder.lock_ata).await, 40);
+
+    set_now(&mut ctx, MATURITY_TS + 1).await;
+
+    // funder cannot withdraw the beneficiary's position
+    let mut ix = withdraw_ix(&iss, &funder);
+    ix.accounts[1] = AccountMeta::new(beneficiary.user, false);
+    assert_custom(send(&mut ctx, &[ix], &[&funder.kp]).await, LockrionError::InvalidPda);
+
+    // beneficiary withdraws to their own account
+    withdraw(&mut ctx, &iss, &beneficiary).await.unwrap();
+    assert_eq!(token_balance(&mut ctx, &beneficiary.lock_ata).await, 60);
+    assert_eq!(token_balance(&mut ctx, &funder.lock_ata).await, 40);
+    assert_eq!(lamports(&mut ctx, &beneficiary.pubkey()).await, 0);
+}
+
+#[tokio::test]
+async fn deposit_for_cannot_pin_lock_tier_pt() {
+    let mut ctx = program_test().start_with_context().await;
+    let platform = platform();
+    let params = IssuanceParams {
+        lock_tiers: vec![
+            LockTier { unlock_day: 0, mult_bps: 20_000, penalty_bps: 0 },
+            LockTier { unlock_day: 3, mult_bps: 10_000, penalty_bps: 1_000 },
+        ],
+        ..IssuanceParams::default()
+    };
+    let iss =
+        funded_issuance(&mut ctx, &platform, Spec::new(RESERVE_TOTAL, START_TS, MATURITY_TS).params(params)).await;
+
+    let griefer = participant(&mut ctx, &iss, 10).await;
+    let victim = participant(&mut ctx, &iss, 100).await;
+    set_now(&mut ctx, START_TS + 1).await;
+
+    // 1 unit ahead of the victim's first deposit would pin the position to tier 0
+    let ix = deposit_for_ix(&iss, victim.user, &griefer, victim.pubkey(), 1);
+    assert_custom(send(&mut ctx, &[ix], &[&griefer.kp]).await, LockrionError::InvalidLockTier);
+    assert!(account(&mut ctx, &victim.user).await.is_none());
+
+    // The victim still picks tier 1
+    let ix = mk_ix(
+        lockrion_issuance_v1_1::id(),
+        LockrionInstruction::DepositWithTier { amount: 90, lock_tier: 1, terms_hash: None }.try_to_vec().unwrap(),
+        deposit_metas(&iss, &victim),
+    );
+    send(&mut ctx, &[ix], &[&victim.kp]).await.unwrap();
+
+    // A top-up by a third party lands in the victim's tier and cannot break it
+    let ix = deposit_for_ix(&iss, victim.user, &griefer, victim.pubkey(), 1);
+    send(&mut ctx, &[ix], &[&griefer.kp]).await.unwrap();
+    let user = read_user(&mut ctx, &victim.user).await;
+    assert_eq!((user.lock_tier, user.locked_amount, user.weighted_locked), (1, 91, 91));
+
+    let ix = mk_ix(
+        lockrion_issuance_v1_1::id(),
+        LockrionInstruction::DepositWithTier { amount: 10, lock_tier: 1, terms_hash: None }.try_to_vec().unwrap(),
+        deposit_metas(&iss, &victim),
+    );
+    send(&mut ctx, &[ix], &[&victim.kp]).await.unwrap();
+    assert_eq!(read_user(&mut ctx, &victim.user).await.locked_amount, 101);
+}
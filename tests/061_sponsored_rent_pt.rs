@@ -0,0 +1,67 @@
+#![forbid(unsafe_code)]
+
+mod common;
+
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    signature::{Keypair, Signer},
+};
+
+use common::*;
+use lockrion_issuance_v1_1::error::LockrionError;
+
+const START_TS: i64 = 1_000_000;
+const MATURITY_TS: i64 = START_TS + DAY * 10;
+const RESERVE_TOTAL: u128 = 1000;
+
+fn sponsored_deposit_ix(iss: &Issuance, p: &Participant, sponsor: &Keypair, signs: bool) -> Instruction {
+    let mut ix = deposit_ix(iss, p, 50);
+    ix.accounts.push(if signs {
+        AccountMeta::new(sponsor.pubkey(), true)
+    } else {
+        AccountMeta::new_readonly(sponsor.pubkey(), false)
+    });
+    ix
+}
+
+#[tokio::test]
+async fn sponsored_rent_deposit_pt() {
+    let mut ctx = program_test().start_with_context().await;
+    let platform = platform();
+    let iss = funded_issuance(&mut ctx, &platform, Spec::new(RESERVE_TOTAL, START_TS, MATURITY_TS)).await;
+
+    // Participant holds lock tokens but no SOL at all
+    let p = participant_with(&mut ctx, &iss, Keypair::new(), 100, 0).await;
+    let sponsor = Keypair::new();
+    airdrop(&mut ctx, &sponsor.pubkey(), 1_000_000_000).await;
+
+    set_now(&mut ctx, START_TS + 1).await;
+
+    // Without a sponsor the participant cannot fund create_account
+    assert!(send(&mut ctx, &[deposit_ix(&iss, &p, 50)], &[&p.kp]).await.is_err());
+
+    // Sponsor present but not signing
+    let ix = sponsored_deposit_ix(&iss, &p, &sponsor, false);
+    assert_custom(send(&mut ctx, &[ix], &[&p.kp]).await, LockrionError::UnauthorizedCaller);
+
+    let sponsor_before = lamports(&mut ctx, &sponsor.pubkey()).await;
+    let ix = sponsored_deposit_ix(&iss, &p, &sponsor, true);
+    send(&mut ctx, &[ix], &[&p.kp, &sponsor]).await.unwrap();
+
+    let user = read_user(&mut ctx, &p.user).await;
+    assert_eq!(user.participant, p.pubkey());
+    assert_eq!(user.rent_payer, sponsor.pubkey());
+    assert_eq!(user.locked_amount, 50);
+
+    let sponsor_after = lamports(&mut ctx, &sponsor.pubkey()).await;
+    assert_eq!(sponsor_before - sponsor_after, lamports(&mut ctx, &p.user).await);
+    assert_eq!(lamports(&mut ctx, &p.pubkey()).await, 0);
+    assert_eq!(token_balance(&mut ctx, &p.lock_ata).await, 50);
+
+    // Top-up deposit needs no rent: sponsor ignored for an existing account, rent_payer unchanged
+    send(&mut ctx, &[deposit_ix(&iss, &p, 50)], &[&p.kp]).await.unwrap();
+    let user = read_user(&mut ctx, &p.user).await;
+    assert_eq!(user.rent_payer, sponsor.pubkey());
+    assert_eq!(user.locked_amount, 100);
+}
@@ -0,0 +1,142 @@
+#![forbid(unsafe_code)]
+
+mod common;
+
+use solana_program_test::*;
+use solana_sdk::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    system_program,
+};
+
+use common::*;
+use lockrion_issuance_v1_1::cpi;
+
+// A mock vault program holds a Lockrion position through its PDA, using the cpi helpers.
+
+const START_TS: i64 = 1_000_000;
+const MATURITY_TS: i64 = START_TS + DAY * 10;
+const RESERVE_TOTAL: u128 = 1000;
+
+// ---------------------------------------------------------------------
+// mock caller program
+// data: [0, amount_le(8)] deposit | [1] claim_reward | [2] withdraw_deposit
+// accounts: 0 lockrion program, 1 issuance, 2 user_state, 3 vault PDA,
+//           4 vault token account (lock or reward), 5 escrow, 6 token_program,
+//           7 system_program (deposit only)
+// ---------------------------------------------------------------------
+fn mock_vault_process(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let (_, bump) = Pubkey::find_program_address(&[b"vault"], program_id);
+    let bump_seed = [bump];
+    let seeds: &[&[u8]] = &[b"vault", &bump_seed];
+
+    let lockrion = &accounts[0];
+    match data[0] {
+        0 => cpi::deposit(
+            lockrion,
+            cpi::DepositAccounts {
+                issuance: accounts[1].clone(),
+                user_state: accounts[2].clone(),
+                participant: accounts[3].clone(),
+                participant_lock_ata: accounts[4].clone(),
+                deposit_escrow: accounts[5].clone(),
+                token_program: accounts[6].clone(),
+                system_program: accounts[7].clone(),
+            },
+            u64::from_le_bytes(data[1..9].try_into().unwrap()),
+            &[seeds],
+        ),
+        1 => cpi::claim_reward(
+            lockrion,
+            cpi::ClaimRewardAccounts {
+                issuance: accounts[1].clone(),
+                user_state: accounts[2].clone(),
+                participant: accounts[3].clone(),
+                participant_reward_ata: accounts[4].clone(),
+                reward_escrow: accounts[5].clone(),
+                token_program: accounts[6].clone(),
+            },
+            &[seeds],
+        ),
+        _ => cpi::withdraw_deposit(
+            lockrion,
+            cpi::WithdrawDepositAccounts {
+                issuance: accounts[1].clone(),
+                user_state: accounts[2].clone(),
+                participant: accounts[3].clone(),
+                participant_lock_ata: accounts[4].clone(),
+                deposit_escrow: accounts[5].clone(),
+                token_program: accounts[6].clone(),
+            },
+            &[seeds],
+        ),
+    }
+}
+
+struct Env {
+    mock_id: Pubkey,
+    issuance_pda: Pubkey,
+    user_pda: Pubkey,
+    vault: Pubkey,
+}
+
+async fn call_mock(ctx: &mut ProgramTestContext, env: &Env, data: Vec<u8>, token_acc: Pubkey, escrow: Pubkey) {
+    let ix = Instruction {
+        program_id: env.mock_id,
+        accounts: vec![
+            AccountMeta::new_readonly(lockrion_issuance_v1_1::id(), false),
+            AccountMeta::new(env.issuance_pda, false),
+            AccountMeta::new(env.user_pda, false),
+            AccountMeta::new(env.vault, false),
+            AccountMeta::new(token_acc, false),
+            AccountMeta::new(escrow, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data,
+    };
+    send(ctx, &[ix], &[]).await.unwrap();
+}
+
+#[tokio::test]
+async fn cpi_mock_caller_pt() {
+    let mock_id = Pubkey::new_unique();
+    let mut pt = program_test();
+    pt.add_program("mock_vault", mock_id, processor!(mock_vault_process));
+
+    let mut ctx = pt.start_with_context().await;
+    let platform = platform();
+    let iss = funded_issuance(&mut ctx, &platform, Spec::new(RESERVE_TOTAL, START_TS, MATURITY_TS)).await;
+
+    // Vault PDA: system-owned with lamports so it can pay the UserState rent
+    let (vault, _) = Pubkey::find_program_address(&[b"vault"], &mock_id);
+    airdrop(&mut ctx, &vault, 1_000_000_000).await;
+    let vault_lock = create_token_account(&mut ctx, &iss.lock_mint, &vault).await;
+    let vault_reward = create_token_account(&mut ctx, &iss.reward_mint, &vault).await;
+    mint_to(&mut ctx, &iss.lock_mint, &vault_lock, 100).await;
+
+    let user_pda = user_pda(&iss, &vault);
+    let env = Env { mock_id, issuance_pda: iss.pda, user_pda, vault };
+
+    set_now(&mut ctx, START_TS + 1).await;
+
+    let mut data = vec![0u8];
+    data.extend_from_slice(&100u64.to_le_bytes());
+    call_mock(&mut ctx, &env, data, vault_lock, iss.deposit_escrow).await;
+
+    let user = read_user(&mut ctx, &user_pda).await;
+    assert_eq!(user.participant, vault);
+    assert_eq!(user.rent_payer, vault);
+    assert_eq!(user.locked_amount, 100);
+    assert_eq!(token_balance(&mut ctx, &iss.deposit_escrow).await, 100);
+
+    set_now(&mut ctx, MATURITY_TS + 1).await;
+    call_mock(&mut ctx, &env, vec![1], vault_reward, iss.reward_escrow).await;
+    assert_eq!(token_balance(&mut ctx, &vault_reward).await, RESERVE_TOTAL as u64);
+
+    call_mock(&mut ctx, &env, vec![2], vault_lock, iss.deposit_escrow).await;
+    assert_eq!(token_balance(&mut ctx, &vault_lock).await, 100);
+    assert_eq!(token_balance(&mut ctx, &iss.deposit_escrow).await, 0);
+}
@@ -0,0 +1,167 @@
+#![forbid(unsafe_code)]
+
+mod common;
+
+use borsh::BorshSerialize;
+use solana_program_test::*;
+use solana_sdk::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+use common::*;
+use lockrion_issuance_v1_1::{
+    error::LockrionError,
+    instruction::{IssuanceParams, LockrionInstruction},
+    pda,
+    registry::{self, RegistryHead, RegistryRecord},
+};
+
+// issuer_address is a PDA of a mock treasury program, which signs FundReserve and
+// ZeroParticipationReclaim through invoke_signed.
+
+const START_TS: i64 = 1_000_000;
+const MATURITY_TS: i64 = START_TS + DAY * 10;
+const RESERVE_TOTAL: u128 = 1000;
+
+fn issuer_metas(issuance: Pubkey, issuer: Pubkey, issuer_ata: Pubkey, reward_escrow: Pubkey) -> Vec<AccountMeta> {
+    vec![
+        AccountMeta::new(issuance, false),
+        AccountMeta::new_readonly(issuer, true),
+        AccountMeta::new(issuer_ata, false),
+        AccountMeta::new(reward_escrow, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+    ]
+}
+
+// ---------------------------------------------------------------------
+// mock treasury program
+// data: [0, amount_le(8)] fund_reserve | [1] reclaim
+// accounts: 0 lockrion program, 1 issuance, 2 treasury PDA (issuer),
+//           3 treasury reward account, 4 reward_escrow, 5 token_program
+// ---------------------------------------------------------------------
+fn mock_treasury_process(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let (_, bump) = Pubkey::find_program_address(&[b"treasury"], program_id);
+    let bump_seed = [bump];
+    let seeds: &[&[u8]] = &[b"treasury", &bump_seed];
+
+    let ix_data = match data[0] {
+        0 => LockrionInstruction::FundReserve { amount: u64::from_le_bytes(data[1..9].try_into().unwrap()) },
+        _ => LockrionInstruction::ZeroParticipationReclaim,
+    };
+    let ix = Instruction {
+        program_id: *accounts[0].key,
+        accounts: issuer_metas(*accounts[1].key, *accounts[2].key, *accounts[3].key, *accounts[4].key),
+        data: ix_data.try_to_vec().unwrap(),
+    };
+    invoke_signed(&ix, &accounts[1..], &[seeds])
+}
+
+#[tokio::test]
+async fn issuer_pda_authority_pt() {
+    let program_id = lockrion_issuance_v1_1::id();
+    let mock_id = Pubkey::new_unique();
+    let mut pt = program_test();
+    pt.add_program("mock_treasury", mock_id, processor!(mock_treasury_process));
+
+    let mut ctx = pt.start_with_context().await;
+    let platform = platform();
+
+    // The platform initializes; the treasury PDA is the issuer and keys the issuance PDA
+    let (treasury, _) = Pubkey::find_program_address(&[b"treasury"], &mock_id);
+    let params = IssuanceParams { issuer: treasury, ..IssuanceParams::default() };
+    let iss =
+        create_issuance(&mut ctx, &platform, Spec::new(RESERVE_TOTAL, START_TS, MATURITY_TS).params(params)).await;
+    assert_eq!(iss.pda, pda::derive_issuance_pda(&program_id, &treasury, START_TS, RESERVE_TOTAL).0);
+    assert_eq!(read_issuance(&mut ctx, &iss.pda).await.issuer_address, treasury);
+
+    let outsider = Keypair::new();
+    let outsider_reward = create_token_account(&mut ctx, &iss.reward_mint, &outsider.pubkey()).await;
+    mint_to(&mut ctx, &iss.reward_mint, &outsider_reward, RESERVE_TOTAL as u64).await;
+
+    set_now(&mut ctx, START_TS - 100).await;
+
+    // Any other signer (e.g. the initializer) cannot fund
+    for (signer, source) in [(&outsider, outsider_reward), (&platform, outsider_reward)] {
+        let ix = Instruction {
+            program_id,
+            accounts: issuer_metas(iss.pda, signer.pubkey(), source, iss.reward_escrow),
+            data: LockrionInstruction::FundReserve { amount: RESERVE_TOTAL as u64 }.try_to_vec().unwrap(),
+        };
+        assert_custom(send(&mut ctx, &[ix], &[signer]).await, LockrionError::UnauthorizedCaller);
+    }
+
+    let call_mock = |data: Vec<u8>| Instruction {
+        program_id: mock_id,
+        accounts: vec![
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new(iss.pda, false),
+            AccountMeta::new_readonly(treasury, false),
+            AccountMeta::new(iss.issuer_reward, false),
+            AccountMeta::new(iss.reward_escrow, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data,
+    };
+
+    // Treasury PDA funds through CPI
+    let mut data = vec![0u8];
+    data.extend_from_slice(&(RESERVE_TOTAL as u64).to_le_bytes());
+    send(&mut ctx, &[call_mock(data)], &[]).await.unwrap();
+    assert_eq!(token_balance(&mut ctx, &iss.reward_escrow).await, RESERVE_TOTAL as u64);
+    assert_eq!(token_balance(&mut ctx, &iss.issuer_reward).await, 0);
+
+    // No participation: the treasury PDA reclaims the reserve after maturity
+    set_now(&mut ctx, MATURITY_TS + 1).await;
+    send(&mut ctx, &[call_mock(vec![1])], &[]).await.unwrap();
+    assert_eq!(token_balance(&mut ctx, &iss.reward_escrow).await, 0);
+    assert_eq!(token_balance(&mut ctx, &iss.issuer_reward).await, RESERVE_TOTAL as u64);
+
+    assert!(read_issuance(&mut ctx, &iss.pda).await.is_reclaim_executed());
+}
+
+#[tokio::test]
+async fn init_explicit_issuer_pt() {
+    let program_id = lockrion_issuance_v1_1::id();
+    let mut ctx = program_test().start_with_context().await;
+    let platform = platform();
+
+    let issuer = Keypair::new();
+    let params = IssuanceParams { issuer: issuer.pubkey(), ..IssuanceParams::default() };
+    let iss =
+        create_issuance(&mut ctx, &platform, Spec::new(RESERVE_TOTAL, START_TS, MATURITY_TS).params(params)).await;
+
+    // Seeds follow the issuer, not the initializer
+    assert_eq!(iss.pda, pda::derive_issuance_pda(&program_id, &issuer.pubkey(), START_TS, RESERVE_TOTAL).0);
+    assert_ne!(iss.pda, pda::derive_issuance_pda(&program_id, &platform.pubkey(), START_TS, RESERVE_TOTAL).0);
+    assert_eq!(read_issuance(&mut ctx, &iss.pda).await.issuer_address, issuer.pubkey());
+
+    // The same record is appended to the platform and the issuer registry
+    let record = RegistryRecord {
+        issuance: iss.pda,
+        lock_mint: iss.lock_mint,
+        reward_mint: iss.reward_mint,
+        start_ts: START_TS,
+        maturity_ts: MATURITY_TS,
+    };
+    for scope in [platform.pubkey(), issuer.pubkey()] {
+        let (head, _) = pda::derive_registry_pda(&program_id, &scope);
+        let head = RegistryHead::unpack(&account(&mut ctx, &head).await.unwrap().data).unwrap();
+        assert_eq!((head.scope, head.count), (scope, 1));
+        let (page, _) = pda::derive_registry_page_pda(&program_id, &scope, 0);
+        let page = account(&mut ctx, &page).await.unwrap();
+        assert_eq!(registry::page_records(&page.data).unwrap(), vec![record.clone()]);
+    }
+
+    // Only the issuer funds
+    set_now(&mut ctx, START_TS - 100).await;
+    let mut ix = fund_ix(&iss);
+    ix.accounts[1] = AccountMeta::new_readonly(platform.pubkey(), true);
+    assert_custom(send(&mut ctx, &[ix], &[&platform]).await, LockrionError::UnauthorizedCaller);
+    fund(&mut ctx, &iss, &issuer).await;
+    assert_eq!(token_balance(&mut ctx, &iss.reward_escrow).await, RESERVE_TOTAL as u64);
+}
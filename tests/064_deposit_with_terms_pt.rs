@@ -0,0 +1,136 @@
+#![forbid(unsafe_code)]
+
+mod common;
+
+use solana_program_test::*;
+use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Signer};
+
+use common::*;
+use lockrion_issuance_v1_1::{
+    cpi,
+    error::LockrionError,
+    instruction::IssuanceParams,
+    metadata::{IssuanceMetadata, MetadataArgs, MAX_NAME_LEN, MAX_SYMBOL_LEN, MAX_URI_LEN, METADATA_SIZE},
+    pda,
+};
+
+// The issuance carries metadata, so deposits must acknowledge its terms hash.
+
+const START_TS: i64 = 1_000_000;
+const MATURITY_TS: i64 = START_TS + DAY * 10;
+const RESERVE_TOTAL: u128 = 1000;
+const TERMS_HASH: [u8; 32] = [0x5a; 32];
+
+#[tokio::test]
+async fn deposit_with_terms_pt() {
+    let program_id = lockrion_issuance_v1_1::id();
+    let mut ctx = program_test().start_with_context().await;
+    let platform = platform();
+    let args = MetadataArgs {
+        name: "Terms test".to_string(),
+        symbol: "TT".to_string(),
+        uri: "https://example.org/terms.json".to_string(),
+        terms_hash: TERMS_HASH,
+    };
+    let params = IssuanceParams { metadata: Some(args), ..IssuanceParams::default() };
+    let iss =
+        funded_issuance(&mut ctx, &platform, Spec::new(RESERVE_TOTAL, START_TS, MATURITY_TS).params(params)).await;
+    assert!(read_issuance(&mut ctx, &iss.pda).await.is_terms_required());
+
+    let p = participant(&mut ctx, &iss, 100).await;
+    set_now(&mut ctx, START_TS + 1).await;
+
+    let with_terms = |terms_hash: [u8; 32]| {
+        cpi::deposit_with_terms_instruction(
+            &program_id,
+            &iss.pda,
+            &p.user,
+            &p.pubkey(),
+            &p.lock_ata,
+            &iss.deposit_escrow,
+            &spl_token::id(),
+            100,
+            terms_hash,
+        )
+        .unwrap()
+    };
+
+    // Plain deposit skips the acknowledgment
+    assert_custom(send(&mut ctx, &[deposit_ix(&iss, &p, 100)], &[&p.kp]).await, LockrionError::TermsNotAcknowledged);
+
+    // Another terms version
+    assert_custom(send(&mut ctx, &[with_terms([0x11; 32])], &[&p.kp]).await, LockrionError::TermsHashMismatch);
+
+    // All-zero hash is never an acknowledgment
+    assert_custom(send(&mut ctx, &[with_terms([0u8; 32])], &[&p.kp]).await, LockrionError::TermsNotAcknowledged);
+
+    send(&mut ctx, &[with_terms(TERMS_HASH)], &[&p.kp]).await.unwrap();
+
+    let user = read_user(&mut ctx, &p.user).await;
+    assert_eq!(user.locked_amount, 100);
+    assert_eq!(user.terms_hash, TERMS_HASH);
+    assert_eq!(user.terms_ack_ts, START_TS + 1);
+}
+
+#[tokio::test]
+async fn init_metadata_pt() {
+    let program_id = lockrion_issuance_v1_1::id();
+    let mut ctx = program_test().start_with_context().await;
+    let platform = platform();
+    let args = MetadataArgs {
+        name: "n".repeat(MAX_NAME_LEN),
+        symbol: "S".repeat(MAX_SYMBOL_LEN),
+        uri: "u".repeat(MAX_URI_LEN),
+        terms_hash: TERMS_HASH,
+    };
+    let spec = |args: MetadataArgs| {
+        Spec::new(RESERVE_TOTAL, START_TS, MATURITY_TS)
+            .params(IssuanceParams { metadata: Some(args), ..IssuanceParams::default() })
+    };
+
+    // One byte over any cap, or an all-zero terms hash
+    let over = [
+        MetadataArgs { name: "n".repeat(MAX_NAME_LEN + 1), ..args.clone() },
+        MetadataArgs { symbol: "S".repeat(MAX_SYMBOL_LEN + 1), ..args.clone() },
+        MetadataArgs { uri: "u".repeat(MAX_URI_LEN + 1), ..args.clone() },
+        MetadataArgs { terms_hash: [0u8; 32], ..args.clone() },
+    ];
+    for args in over {
+        assert_init_rejected(&mut ctx, &platform, spec(args), LockrionError::InvalidIssuanceParams).await;
+    }
+
+    // Metadata account other than the [metadata, issuance] PDA
+    let (issuance, _) = pda::derive_issuance_pda(&program_id, &platform.pubkey(), START_TS, RESERVE_TOTAL);
+    let mint = create_mint(&mut ctx).await;
+    let deposit_escrow = create_token_account(&mut ctx, &mint, &issuance).await;
+    let reward_escrow = create_token_account(&mut ctx, &mint, &issuance).await;
+    let treasury = create_token_account(&mut ctx, &mint, &platform.pubkey()).await;
+    airdrop(&mut ctx, &platform.pubkey(), 1_000_000_000).await;
+    let mut ix = init_ix(
+        &mut ctx,
+        &platform.pubkey(),
+        &issuance,
+        &mint,
+        &mint,
+        &deposit_escrow,
+        &reward_escrow,
+        &treasury,
+        RESERVE_TOTAL,
+        START_TS,
+        MATURITY_TS,
+        IssuanceParams { metadata: Some(args.clone()), ..IssuanceParams::default() },
+    )
+    .await;
+    ix.accounts[8] = AccountMeta::new(Pubkey::new_unique(), false);
+    assert_custom(send(&mut ctx, &[ix], &[&platform]).await, LockrionError::InvalidPda);
+    assert!(account(&mut ctx, &issuance).await.is_none());
+
+    // At the caps: written verbatim to the metadata PDA
+    let iss = create_issuance(&mut ctx, &platform, spec(args.clone())).await;
+    let (metadata, bump) = pda::derive_metadata_pda(&program_id, &iss.pda);
+    assert_eq!(iss.metadata, Some(metadata));
+    let acc = account(&mut ctx, &metadata).await.unwrap();
+    assert_eq!((acc.owner, acc.data.len()), (program_id, METADATA_SIZE));
+    assert_eq!(IssuanceMetadata::unpack(&acc.data).unwrap(), IssuanceMetadata::new(iss.pda, bump, &args));
+    assert!(read_issuance(&mut ctx, &iss.pda).await.is_terms_required());
+}
@@ -0,0 +1,225 @@
+#![forbid(unsafe_code)]
+
+mod common;
+
+use solana_program_test::*;
+use solana_sdk::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction, InstructionError},
+    program::invoke_signed,
+    pubkey::Pubkey,
+    rent::Rent,
+    signature::{Keypair, Signer},
+    system_instruction, system_program,
+    sysvar::Sysvar,
+    transaction::TransactionError,
+};
+
+use common::*;
+use lockrion_issuance_v1_1::{
+    attestation::{Attestation, ATTESTATION_LEN},
+    error::LockrionError,
+    instruction::IssuanceParams,
+};
+
+// A mock attestation program (builtin) issues attestation PDAs [attest, subject, schema].
+
+const START_TS: i64 = 1_000_000;
+const MATURITY_TS: i64 = START_TS + DAY * 10;
+const RESERVE_TOTAL: u128 = 1000;
+
+// ---------------------------------------------------------------------
+// mock attestation program
+// data: schema(32) subject(32) expires_at_le(8)
+// accounts: 0 payer (signer), 1 attestation PDA [attest, subject, schema], 2 system_program
+// ---------------------------------------------------------------------
+fn mock_attestor_process(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let attestation = Attestation::unpack(data)?;
+    let (_, bump) = attestation_address(program_id, &attestation.subject, &attestation.schema);
+    let bump_seed = [bump];
+    let seeds: &[&[u8]] = &[b"attest", attestation.subject.as_ref(), attestation.schema.as_ref(), &bump_seed];
+
+    invoke_signed(
+        &system_instruction::create_account(
+            accounts[0].key,
+            accounts[1].key,
+            Rent::get()?.minimum_balance(ATTESTATION_LEN),
+            ATTESTATION_LEN as u64,
+            program_id,
+        ),
+        &[accounts[0].clone(), accounts[1].clone(), accounts[2].clone()],
+        &[seeds],
+    )?;
+    attestation.pack(&mut accounts[1].try_borrow_mut_data()?)
+}
+
+fn attestation_address(attestor: &Pubkey, subject: &Pubkey, schema: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"attest", subject.as_ref(), schema.as_ref()], attestor)
+}
+
+struct Setup {
+    ctx: ProgramTestContext,
+    attestor: Pubkey,
+    schema: Pubkey,
+    iss: Issuance,
+    participants: Vec<Participant>,
+    spoofed: Pubkey,
+}
+
+async fn setup() -> Setup {
+    let attestor = Pubkey::new_unique();
+    let schema = Pubkey::new_unique();
+
+    let mut pt = program_test();
+    pt.add_program("mock_attestor", attestor, processor!(mock_attestor_process));
+
+    // Right bytes for participant 0, but not owned by the attestation program:
+    // an adversarial account no instruction of the attestor would produce, so seeded
+    let first = Keypair::new();
+    let spoofed = Pubkey::new_unique();
+    let mut data = vec![0u8; ATTESTATION_LEN];
+    Attestation { schema, subject: first.pubkey(), expires_at: 0 }.pack(&mut data).unwrap();
+    pt.add_account(spoofed, program_account(&Pubkey::new_unique(), data));
+
+    let mut ctx = pt.start_with_context().await;
+    let platform = platform();
+    let params =
+        IssuanceParams { attestation_program: attestor, attestation_schema: schema, ..IssuanceParams::default() };
+    let iss =
+        funded_issuance(&mut ctx, &platform, Spec::new(RESERVE_TOTAL, START_TS, MATURITY_TS).params(params)).await;
+
+    let issuance = read_issuance(&mut ctx, &iss.pda).await;
+    assert_eq!(issuance.attestation_program, attestor);
+    assert_eq!(issuance.attestation_schema, schema);
+
+    let mut participants = vec![participant_with(&mut ctx, &iss, first, 100, 1_000_000_000).await];
+    for _ in 0..2 {
+        participants.push(participant(&mut ctx, &iss, 100).await);
+    }
+
+    set_now(&mut ctx, START_TS + 1).await;
+
+    Setup { ctx, attestor, schema, iss, participants, spoofed }
+}
+
+async fn attest(s: &mut Setup, subject: Pubkey, schema: Pubkey, expires_at: i64) -> Pubkey {
+    let address = attestation_address(&s.attestor, &subject, &schema).0;
+    let mut data = vec![0u8; ATTESTATION_LEN];
+    Attestation { schema, subject, expires_at }.pack(&mut data).unwrap();
+
+    let payer = s.ctx.payer.pubkey();
+    let ix = Instruction {
+        program_id: s.attestor,
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new(address, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data,
+    };
+    send(&mut s.ctx, &[ix], &[]).await.unwrap();
+    address
+}
+
+async fn deposit_attested(s: &mut Setup, who: usize, attestation: Option<Pubkey>) -> Result<(), BanksClientError> {
+    let p = &s.participants[who];
+    let mut ix = deposit_ix(&s.iss, p, 100);
+    if let Some(attestation) = attestation {
+        ix.accounts.push(AccountMeta::new_readonly(attestation, false));
+    }
+    send(&mut s.ctx, &[ix], &[&p.kp]).await
+}
+
+async fn transfer(s: &mut Setup, from: usize, to: usize, attestation: Pubkey) -> Result<(), BanksClientError> {
+    let (src, dst) = (&s.participants[from], &s.participants[to]);
+    let mut ix = transfer_position_ix(&s.iss, src, &dst.pubkey());
+    ix.accounts.push(AccountMeta::new_readonly(attestation, false));
+    send(&mut s.ctx, &[ix], &[&src.kp]).await
+}
+
+#[tokio::test]
+async fn attestation_gates_deposit_pt() {
+    let mut s = setup().await;
+    let (p0, p1, p2) = (s.participants[0].pubkey(), s.participants[1].pubkey(), s.participants[2].pubkey());
+    let schema = s.schema;
+
+    let valid = attest(&mut s, p0, schema, 0).await;
+    let other_schema = attest(&mut s, p1, Pubkey::new_unique(), 0).await;
+    let expired = attest(&mut s, p2, schema, START_TS + 1).await;
+
+    // Missing attestation account
+    let err = deposit_attested(&mut s, 0, None).await.unwrap_err();
+    assert!(
+        matches!(
+            err,
+            BanksClientError::TransactionError(TransactionError::InstructionError(
+                0,
+                InstructionError::NotEnoughAccountKeys
+            ))
+        ),
+        "unexpected: {:?}",
+        err
+    );
+
+    // Someone else's attestation / wrong schema / foreign owner
+    assert_custom(deposit_attested(&mut s, 1, Some(valid)).await, LockrionError::InvalidAttestation);
+    assert_custom(deposit_attested(&mut s, 1, Some(other_schema)).await, LockrionError::InvalidAttestation);
+    let spoofed = s.spoofed;
+    assert_custom(deposit_attested(&mut s, 0, Some(spoofed)).await, LockrionError::InvalidAttestation);
+
+    // expires_at == now
+    assert_custom(deposit_attested(&mut s, 2, Some(expired)).await, LockrionError::AttestationExpired);
+
+    deposit_attested(&mut s, 0, Some(valid)).await.unwrap();
+    let user_0 = s.participants[0].user;
+    assert_eq!(read_user(&mut s.ctx, &user_0).await.locked_amount, 100);
+}
+
+#[tokio::test]
+async fn attestation_gates_position_transfer_pt() {
+    let mut s = setup().await;
+    let (p0, p1) = (s.participants[0].pubkey(), s.participants[1].pubkey());
+    let schema = s.schema;
+
+    let valid = attest(&mut s, p0, schema, 0).await;
+    deposit_attested(&mut s, 0, Some(valid)).await.unwrap();
+
+    // Recipient without an attestation of its own
+    assert_custom(transfer(&mut s, 0, 1, valid).await, LockrionError::InvalidAttestation);
+
+    let recipient = attest(&mut s, p1, schema, MATURITY_TS).await;
+    transfer(&mut s, 0, 1, recipient).await.unwrap();
+
+    let user_1 = s.participants[1].user;
+    assert_eq!(read_user(&mut s.ctx, &user_1).await.locked_amount, 100);
+}
+
+#[tokio::test]
+async fn init_attestation_config_pt() {
+    let mut ctx = program_test().start_with_context().await;
+    let platform = platform();
+    let gate = IssuanceParams {
+        attestation_program: Pubkey::new_unique(),
+        attestation_schema: Pubkey::new_unique(),
+        ..IssuanceParams::default()
+    };
+
+    // Receipts move freely between wallets, so they cannot be gated
+    let spec =
+        Spec::new(RESERVE_TOTAL, START_TS, MATURITY_TS).params(IssuanceParams { receipt_mint: true, ..gate.clone() });
+    assert_init_rejected(&mut ctx, &platform, spec, LockrionError::InvalidIssuanceParams).await;
+
+    let iss =
+        create_issuance(&mut ctx, &platform, Spec::new(RESERVE_TOTAL, START_TS, MATURITY_TS).params(gate.clone()))
+            .await;
+    let issuance = read_issuance(&mut ctx, &iss.pda).await;
+    assert_eq!(issuance.attestation_program, gate.attestation_program);
+    assert_eq!(issuance.attestation_schema, gate.attestation_schema);
+
+    // Default params: no gate
+    let iss = create_issuance(&mut ctx, &platform, Spec::new(RESERVE_TOTAL + 1, START_TS, MATURITY_TS)).await;
+    let issuance = read_issuance(&mut ctx, &iss.pda).await;
+    assert_eq!(issuance.attestation_program, Pubkey::default());
+    assert_eq!(issuance.attestation_schema, Pubkey::default());
+}
@@ -0,0 +1,232 @@
+#![forbid(unsafe_code)]
+
+mod common;
+
+use borsh::BorshSerialize;
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    system_program,
+};
+
+use common::*;
+use lockrion_issuance_v1_1::{
+    error::LockrionError,
+    instruction::{IssuanceParams, LockrionInstruction},
+    metadata::MetadataArgs,
+    state::LockTier,
+};
+
+// Series: A matures exactly when B starts; C overlaps B but locks another mint.
+
+const A_START: i64 = 1_000_000;
+const A_MATURITY: i64 = A_START + DAY * 10;
+const B_MATURITY: i64 = A_MATURITY + DAY * 10;
+const RESERVE_TOTAL: u128 = 1000;
+const TERMS_HASH: [u8; 32] = [0x5a; 32];
+
+struct Setup {
+    ctx: ProgramTestContext,
+    participant: Participant,
+    a: Issuance,
+    b: Issuance,
+    c: Issuance,
+}
+
+async fn setup() -> Setup {
+    let mut ctx = program_test().start_with_context().await;
+    let platform = platform();
+
+    let lock_mint = create_mint(&mut ctx).await;
+    let reward_mint = create_mint(&mut ctx).await;
+    let series = |start_ts, maturity_ts| Spec::new(RESERVE_TOTAL, start_ts, maturity_ts).reward_mint(reward_mint);
+
+    let a = funded_issuance(&mut ctx, &platform, series(A_START, A_MATURITY).lock_mint(lock_mint)).await;
+    let b = funded_issuance(&mut ctx, &platform, series(A_MATURITY, B_MATURITY).lock_mint(lock_mint)).await;
+    // Same issuer and start as B: a different reserve keeps the issuance PDA apart
+    let c_spec = Spec { reserve_total: 2 * RESERVE_TOTAL, ..series(A_MATURITY, B_MATURITY) };
+    let c = funded_issuance(&mut ctx, &platform, c_spec).await;
+
+    let participant = participant(&mut ctx, &a, 100).await;
+    Setup { ctx, participant, a, b, c }
+}
+
+fn rollover_ix(
+    source: &Issuance,
+    p: &Participant,
+    target: &Issuance,
+    terms_hash: Option<[u8; 32]>,
+    lock_tier: u8,
+) -> Instruction {
+    let mut metas = vec![
+        AccountMeta::new(source.pda, false),
+        AccountMeta::new(user_pda(source, &p.pubkey()), false),
+        AccountMeta::new(p.pubkey(), true),
+        AccountMeta::new(source.deposit_escrow, false),
+        AccountMeta::new(target.pda, false),
+        AccountMeta::new(user_pda(target, &p.pubkey()), false),
+        AccountMeta::new(target.deposit_escrow, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    if terms_hash.is_some() {
+        metas.push(AccountMeta::new_readonly(target.metadata.unwrap(), false));
+    }
+    mk_ix(
+        lockrion_issuance_v1_1::id(),
+        LockrionInstruction::Rollover { terms_hash, lock_tier }.try_to_vec().unwrap(),
+        metas,
+    )
+}
+
+async fn rollover(s: &mut Setup, target: Issuance) -> Result<(), BanksClientError> {
+    let ix = rollover_ix(&s.a, &s.participant, &target, None, 0);
+    send(&mut s.ctx, &[ix], &[&s.participant.kp]).await
+}
+
+// Another target in the B window on A's lock_mint (reserve keeps the PDA apart)
+async fn target_with(s: &mut Setup, reserve_total: u128, params: IssuanceParams) -> Issuance {
+    let spec = Spec::new(reserve_total, A_MATURITY, B_MATURITY)
+        .lock_mint(s.a.lock_mint)
+        .reward_mint(s.a.reward_mint)
+        .params(params);
+    funded_issuance(&mut s.ctx, &platform(), spec).await
+}
+
+#[tokio::test]
+async fn rollover_moves_principal_between_series_pt() {
+    let mut s = setup().await;
+
+    set_now(&mut s.ctx, A_START + 1).await;
+    deposit(&mut s.ctx, &s.a, &s.participant, 100).await;
+
+    // A still running
+    let b = s.b;
+    set_now(&mut s.ctx, A_MATURITY - 1).await;
+    assert_custom(rollover(&mut s, b).await, LockrionError::DepositWindowNotClosed);
+
+    // Target locks another mint
+    let c = s.c;
+    set_now(&mut s.ctx, A_MATURITY + 1).await;
+    assert_custom(rollover(&mut s, c).await, LockrionError::InvalidMint);
+
+    rollover(&mut s, b).await.unwrap();
+
+    // Principal moved escrow -> escrow; the wallet never held it
+    let a = s.a;
+    assert_eq!(token_balance(&mut s.ctx, &a.deposit_escrow).await, 0);
+    assert_eq!(token_balance(&mut s.ctx, &b.deposit_escrow).await, 100);
+    let participant_lock = s.participant.lock_ata;
+    assert_eq!(token_balance(&mut s.ctx, &participant_lock).await, 0);
+
+    // Source: finalized and emptied like withdraw_deposit
+    let a_user = read_user(&mut s.ctx, &s.participant.user).await;
+    assert_eq!(a_user.locked_amount, 0);
+    assert_eq!(a_user.user_weight_accum, 100 * 10);
+    let a_state = read_issuance(&mut s.ctx, &a.pda).await;
+    assert_eq!(a_state.total_locked, 0);
+    assert_eq!(a_state.total_weight_accum, 100 * 10);
+    assert_eq!(a_state.withdrawn_count, 1);
+    assert_eq!(a_state.total_withdrawn, 100);
+
+    // Target: credited like deposit on B day 0
+    let b_user = read_user(&mut s.ctx, &user_pda(&b, &s.participant.pubkey())).await;
+    assert_eq!(b_user.locked_amount, 100);
+    assert_eq!(b_user.weighted_locked, 100);
+    assert_eq!(b_user.user_last_day_index, 0);
+    assert_eq!(b_user.rent_payer, s.participant.pubkey());
+    let b_state = read_issuance(&mut s.ctx, &b.pda).await;
+    assert_eq!(b_state.total_locked, 100);
+    assert_eq!(b_state.participant_count, 1);
+
+    // A reward is still claimable after the rollover
+    claim(&mut s.ctx, &a, &s.participant).await.unwrap();
+    let participant_reward = s.participant.reward_ata;
+    assert_eq!(token_balance(&mut s.ctx, &participant_reward).await, RESERVE_TOTAL as u64);
+
+    // Nothing left to roll
+    assert_custom(rollover(&mut s, b).await, LockrionError::InvalidAmount);
+}
+
+#[tokio::test]
+async fn rollover_into_terms_target_pt() {
+    let mut s = setup().await;
+    let args = MetadataArgs {
+        name: "Series D".to_string(),
+        symbol: "D".to_string(),
+        uri: "https://example.org/d.json".to_string(),
+        terms_hash: TERMS_HASH,
+    };
+    let d =
+        target_with(&mut s, 3 * RESERVE_TOTAL, IssuanceParams { metadata: Some(args), ..IssuanceParams::default() })
+            .await;
+
+    set_now(&mut s.ctx, A_START + 1).await;
+    deposit(&mut s.ctx, &s.a, &s.participant, 100).await;
+    set_now(&mut s.ctx, A_MATURITY + 1).await;
+
+    let p = &s.participant;
+    let cases = [
+        (None, LockrionError::TermsNotAcknowledged),
+        (Some([0x11; 32]), LockrionError::TermsHashMismatch),
+        (Some([0u8; 32]), LockrionError::TermsNotAcknowledged),
+    ];
+    for (terms_hash, code) in cases {
+        let ix = rollover_ix(&s.a, p, &d, terms_hash, 0);
+        assert_custom(send(&mut s.ctx, &[ix], &[&p.kp]).await, code);
+    }
+
+    let ix = rollover_ix(&s.a, p, &d, Some(TERMS_HASH), 0);
+    send(&mut s.ctx, &[ix], &[&p.kp]).await.unwrap();
+    let d_user = read_user(&mut s.ctx, &user_pda(&d, &p.pubkey())).await;
+    assert_eq!(d_user.locked_amount, 100);
+    assert_eq!(d_user.terms_hash, TERMS_HASH);
+    assert_eq!(d_user.terms_ack_ts, A_MATURITY + 1);
+}
+
+#[tokio::test]
+async fn rollover_into_tiered_target_pt() {
+    let mut s = setup().await;
+    // Tier 0: held to maturity at 2x. Tier 1: 1x, withdrawable from day 3 less 10%.
+    let params = IssuanceParams {
+        lock_tiers: vec![
+            LockTier { unlock_day: 0, mult_bps: 20_000, penalty_bps: 0 },
+            LockTier { unlock_day: 3, mult_bps: 10_000, penalty_bps: 1_000 },
+        ],
+        ..IssuanceParams::default()
+    };
+    let e = target_with(&mut s, 4 * RESERVE_TOTAL, params).await;
+    let a = s.a;
+    let q = participant(&mut s.ctx, &a, 100).await;
+    mint_to(&mut s.ctx, &a.lock_mint, &s.participant.lock_ata, 50).await;
+
+    set_now(&mut s.ctx, A_START + 1).await;
+    deposit(&mut s.ctx, &a, &s.participant, 100).await;
+    deposit(&mut s.ctx, &a, &q, 100).await;
+    set_now(&mut s.ctx, A_MATURITY + 1).await;
+
+    // Unused tier slot
+    let ix = rollover_ix(&a, &q, &e, None, 2);
+    assert_custom(send(&mut s.ctx, &[ix], &[&q.kp]).await, LockrionError::InvalidLockTier);
+
+    // Fresh target position takes the chosen tier (1x)
+    let ix = rollover_ix(&a, &q, &e, None, 1);
+    send(&mut s.ctx, &[ix], &[&q.kp]).await.unwrap();
+    let q_user = read_user(&mut s.ctx, &user_pda(&e, &q.pubkey())).await;
+    assert_eq!((q_user.lock_tier, q_user.locked_amount, q_user.weighted_locked), (1, 100, 100));
+
+    // A target position already holding tier 0 principal cannot switch tiers
+    let p = &s.participant;
+    let mut ix = deposit_ix(&e, p, 50);
+    ix.accounts[1] = AccountMeta::new(user_pda(&e, &p.pubkey()), false);
+    send(&mut s.ctx, &[ix], &[&p.kp]).await.unwrap();
+    let ix = rollover_ix(&a, p, &e, None, 1);
+    assert_custom(send(&mut s.ctx, &[ix], &[&p.kp]).await, LockrionError::InvalidLockTier);
+
+    let ix = rollover_ix(&a, p, &e, None, 0);
+    send(&mut s.ctx, &[ix], &[&p.kp]).await.unwrap();
+    let p_user = read_user(&mut s.ctx, &user_pda(&e, &p.pubkey())).await;
+    assert_eq!((p_user.lock_tier, p_user.locked_amount, p_user.weighted_locked), (0, 150, 300));
+    let e_state = read_issuance(&mut s.ctx, &e.pda).await;
+    assert_eq!((e_state.total_locked, e_state.total_weighted_locked), (250, 400));
+}
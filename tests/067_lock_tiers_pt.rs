@@ -0,0 +1,151 @@
+#![forbid(unsafe_code)]
+
+mod common;
+
+use borsh::BorshSerialize;
+use solana_program::program_pack::Pack;
+use solana_program_test::*;
+use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey};
+
+use common::*;
+use lockrion_issuance_v1_1::{
+    error::LockrionError,
+    instruction::{IssuanceParams, LockrionInstruction},
+    state::{IssuanceState, LockTier, UserState},
+};
+use spl_token::state::Mint;
+
+// Tier 0: held to maturity at 2x. Tier 1: 1x, withdrawable from day 3 less 10%.
+
+const START_TS: i64 = 1_000_000;
+const MATURITY_TS: i64 = START_TS + DAY * 10;
+const RESERVE_TOTAL: u128 = 1000;
+
+async fn withdraw_to(
+    ctx: &mut ProgramTestContext,
+    iss: &Issuance,
+    p: &Participant,
+    penalty_dst: &Pubkey,
+) -> Result<(), BanksClientError> {
+    let mut ix = withdraw_ix(iss, p);
+    ix.accounts.push(AccountMeta::new(*penalty_dst, false));
+    send(ctx, &[ix], &[&p.kp]).await
+}
+
+#[tokio::test]
+async fn lock_tiers_pt() {
+    let mut ctx = program_test().start_with_context().await;
+    let platform = platform();
+    let params = IssuanceParams {
+        lock_tiers: vec![
+            LockTier { unlock_day: 0, mult_bps: 20_000, penalty_bps: 0 },
+            LockTier { unlock_day: 3, mult_bps: 10_000, penalty_bps: 1_000 },
+        ],
+        ..IssuanceParams::default()
+    };
+    let iss =
+        funded_issuance(&mut ctx, &platform, Spec::new(RESERVE_TOTAL, START_TS, MATURITY_TS).params(params)).await;
+
+    let holder = participant(&mut ctx, &iss, 100).await;
+    let early = participant(&mut ctx, &iss, 200).await;
+    let issuer_lock = create_token_account(&mut ctx, &iss.lock_mint, &iss.issuer).await;
+    set_now(&mut ctx, START_TS + 1).await;
+
+    // Plain deposit = tier 0 (2x); tiered deposit into tier 1 (1x)
+    deposit(&mut ctx, &iss, &holder, 100).await;
+    let tiered = |lock_tier: u8| {
+        mk_ix(
+            lockrion_issuance_v1_1::id(),
+            LockrionInstruction::DepositWithTier { amount: 100, lock_tier, terms_hash: None }.try_to_vec().unwrap(),
+            deposit_metas(&iss, &early),
+        )
+    };
+    send(&mut ctx, &[tiered(1)], &[&early.kp]).await.unwrap();
+
+    // Unused slot, then a second tier on a position holding principal
+    assert_custom(send(&mut ctx, &[tiered(2)], &[&early.kp]).await, LockrionError::InvalidLockTier);
+    assert_custom(send(&mut ctx, &[tiered(0)], &[&early.kp]).await, LockrionError::InvalidLockTier);
+
+    let user = read_user(&mut ctx, &early.user).await;
+    assert_eq!((user.lock_tier, user.weighted_locked), (1, 100));
+    let user = read_user(&mut ctx, &holder.user).await;
+    assert_eq!((user.lock_tier, user.weighted_locked), (0, 200));
+
+    // Before the unlock day (and always for tier 0) principal stays locked
+    set_now(&mut ctx, START_TS + 2 * DAY).await;
+    assert_custom(withdraw_to(&mut ctx, &iss, &early, &issuer_lock).await, LockrionError::DepositWindowNotClosed);
+
+    set_now(&mut ctx, START_TS + 5 * DAY).await;
+    assert_custom(withdraw_to(&mut ctx, &iss, &holder, &issuer_lock).await, LockrionError::DepositWindowNotClosed);
+
+    // Penalty must go to an issuer-owned lock-mint account
+    assert_custom(withdraw_to(&mut ctx, &iss, &early, &holder.lock_ata).await, LockrionError::InvalidAuthority);
+
+    withdraw_to(&mut ctx, &iss, &early, &issuer_lock).await.unwrap();
+
+    assert_eq!(token_balance(&mut ctx, &early.lock_ata).await, 100 + 90);
+    assert_eq!(token_balance(&mut ctx, &issuer_lock).await, 10);
+    assert_eq!(token_balance(&mut ctx, &iss.deposit_escrow).await, 100);
+
+    // Weight accrued by the early position stays; totals carry only the 2x holder from now on
+    let user = read_user(&mut ctx, &early.user).await;
+    assert_eq!((user.locked_amount, user.weighted_locked, user.user_weight_accum), (0, 0, 5 * 100));
+    let state = read_issuance(&mut ctx, &iss.pda).await;
+    assert_eq!(state.total_weight_accum, 5 * 300);
+    assert_eq!((state.total_locked, state.total_weighted_locked), (100, 200));
+    assert_eq!((state.withdrawn_count, state.total_withdrawn), (1, 100));
+}
+
+#[tokio::test]
+async fn early_unlock_receipt_mode_pt() {
+    let mut ctx = program_test().start_with_context().await;
+    let platform = platform();
+    let params = IssuanceParams { receipt_mint: true, ..IssuanceParams::default() };
+    let iss =
+        funded_issuance(&mut ctx, &platform, Spec::new(RESERVE_TOTAL, START_TS, MATURITY_TS).params(params)).await;
+    let receipt_mint = iss.receipt_mint.unwrap();
+
+    let early = participant(&mut ctx, &iss, 100).await;
+    let receipt_ata = create_token_account(&mut ctx, &receipt_mint, &early.pubkey()).await;
+    let issuer_lock = create_token_account(&mut ctx, &iss.lock_mint, &iss.issuer).await;
+
+    set_now(&mut ctx, START_TS + 1).await;
+    let mut ix = deposit_ix(&iss, &early, 100);
+    ix.accounts.push(AccountMeta::new(receipt_mint, false));
+    ix.accounts.push(AccountMeta::new(receipt_ata, false));
+    send(&mut ctx, &[ix], &[&early.kp]).await.unwrap();
+    assert_eq!(token_balance(&mut ctx, &receipt_ata).await, 100);
+
+    // InitIssuance refuses receipts together with lock tiers, so the combined state is
+    // written directly; it pins the account order withdraw_deposit reads if both apply.
+    let mut acc = account(&mut ctx, &iss.pda).await.unwrap();
+    let mut state = IssuanceState::unpack(&acc.data).unwrap();
+    state.lock_tiers[0] = LockTier { unlock_day: 0, mult_bps: 10_000, penalty_bps: 0 };
+    state.lock_tiers[1] = LockTier { unlock_day: 3, mult_bps: 10_000, penalty_bps: 1_000 };
+    state.pack(&mut acc.data).unwrap();
+    ctx.set_account(&iss.pda, &acc.into());
+    let mut acc = account(&mut ctx, &early.user).await.unwrap();
+    let mut user = UserState::unpack(&acc.data).unwrap();
+    user.lock_tier = 1;
+    user.pack(&mut acc.data).unwrap();
+    ctx.set_account(&early.user, &acc.into());
+
+    // 6 = issuer_lock_ata, then 7 = receipt_mint, 8 = participant_receipt_ata
+    set_now(&mut ctx, START_TS + 5 * DAY).await;
+    let mut ix = withdraw_ix(&iss, &early);
+    ix.accounts.push(AccountMeta::new(issuer_lock, false));
+    ix.accounts.push(AccountMeta::new(receipt_mint, false));
+    ix.accounts.push(AccountMeta::new(receipt_ata, false));
+    send(&mut ctx, &[ix], &[&early.kp]).await.unwrap();
+
+    // Receipts for the whole principal are burned; the penalty goes to the issuer
+    assert_eq!(token_balance(&mut ctx, &receipt_ata).await, 0);
+    let mint = account(&mut ctx, &receipt_mint).await.unwrap();
+    assert_eq!(Mint::unpack(&mint.data).unwrap().supply, 0);
+    assert_eq!(token_balance(&mut ctx, &early.lock_ata).await, 90);
+    assert_eq!(token_balance(&mut ctx, &issuer_lock).await, 10);
+    assert_eq!(token_balance(&mut ctx, &iss.deposit_escrow).await, 0);
+
+    let state = read_issuance(&mut ctx, &iss.pda).await;
+    assert_eq!((state.total_locked, state.withdrawn_count, state.total_withdrawn), (0, 1, 100));
+}
@@ -0,0 +1,71 @@
+#![forbid(unsafe_code)]
+
+mod common;
+
+use solana_program::program_pack::Pack;
+use solana_program_test::*;
+use solana_sdk::{instruction::AccountMeta, signature::Keypair, system_instruction};
+use spl_token::state::Account as TokenAccount;
+
+use common::*;
+use lockrion_issuance_v1_1::error::LockrionError;
+
+// lock_mint is the native mint: the deposit escrow is a wSOL token account.
+
+const START_TS: i64 = 1_000_000;
+const MATURITY_TS: i64 = START_TS + DAY * 10;
+const RESERVE_TOTAL: u128 = 1000;
+const WALLET_SOL: u64 = 10_000_000_000;
+
+#[tokio::test]
+async fn native_sol_pt() {
+    let mut ctx = program_test().start_with_context().await;
+    let platform = platform();
+    let spec = Spec::new(RESERVE_TOTAL, START_TS, MATURITY_TS).lock_mint(spl_token::native_mint::id());
+    let iss = funded_issuance(&mut ctx, &platform, spec).await;
+
+    // SOL wallet depositor and wSOL token account depositor
+    let wallet = participant_with(&mut ctx, &iss, Keypair::new(), 0, WALLET_SOL).await;
+    let holder = participant_with(&mut ctx, &iss, Keypair::new(), 0, WALLET_SOL).await;
+    let wrap = [
+        system_instruction::transfer(&holder.pubkey(), &holder.lock_ata, 500_000_000),
+        spl_token::instruction::sync_native(&spl_token::id(), &holder.lock_ata).unwrap(),
+    ];
+    send(&mut ctx, &wrap, &[&holder.kp]).await.unwrap();
+
+    set_now(&mut ctx, START_TS + 1).await;
+    let rent = ctx.banks_client.get_rent().await.unwrap();
+    let reserve = rent.minimum_balance(TokenAccount::LEN);
+
+    // Lamports straight from the wallet (account 3 = funder)
+    let mut ix = deposit_ix(&iss, &wallet, 1_000_000_000);
+    ix.accounts[3] = AccountMeta::new(wallet.pubkey(), false);
+    send(&mut ctx, &[ix], &[&wallet.kp]).await.unwrap();
+    let user_rent = rent.minimum_balance(account(&mut ctx, &wallet.user).await.unwrap().data.len());
+    assert_eq!(lamports(&mut ctx, &wallet.pubkey()).await, WALLET_SOL - 1_000_000_000 - user_rent);
+
+    // Already-wrapped SOL keeps the plain token path
+    deposit(&mut ctx, &iss, &holder, 500_000_000).await;
+
+    assert_eq!(token_balance(&mut ctx, &iss.deposit_escrow).await, 1_500_000_000);
+    assert_eq!(lamports(&mut ctx, &iss.deposit_escrow).await, reserve + 1_500_000_000);
+    assert_eq!(read_user(&mut ctx, &wallet.user).await.locked_amount, 1_000_000_000);
+
+    // A non-wSOL token account is still rejected by the mint check
+    let other_mint = create_mint(&mut ctx).await;
+    let stray = create_token_account(&mut ctx, &other_mint, &holder.pubkey()).await;
+    mint_to(&mut ctx, &other_mint, &stray, 10).await;
+    let mut ix = deposit_ix(&iss, &holder, 10);
+    ix.accounts[3] = AccountMeta::new(stray, false);
+    assert_custom(send(&mut ctx, &[ix], &[&holder.kp]).await, LockrionError::InvalidMint);
+
+    // Withdraw unwraps: the wSOL account is closed into the wallet
+    set_now(&mut ctx, MATURITY_TS).await;
+    let before = lamports(&mut ctx, &wallet.pubkey()).await;
+    withdraw(&mut ctx, &iss, &wallet).await.unwrap();
+
+    assert_eq!(lamports(&mut ctx, &wallet.pubkey()).await, before + 1_000_000_000 + reserve);
+    assert!(account(&mut ctx, &wallet.lock_ata).await.is_none());
+    assert_eq!(token_balance(&mut ctx, &iss.deposit_escrow).await, 500_000_000);
+    assert_eq!(lamports(&mut ctx, &iss.deposit_escrow).await, reserve + 500_000_000);
+}
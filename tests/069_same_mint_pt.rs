@@ -0,0 +1,177 @@
+#![forbid(unsafe_code)]
+
+mod common;
+
+use solana_program::program_pack::Pack;
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::{AccountMeta, InstructionError},
+    signature::Signer,
+    transaction::TransactionError,
+};
+use spl_token::state::Account as TokenAccount;
+
+use common::*;
+use lockrion_issuance_v1_1::{error::LockrionError, instruction::IssuanceParams, pda, state::IssuanceState};
+
+// "Lock TOKEN, earn TOKEN": lock_mint == reward_mint with separate escrows.
+
+const START_TS: i64 = 1_000_000;
+const MATURITY_TS: i64 = START_TS + DAY * 10;
+const CLAIM_WINDOW_END: i64 = MATURITY_TS + 90 * DAY;
+const RESERVE_TOTAL: u128 = 1000;
+
+async fn same_mint_issuance(ctx: &mut ProgramTestContext) -> Issuance {
+    let mint = create_mint(ctx).await;
+    let spec = Spec::new(RESERVE_TOTAL, START_TS, MATURITY_TS).lock_mint(mint).reward_mint(mint);
+    create_issuance(ctx, &platform(), spec).await
+}
+
+#[tokio::test]
+async fn init_rejects_shared_escrow_pt() {
+    let mut ctx = program_test().start_with_context().await;
+    let platform = platform();
+    airdrop(&mut ctx, &platform.pubkey(), 1_000_000_000).await;
+
+    let program_id = lockrion_issuance_v1_1::id();
+    let (issuance, _) = pda::derive_issuance_pda(&program_id, &platform.pubkey(), START_TS, RESERVE_TOTAL);
+    let mint = create_mint(&mut ctx).await;
+    let escrow = create_token_account(&mut ctx, &mint, &issuance).await;
+    let treasury = create_token_account(&mut ctx, &mint, &platform.pubkey()).await;
+
+    let ix = init_ix(
+        &mut ctx,
+        &platform.pubkey(),
+        &issuance,
+        &mint,
+        &mint,
+        &escrow,
+        &escrow,
+        &treasury,
+        RESERVE_TOTAL,
+        START_TS,
+        MATURITY_TS,
+        IssuanceParams::default(),
+    )
+    .await;
+    assert_custom(send(&mut ctx, &[ix], &[&platform]).await, LockrionError::InvalidEscrowAccount);
+    assert!(account(&mut ctx, &issuance).await.is_none());
+}
+
+#[tokio::test]
+async fn sweep_keeps_principal_pt() {
+    let mut ctx = program_test().start_with_context().await;
+    let iss = same_mint_issuance(&mut ctx).await;
+    fund(&mut ctx, &iss, &platform()).await;
+
+    // A claims 600 and leaves; B's 40 stays locked and its 400 is never claimed
+    let a = participant(&mut ctx, &iss, 60).await;
+    let b = participant(&mut ctx, &iss, 40).await;
+    set_now(&mut ctx, START_TS).await;
+    deposit(&mut ctx, &iss, &a, 60).await;
+    deposit(&mut ctx, &iss, &b, 40).await;
+    set_now(&mut ctx, MATURITY_TS + 1).await;
+    claim(&mut ctx, &iss, &a).await.unwrap();
+    withdraw(&mut ctx, &iss, &a).await.unwrap();
+    assert_eq!(token_balance(&mut ctx, &a.lock_ata).await, 660);
+
+    set_now(&mut ctx, CLAIM_WINDOW_END + 1).await;
+
+    // Same-mint sweeps must show the deposit escrow, and only the stored one
+    let mut ix = sweep_ix(&iss);
+    ix.accounts.pop();
+    let err = send(&mut ctx, &[ix], &[]).await.unwrap_err();
+    assert!(
+        matches!(
+            err,
+            BanksClientError::TransactionError(TransactionError::InstructionError(
+                0,
+                InstructionError::NotEnoughAccountKeys
+            ))
+        ),
+        "unexpected: {:?}",
+        err
+    );
+    let mut ix = sweep_ix(&iss);
+    ix.accounts[4] = AccountMeta::new_readonly(iss.reward_escrow, false);
+    assert_custom(send(&mut ctx, &[ix], &[]).await, LockrionError::InvalidEscrowAccount);
+
+    // Deposit escrow short of total_locked: nothing may leave the reward escrow.
+    // No instruction can drain the PDA-owned escrow, so the shortfall is written directly.
+    let escrow_acc = account(&mut ctx, &iss.deposit_escrow).await.unwrap();
+    let mut short = escrow_acc.clone();
+    let mut state = TokenAccount::unpack(&short.data).unwrap();
+    state.amount = 20;
+    TokenAccount::pack(state, &mut short.data).unwrap();
+    ctx.set_account(&iss.deposit_escrow, &short.into());
+    assert_custom(send(&mut ctx, &[sweep_ix(&iss)], &[]).await, LockrionError::InvariantViolation);
+    ctx.set_account(&iss.deposit_escrow, &escrow_acc.into());
+
+    // Permissionless: the fee payer is the only signer
+    send(&mut ctx, &[sweep_ix(&iss)], &[]).await.unwrap();
+    assert_eq!(token_balance(&mut ctx, &iss.platform_treasury).await, 400);
+    assert_eq!(token_balance(&mut ctx, &iss.reward_escrow).await, 0);
+    assert_eq!(token_balance(&mut ctx, &iss.deposit_escrow).await, 40);
+
+    // Principal is still withdrawable in full after the sweep
+    withdraw(&mut ctx, &iss, &b).await.unwrap();
+    assert_eq!(token_balance(&mut ctx, &b.lock_ata).await, 40);
+    assert_eq!(token_balance(&mut ctx, &iss.deposit_escrow).await, 0);
+}
+
+#[tokio::test]
+async fn sweep_legacy_shared_escrow_pt() {
+    let mut ctx = program_test().start_with_context().await;
+    let mut iss = same_mint_issuance(&mut ctx).await;
+
+    // Issuances created before InitIssuance rejected a shared escrow can hold principal
+    // and reserve in one account; that state can no longer be created, so it is written directly.
+    let mut acc = account(&mut ctx, &iss.pda).await.unwrap();
+    let mut state = IssuanceState::unpack(&acc.data).unwrap();
+    state.deposit_escrow = state.reward_escrow;
+    state.pack(&mut acc.data).unwrap();
+    ctx.set_account(&iss.pda, &acc.into());
+    iss.deposit_escrow = iss.reward_escrow;
+
+    fund(&mut ctx, &iss, &platform()).await;
+    let a = participant(&mut ctx, &iss, 60).await;
+    let b = participant(&mut ctx, &iss, 40).await;
+    set_now(&mut ctx, START_TS).await;
+    deposit(&mut ctx, &iss, &a, 60).await;
+    deposit(&mut ctx, &iss, &b, 40).await;
+    set_now(&mut ctx, MATURITY_TS + 1).await;
+    claim(&mut ctx, &iss, &a).await.unwrap();
+    withdraw(&mut ctx, &iss, &a).await.unwrap();
+    assert_eq!(token_balance(&mut ctx, &iss.reward_escrow).await, 440);
+
+    // Only the balance above total_locked leaves; B's 40 stays behind
+    set_now(&mut ctx, CLAIM_WINDOW_END + 1).await;
+    send(&mut ctx, &[sweep_ix(&iss)], &[]).await.unwrap();
+    assert_eq!(token_balance(&mut ctx, &iss.platform_treasury).await, 400);
+    assert_eq!(token_balance(&mut ctx, &iss.reward_escrow).await, 40);
+
+    withdraw(&mut ctx, &iss, &b).await.unwrap();
+    assert_eq!(token_balance(&mut ctx, &b.lock_ata).await, 40);
+    assert_eq!(token_balance(&mut ctx, &iss.reward_escrow).await, 0);
+}
+
+#[tokio::test]
+async fn fund_and_reclaim_same_mint_pt() {
+    let mut ctx = program_test().start_with_context().await;
+    let platform = platform();
+    let iss = same_mint_issuance(&mut ctx).await;
+
+    // The deposit escrow has the reward mint and the PDA authority, but is not the reward escrow
+    let mut ix = fund_ix(&iss);
+    ix.accounts[3] = AccountMeta::new(iss.deposit_escrow, false);
+    assert_custom(send(&mut ctx, &[ix], &[&platform]).await, LockrionError::InvalidEscrowAccount);
+    fund(&mut ctx, &iss, &platform).await;
+    assert_eq!(token_balance(&mut ctx, &iss.reward_escrow).await, 1_000);
+    assert_eq!(token_balance(&mut ctx, &iss.deposit_escrow).await, 0);
+
+    // Nobody deposited: the issuer reclaims the reserve, principal side untouched
+    set_now(&mut ctx, MATURITY_TS).await;
+    send(&mut ctx, &[reclaim_ix(&iss)], &[&platform]).await.unwrap();
+    assert_eq!(token_balance(&mut ctx, &iss.issuer_reward).await, 1_000);
+    assert_eq!(token_balance(&mut ctx, &iss.reward_escrow).await, 0);
+}
@@ -0,0 +1,140 @@
+#![forbid(unsafe_code)]
+
+mod common;
+
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::{Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::TransactionError,
+};
+
+use common::*;
+use lockrion_issuance_v1_1::{
+    instruction::IssuanceParams,
+    pda,
+    registry::{self, RegistryHead, RECORDS_PER_PAGE},
+};
+
+const START_TS: i64 = 1_000_000;
+const MATURITY_TS: i64 = START_TS + DAY * 10;
+const RESERVE_TOTAL: u128 = 1000;
+
+// Mints, PDA-owned escrows and treasury for an InitIssuance by the platform.
+async fn init_for(
+    ctx: &mut ProgramTestContext,
+    platform: &Keypair,
+    start_ts: i64,
+    params: IssuanceParams,
+) -> (Pubkey, Instruction) {
+    let program_id = lockrion_issuance_v1_1::id();
+    let issuer = if params.issuer == Pubkey::default() { platform.pubkey() } else { params.issuer };
+    let (issuance, _) = pda::derive_issuance_pda(&program_id, &issuer, start_ts, RESERVE_TOTAL);
+    let mint = create_mint(ctx).await;
+    let deposit_escrow = create_token_account(ctx, &mint, &issuance).await;
+    let reward_escrow = create_token_account(ctx, &mint, &issuance).await;
+    let treasury = create_token_account(ctx, &mint, &platform.pubkey()).await;
+    let ix = init_ix(
+        ctx,
+        &platform.pubkey(),
+        &issuance,
+        &mint,
+        &mint,
+        &deposit_escrow,
+        &reward_escrow,
+        &treasury,
+        RESERVE_TOTAL,
+        start_ts,
+        MATURITY_TS,
+        params,
+    )
+    .await;
+    (issuance, ix)
+}
+
+fn assert_not_enough_keys(res: Result<(), BanksClientError>) {
+    let err = res.expect_err("transaction unexpectedly succeeded");
+    assert!(
+        matches!(
+            err,
+            BanksClientError::TransactionError(TransactionError::InstructionError(
+                0,
+                InstructionError::NotEnoughAccountKeys
+            ))
+        ),
+        "unexpected: {:?}",
+        err
+    );
+}
+
+#[tokio::test]
+async fn registry_accounts_required_pt() {
+    let mut ctx = program_test().start_with_context().await;
+    let platform = platform();
+    airdrop(&mut ctx, &platform.pubkey(), 1_000_000_000).await;
+
+    // No platform registry accounts
+    let (issuance, mut ix) = init_for(&mut ctx, &platform, START_TS, IssuanceParams::default()).await;
+    ix.accounts.truncate(ix.accounts.len() - 2);
+    assert_not_enough_keys(send(&mut ctx, &[ix], &[&platform]).await);
+    assert!(account(&mut ctx, &issuance).await.is_none());
+
+    // Explicit issuer without its registry pair
+    let params = IssuanceParams { issuer: Keypair::new().pubkey(), ..IssuanceParams::default() };
+    let (issuance, mut ix) = init_for(&mut ctx, &platform, START_TS, params).await;
+    ix.accounts.truncate(ix.accounts.len() - 2);
+    assert_not_enough_keys(send(&mut ctx, &[ix], &[&platform]).await);
+    assert!(account(&mut ctx, &issuance).await.is_none());
+    let (platform_head, _) = pda::derive_registry_pda(&lockrion_issuance_v1_1::id(), &platform.pubkey());
+    assert!(account(&mut ctx, &platform_head).await.is_none());
+}
+
+#[tokio::test]
+async fn registry_page_boundary_pt() {
+    let mut ctx = program_test().start_with_context().await;
+    let platform = platform();
+    let program_id = lockrion_issuance_v1_1::id();
+    airdrop(&mut ctx, &platform.pubkey(), 10_000_000_000).await;
+
+    let (head, _) = pda::derive_registry_pda(&program_id, &platform.pubkey());
+    let (page1, _) = pda::derive_registry_page_pda(&program_id, &platform.pubkey(), 1);
+
+    let mut issuances = Vec::new();
+    for i in 0..=RECORDS_PER_PAGE as i64 {
+        let (issuance, ix) = init_for(&mut ctx, &platform, START_TS + i, IssuanceParams::default()).await;
+        if i == RECORDS_PER_PAGE as i64 {
+            // Anyone can pre-fund the next PDAs: above rent for the page, below it for the issuance
+            airdrop(&mut ctx, &page1, 1_000_000_000).await;
+            airdrop(&mut ctx, &issuance, 1_000_000).await;
+        }
+        send(&mut ctx, &[ix], &[&platform]).await.unwrap();
+        issuances.push(issuance);
+    }
+
+    let head_acc = account(&mut ctx, &head).await.unwrap();
+    assert_eq!(RegistryHead::unpack(&head_acc.data).unwrap().count, RECORDS_PER_PAGE + 1);
+
+    let pages = registry::page_addresses(&program_id, &platform.pubkey(), RECORDS_PER_PAGE + 1);
+    assert_eq!(pages.len(), 2);
+    assert_eq!(pages[1], page1);
+
+    let mut listed = Vec::new();
+    for page in &pages {
+        let acc = account(&mut ctx, page).await.unwrap();
+        assert_eq!(acc.owner, program_id);
+        listed.extend(registry::page_records(&acc.data).unwrap().into_iter().map(|r| r.issuance));
+    }
+    assert_eq!(listed, issuances);
+
+    let page1_acc = account(&mut ctx, &page1).await.unwrap();
+    assert_eq!(page1_acc.data.len(), registry::page_size(1));
+    assert_eq!(page1_acc.lamports, 1_000_000_000);
+
+    let last = issuances.last().unwrap();
+    let rent = ctx.banks_client.get_rent().await.unwrap();
+    let last_acc = account(&mut ctx, last).await.unwrap();
+    assert_eq!(last_acc.owner, program_id);
+    assert_eq!(last_acc.lamports, rent.minimum_balance(last_acc.data.len()));
+    assert_eq!(read_issuance(&mut ctx, last).await.start_ts, START_TS + RECORDS_PER_PAGE as i64);
+}
@@ -10,7 +10,7 @@ fn ut_raw_day_before_start_returns_zero() {
     let start = 1_000_000;
     let block = 999_000;
 
-    let idx = raw_day_index(block, start).unwrap();
+    let idx = raw_day_index(block, start, DEFAULT_ACCOUNTING_PERIOD).unwrap();
     assert_eq!(idx, 0);
 }
 
@@ -19,25 +19,25 @@ fn ut_raw_day_within_first_day() {
     let start = 1_000_000;
     let block = start + 10_000; // < 86400
 
-    let idx = raw_day_index(block, start).unwrap();
+    let idx = raw_day_index(block, start, DEFAULT_ACCOUNTING_PERIOD).unwrap();
     assert_eq!(idx, 0);
 }
 
 #[test]
 fn ut_raw_day_exact_boundary() {
     let start = 1_000_000;
-    let block = start + ACCOUNTING_PERIOD;
+    let block = start + DEFAULT_ACCOUNTING_PERIOD;
 
-    let idx = raw_day_index(block, start).unwrap();
+    let idx = raw_day_index(block, start, DEFAULT_ACCOUNTING_PERIOD).unwrap();
     assert_eq!(idx, 1);
 }
 
 #[test]
 fn ut_raw_day_multiple_days() {
     let start = 1_000_000;
-    let block = start + ACCOUNTING_PERIOD * 5 + 1;
+    let block = start + DEFAULT_ACCOUNTING_PERIOD * 5 + 1;
 
-    let idx = raw_day_index(block, start).unwrap();
+    let idx = raw_day_index(block, start, DEFAULT_ACCOUNTING_PERIOD).unwrap();
     assert_eq!(idx, 5);
 }
 
@@ -50,7 +50,7 @@ fn ut_raw_day_overflow_guard() {
     let block = i64::MAX;
     let start = -1;
 
-    let result = raw_day_index(block, start);
+    let result = raw_day_index(block, start, DEFAULT_ACCOUNTING_PERIOD);
 
     assert!(matches!(result, Err(LockrionError::ArithmeticUnderflow)));
 }
@@ -102,8 +102,8 @@ fn ut_same_01_same_timestamp_same_result() {
     let start = 1_000_000;
     let block = start + 10;
 
-    let idx1 = raw_day_index(block, start).unwrap();
-    let idx2 = raw_day_index(block, start).unwrap();
+    let idx1 = raw_day_index(block, start, DEFAULT_ACCOUNTING_PERIOD).unwrap();
+    let idx2 = raw_day_index(block, start, DEFAULT_ACCOUNTING_PERIOD).unwrap();
 
     assert_eq!(idx1, idx2);
 }
@@ -111,10 +111,10 @@ fn ut_same_01_same_timestamp_same_result() {
 #[test]
 fn ut_same_02_repeated_call_deterministic() {
     let start = 1_000_000;
-    let block = start + ACCOUNTING_PERIOD * 3;
+    let block = start + DEFAULT_ACCOUNTING_PERIOD * 3;
 
-    let first = raw_day_index(block, start).unwrap();
-    let second = raw_day_index(block, start).unwrap();
+    let first = raw_day_index(block, start, DEFAULT_ACCOUNTING_PERIOD).unwrap();
+    let second = raw_day_index(block, start, DEFAULT_ACCOUNTING_PERIOD).unwrap();
 
     assert_eq!(first, second);
 }
@@ -124,7 +124,277 @@ fn ut_arith_03_negative_start_safe() {
     let start = -1_000;
     let block = 0;
 
-    let idx = raw_day_index(block, start).unwrap();
+    let idx = raw_day_index(block, start, DEFAULT_ACCOUNTING_PERIOD).unwrap();
 
-    assert_eq!(idx, ((0 - (-1_000)) / ACCOUNTING_PERIOD) as u64);
-}
\ No newline at end of file
+    assert_eq!(idx, ((0 - (-1_000)) / DEFAULT_ACCOUNTING_PERIOD) as u64);
+}
+// =====================================================
+// EARLY-BIRD MULTIPLIER TESTS
+// =====================================================
+
+use lockrion_issuance_v1_1::state::{MultiplierTier, MAX_MULTIPLIER_TIERS};
+
+fn schedule() -> [MultiplierTier; MAX_MULTIPLIER_TIERS] {
+    let mut s = [MultiplierTier::default(); MAX_MULTIPLIER_TIERS];
+    s[0] = MultiplierTier { end_day: 7, mult_bps: 15_000 };
+    s[1] = MultiplierTier { end_day: 14, mult_bps: 12_500 };
+    s
+}
+
+#[test]
+fn ut_mult_empty_schedule_is_one() {
+    let s = [MultiplierTier::default(); MAX_MULTIPLIER_TIERS];
+    assert_eq!(multiplier_bps(&s, 0), MULTIPLIER_BPS_ONE);
+    assert_eq!(multiplier_bps(&s, 1_000), MULTIPLIER_BPS_ONE);
+}
+
+#[test]
+fn ut_mult_tier_boundaries() {
+    let s = schedule();
+    assert_eq!(multiplier_bps(&s, 0), 15_000);
+    assert_eq!(multiplier_bps(&s, 6), 15_000);
+    assert_eq!(multiplier_bps(&s, 7), 12_500);
+    assert_eq!(multiplier_bps(&s, 13), 12_500);
+    assert_eq!(multiplier_bps(&s, 14), MULTIPLIER_BPS_ONE);
+}
+
+#[test]
+fn ut_mult_weighted_amount_floor() {
+    assert_eq!(weighted_amount(100, 15_000).unwrap(), 150);
+    assert_eq!(weighted_amount(3, 12_500).unwrap(), 3); // 3.75 -> 3
+    assert_eq!(weighted_amount(7, MULTIPLIER_BPS_ONE).unwrap(), 7);
+}
+
+#[test]
+fn ut_mult_weighted_amount_overflow() {
+    let r = weighted_amount(u128::MAX, 15_000);
+    assert!(matches!(r, Err(LockrionError::ArithmeticOverflow)));
+}
+
+#[test]
+fn ut_mult_validate_ok() {
+    assert!(validate_multiplier_schedule(&[]).is_ok());
+    assert!(validate_multiplier_schedule(&schedule()[..2]).is_ok());
+}
+
+#[test]
+fn ut_mult_validate_rejects_bad_schedules() {
+    let too_many = [MultiplierTier { end_day: 1, mult_bps: 15_000 }; MAX_MULTIPLIER_TIERS + 1];
+    let not_increasing = [
+        MultiplierTier { end_day: 7, mult_bps: 15_000 },
+        MultiplierTier { end_day: 7, mult_bps: 12_000 },
+    ];
+    let zero_end = [MultiplierTier { end_day: 0, mult_bps: 15_000 }];
+    let below_one = [MultiplierTier { end_day: 3, mult_bps: 9_999 }];
+    let above_max = [MultiplierTier { end_day: 3, mult_bps: MAX_MULTIPLIER_BPS + 1 }];
+
+    for bad in [&too_many[..], &not_increasing[..], &zero_end[..], &below_one[..], &above_max[..]] {
+        assert!(matches!(
+            validate_multiplier_schedule(bad),
+            Err(LockrionError::InvalidIssuanceParams)
+        ));
+    }
+}
+
+// =====================================================
+// ACCOUNTING PERIOD TESTS
+// =====================================================
+
+#[test]
+fn ut_period_hourly_buckets() {
+    let start = 1_000_000;
+    assert_eq!(raw_day_index(start + 3_599, start, 3_600).unwrap(), 0);
+    assert_eq!(raw_day_index(start + 3_600, start, 3_600).unwrap(), 1);
+    assert_eq!(raw_day_index(start + 86_400, start, 3_600).unwrap(), 24);
+}
+
+#[test]
+fn ut_period_weekly_buckets() {
+    let start = 0;
+    let week = 7 * 86_400;
+    assert_eq!(raw_day_index(week - 1, start, week).unwrap(), 0);
+    assert_eq!(raw_day_index(week * 3 + 5, start, week).unwrap(), 3);
+}
+
+#[test]
+fn ut_period_zero_rejected() {
+    assert!(matches!(raw_day_index(10, 0, 0), Err(LockrionError::DivisionByZero)));
+    assert!(matches!(raw_day_index(10, 0, -5), Err(LockrionError::DivisionByZero)));
+}
+
+#[test]
+fn ut_final_day_index_uses_period() {
+    assert_eq!(final_day_index(0, 86_400 * 10, DEFAULT_ACCOUNTING_PERIOD).unwrap(), 10);
+    assert_eq!(final_day_index(0, 86_400, 3_600).unwrap(), 24);
+    assert_eq!(final_day_index(100, 100, 3_600).unwrap(), 0);
+    assert_eq!(final_day_index(100, 50, 3_600).unwrap(), 0);
+}
+
+#[test]
+fn ut_final_day_index_period_bounds() {
+    for bad in [0, MIN_ACCOUNTING_PERIOD - 1, MAX_ACCOUNTING_PERIOD + 1] {
+        assert!(matches!(
+            final_day_index(0, 86_400, bad),
+            Err(LockrionError::InvalidIssuanceParams)
+        ));
+    }
+    assert!(final_day_index(0, 86_400, MIN_ACCOUNTING_PERIOD).is_ok());
+    assert!(final_day_index(0, 86_400, MAX_ACCOUNTING_PERIOD).is_ok());
+}
+
+#[test]
+fn ut_final_day_index_capped() {
+    let too_long = (MAX_FINAL_DAY_INDEX as i64) + 1;
+    assert!(matches!(
+        final_day_index(0, too_long, 1),
+        Err(LockrionError::InvalidIssuanceParams)
+    ));
+    assert_eq!(final_day_index(0, MAX_FINAL_DAY_INDEX as i64, 1).unwrap(), MAX_FINAL_DAY_INDEX);
+}
+
+// =====================================================
+// PRO-RATED FINAL PERIOD TESTS
+// =====================================================
+
+const TAIL_START: i64 = 1_000_000;
+// 10 whole days + 1 hour
+const TAIL_MATURITY: i64 = TAIL_START + 10 * 86_400 + 3_600;
+
+#[test]
+fn ut_tail_length_vectors() {
+    let p = DEFAULT_ACCOUNTING_PERIOD;
+    assert_eq!(final_period_tail(TAIL_START, TAIL_START + 10 * p, p).unwrap(), 0);
+    assert_eq!(final_period_tail(TAIL_START, TAIL_MATURITY, p).unwrap(), 3_600);
+    assert_eq!(final_period_tail(TAIL_START, TAIL_START + 10 * p - 1, p).unwrap(), p - 1);
+    assert_eq!(final_period_tail(TAIL_START, TAIL_START - 5, p).unwrap(), 0);
+}
+
+#[test]
+fn ut_tail_prorated_final_day_index_vectors() {
+    let p = DEFAULT_ACCOUNTING_PERIOD;
+    assert_eq!(prorated_final_day_index(TAIL_START, TAIL_START + 10 * p, p).unwrap(), (10, 0));
+    assert_eq!(prorated_final_day_index(TAIL_START, TAIL_MATURITY, p).unwrap(), (11, 3_600));
+    assert_eq!(prorated_final_day_index(TAIL_START, TAIL_START + 1, p).unwrap(), (1, 1));
+    assert_eq!(prorated_final_day_index(TAIL_START, TAIL_START, p).unwrap(), (0, 0));
+}
+
+#[test]
+fn ut_tail_prorated_final_day_index_capped() {
+    let too_long = MAX_FINAL_DAY_INDEX as i64 * 2 + 1; // whole = MAX, tail pushes past it
+    assert!(matches!(
+        prorated_final_day_index(0, too_long, 2),
+        Err(LockrionError::InvalidIssuanceParams)
+    ));
+}
+
+#[test]
+fn ut_tail_current_day_index_around_maturity() {
+    let p = DEFAULT_ACCOUNTING_PERIOD;
+    let cur = |now| current_day_index(now, TAIL_START, TAIL_MATURITY, p, 11).unwrap();
+    assert_eq!(cur(TAIL_START + 10 * p - 1), 9);
+    assert_eq!(cur(TAIL_START + 10 * p), 10); // inside the tail
+    assert_eq!(cur(TAIL_MATURITY - 1), 10);
+    assert_eq!(cur(TAIL_MATURITY), 11); // tail closes exactly at maturity
+    assert_eq!(cur(TAIL_MATURITY + 100 * p), 11);
+}
+
+#[test]
+fn ut_tail_current_day_index_plain_matches_bounded() {
+    let p = DEFAULT_ACCOUNTING_PERIOD;
+    for now in [TAIL_START, TAIL_START + 5 * p, TAIL_MATURITY - 1, TAIL_MATURITY, TAIL_MATURITY + 7 * p] {
+        let raw = raw_day_index(now, TAIL_START, p).unwrap();
+        assert_eq!(
+            current_day_index(now, TAIL_START, TAIL_MATURITY, p, 10).unwrap(),
+            bounded_day_index(raw, 10)
+        );
+    }
+}
+
+#[test]
+fn ut_tail_period_units_plain() {
+    assert_eq!(period_units(2, 5, 10, DEFAULT_ACCOUNTING_PERIOD, 0).unwrap(), 3);
+    assert_eq!(period_units(5, 5, 10, DEFAULT_ACCOUNTING_PERIOD, 0).unwrap(), 0);
+    assert_eq!(period_units(6, 5, 10, DEFAULT_ACCOUNTING_PERIOD, 0).unwrap(), 0);
+}
+
+#[test]
+fn ut_tail_period_units_prorated() {
+    let p = DEFAULT_ACCOUNTING_PERIOD as u128;
+    let u = |from, to| period_units(from, to, 11, DEFAULT_ACCOUNTING_PERIOD, 3_600).unwrap();
+    assert_eq!(u(0, 10), 10 * p);
+    assert_eq!(u(10, 11), 3_600);
+    assert_eq!(u(9, 11), p + 3_600);
+    assert_eq!(u(0, 11), 10 * p + 3_600);
+    // split accrual equals single-step accrual
+    assert_eq!(u(0, 4) + u(4, 10) + u(10, 11), u(0, 11));
+}
+
+#[test]
+fn ut_tail_period_units_guards() {
+    let p = DEFAULT_ACCOUNTING_PERIOD;
+    assert!(matches!(period_units(0, 12, 11, p, 3_600), Err(LockrionError::InvariantViolation)));
+    assert!(matches!(period_units(0, 5, 11, p, p), Err(LockrionError::InvariantViolation)));
+    assert!(matches!(period_units(0, 5, 11, p, -1), Err(LockrionError::InvariantViolation)));
+}
+
+// =====================================================
+// LOCK TIER TESTS
+// =====================================================
+
+use lockrion_issuance_v1_1::state::{LockTier, MAX_LOCK_TIERS};
+
+fn lock_tiers() -> [LockTier; MAX_LOCK_TIERS] {
+    let mut t = [LockTier::default(); MAX_LOCK_TIERS];
+    t[0] = LockTier { unlock_day: 0, mult_bps: 20_000, penalty_bps: 0 };
+    t[1] = LockTier { unlock_day: 30, mult_bps: 12_000, penalty_bps: 500 };
+    t
+}
+
+#[test]
+fn ut_lock_tier_lookup() {
+    let empty = [LockTier::default(); MAX_LOCK_TIERS];
+    assert_eq!(lock_tier(&empty, 0).unwrap().mult_bps, MULTIPLIER_BPS_ONE);
+    assert_eq!(lock_tier(&empty, 0).unwrap().unlock_day, 0);
+    assert!(matches!(lock_tier(&empty, 1), Err(LockrionError::InvalidLockTier)));
+
+    let t = lock_tiers();
+    assert_eq!(lock_tier(&t, 0).unwrap(), t[0]);
+    assert_eq!(lock_tier(&t, 1).unwrap(), t[1]);
+    // unused slot and out of range
+    assert!(matches!(lock_tier(&t, 2), Err(LockrionError::InvalidLockTier)));
+    assert!(matches!(lock_tier(&t, MAX_LOCK_TIERS as u8), Err(LockrionError::InvalidLockTier)));
+}
+
+#[test]
+fn ut_lock_tier_penalty_floor() {
+    assert_eq!(penalty_amount(1_000, 500).unwrap(), 50);
+    assert_eq!(penalty_amount(19, 500).unwrap(), 0);
+    assert_eq!(penalty_amount(1_000, 0).unwrap(), 0);
+    assert_eq!(penalty_amount(1_000, 10_000).unwrap(), 1_000);
+    assert!(matches!(penalty_amount(1_000, 10_001), Err(LockrionError::InvariantViolation)));
+}
+
+#[test]
+fn ut_lock_tier_validate() {
+    assert!(validate_lock_tiers(&[], 90).is_ok());
+    assert!(validate_lock_tiers(&lock_tiers()[..2], 90).is_ok());
+
+    let ok = LockTier { unlock_day: 30, mult_bps: 12_000, penalty_bps: 500 };
+    let too_many = [ok; MAX_LOCK_TIERS + 1];
+    let below_one = [LockTier { mult_bps: 9_999, ..ok }];
+    let above_max = [LockTier { mult_bps: MAX_MULTIPLIER_BPS + 1, ..ok }];
+    let penalty_over = [LockTier { penalty_bps: 10_001, ..ok }];
+    let penalty_at_maturity = [LockTier { unlock_day: 0, ..ok }];
+    let unlock_at_final = [LockTier { unlock_day: 90, ..ok }];
+
+    for bad in [
+        &too_many[..],
+        &below_one[..],
+        &above_max[..],
+        &penalty_over[..],
+        &penalty_at_maturity[..],
+        &unlock_at_final[..],
+    ] {
+        assert!(matches!(validate_lock_tiers(bad, 90), Err(LockrionError::InvalidIssuanceParams)));
+    }
+}
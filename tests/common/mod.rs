@@ -0,0 +1,613 @@
+// ==============================
+// tests/common/mod.rs (shared program-test fixtures)
+// ==============================
+#![forbid(unsafe_code)]
+#![allow(dead_code)]
+
+use borsh::BorshSerialize;
+use solana_program::program_pack::Pack;
+use solana_program_test::*;
+use solana_sdk::{
+    account::Account,
+    clock::Clock,
+    instruction::{AccountMeta, Instruction, InstructionError},
+    program_option::COption,
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair, Signer},
+    system_instruction, system_program,
+    transaction::{Transaction, TransactionError},
+};
+use spl_token::state::{Account as TokenAccount, AccountState, Mint};
+
+use lockrion_issuance_v1_1::{
+    error::LockrionError,
+    instruction::{IssuanceParams, LockrionInstruction},
+    pda,
+    registry::{self, RegistryHead},
+    state::{IssuanceState, UserState},
+};
+
+pub const DAY: i64 = 86_400;
+
+pub fn program_test() -> ProgramTest {
+    ProgramTest::new(
+        "lockrion_issuance_v1_1",
+        lockrion_issuance_v1_1::id(),
+        processor!(lockrion_issuance_v1_1::entrypoint::process_instruction),
+    )
+}
+
+/// Platform authority keypair (the only InitIssuance signer), as in tests 011-046.
+pub fn platform() -> Keypair {
+    read_keypair_file("platform-authority.json").unwrap()
+}
+
+pub fn mk_ix(program_id: Pubkey, data: Vec<u8>, metas: Vec<AccountMeta>) -> Instruction {
+    Instruction { program_id, accounts: metas, data }
+}
+
+fn ix(data: LockrionInstruction, metas: Vec<AccountMeta>) -> Instruction {
+    mk_ix(lockrion_issuance_v1_1::id(), data.try_to_vec().unwrap(), metas)
+}
+
+// ---------------------------------------------------------------------
+// transactions / errors
+// ---------------------------------------------------------------------
+
+/// Sends `ixs` paid by ctx.payer; a fresh blockhash lets identical transactions repeat.
+pub async fn send(
+    ctx: &mut ProgramTestContext,
+    ixs: &[Instruction],
+    signers: &[&Keypair],
+) -> Result<(), BanksClientError> {
+    let payer = ctx.payer.pubkey();
+    let bh = ctx.get_new_latest_blockhash().await.unwrap();
+    let mut all: Vec<&Keypair> = vec![&ctx.payer];
+    all.extend_from_slice(signers);
+    let tx = Transaction::new_signed_with_payer(ixs, Some(&payer), &all, bh);
+    ctx.banks_client.process_transaction(tx).await
+}
+
+pub fn custom_code(err: &BanksClientError) -> Option<u32> {
+    match err {
+        BanksClientError::TransactionError(TransactionError::InstructionError(_, InstructionError::Custom(c))) => {
+            Some(*c)
+        }
+        _ => None,
+    }
+}
+
+pub fn is_custom(err: &BanksClientError, code: LockrionError) -> bool {
+    custom_code(err) == Some(code as u32)
+}
+
+#[track_caller]
+pub fn assert_custom(res: Result<(), BanksClientError>, code: LockrionError) {
+    let err = res.expect_err("transaction unexpectedly succeeded");
+    assert_eq!(custom_code(&err), Some(code as u32), "unexpected error: {err:?}");
+}
+
+// ---------------------------------------------------------------------
+// clock / reads
+// ---------------------------------------------------------------------
+
+pub async fn set_now(ctx: &mut ProgramTestContext, ts: i64) {
+    let mut c: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    c.unix_timestamp = ts;
+    c.slot = (ts as u64) * 2; // test-clock: now = slot / 2
+    ctx.set_sysvar(&c);
+}
+
+pub async fn account(ctx: &mut ProgramTestContext, key: &Pubkey) -> Option<Account> {
+    ctx.banks_client.get_account(*key).await.unwrap()
+}
+
+pub async fn lamports(ctx: &mut ProgramTestContext, key: &Pubkey) -> u64 {
+    account(ctx, key).await.map(|a| a.lamports).unwrap_or(0)
+}
+
+pub async fn token_balance(ctx: &mut ProgramTestContext, token_acc: &Pubkey) -> u64 {
+    let acc = account(ctx, token_acc).await.unwrap();
+    TokenAccount::unpack_from_slice(&acc.data).unwrap().amount
+}
+
+pub async fn read_issuance(ctx: &mut ProgramTestContext, key: &Pubkey) -> IssuanceState {
+    IssuanceState::load(&account(ctx, key).await.unwrap().data).unwrap()
+}
+
+pub async fn read_user(ctx: &mut ProgramTestContext, key: &Pubkey) -> UserState {
+    UserState::load(&account(ctx, key).await.unwrap().data).unwrap()
+}
+
+// ---------------------------------------------------------------------
+// raw accounts: only for state no instruction can produce (legacy layouts,
+// spoofed / manipulated accounts)
+// ---------------------------------------------------------------------
+
+pub fn mint_account(supply: u64) -> Account {
+    let mut data = vec![0u8; Mint::LEN];
+    Mint::pack(
+        Mint {
+            mint_authority: COption::None,
+            supply,
+            decimals: 0,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        },
+        &mut data,
+    )
+    .unwrap();
+    Account { lamports: 1_000_000_000, data, owner: spl_token::id(), executable: false, rent_epoch: 0 }
+}
+
+pub fn token_account(mint: &Pubkey, owner: &Pubkey, amount: u64) -> Account {
+    let mut data = vec![0u8; TokenAccount::LEN];
+    TokenAccount::pack(
+        TokenAccount {
+            mint: *mint,
+            owner: *owner,
+            amount,
+            state: AccountState::Initialized,
+            ..TokenAccount::default()
+        },
+        &mut data,
+    )
+    .unwrap();
+    Account { lamports: 1_000_000_000, data, owner: spl_token::id(), executable: false, rent_epoch: 0 }
+}
+
+pub fn program_account(program_id: &Pubkey, data: Vec<u8>) -> Account {
+    Account { lamports: 1_000_000_000, data, owner: *program_id, executable: false, rent_epoch: 0 }
+}
+
+// ---------------------------------------------------------------------
+// SPL token / system setup (mint authority = ctx.payer)
+// ---------------------------------------------------------------------
+
+pub async fn airdrop(ctx: &mut ProgramTestContext, to: &Pubkey, amount: u64) {
+    let ix = system_instruction::transfer(&ctx.payer.pubkey(), to, amount);
+    send(ctx, &[ix], &[]).await.unwrap();
+}
+
+pub async fn create_mint(ctx: &mut ProgramTestContext) -> Pubkey {
+    create_mint_with_decimals(ctx, 0).await
+}
+
+pub async fn create_mint_with_decimals(ctx: &mut ProgramTestContext, decimals: u8) -> Pubkey {
+    let mint = Keypair::new();
+    let rent = ctx.banks_client.get_rent().await.unwrap();
+    let payer = ctx.payer.pubkey();
+    let ixs = [
+        system_instruction::create_account(
+            &payer,
+            &mint.pubkey(),
+            rent.minimum_balance(Mint::LEN),
+            Mint::LEN as u64,
+            &spl_token::id(),
+        ),
+        spl_token::instruction::initialize_mint(&spl_token::id(), &mint.pubkey(), &payer, None, decimals).unwrap(),
+    ];
+    send(ctx, &ixs, &[&mint]).await.unwrap();
+    mint.pubkey()
+}
+
+pub async fn create_token_account(ctx: &mut ProgramTestContext, mint: &Pubkey, owner: &Pubkey) -> Pubkey {
+    let acct = Keypair::new();
+    let rent = ctx.banks_client.get_rent().await.unwrap();
+    let payer = ctx.payer.pubkey();
+    let ixs = [
+        system_instruction::create_account(
+            &payer,
+            &acct.pubkey(),
+            rent.minimum_balance(TokenAccount::LEN),
+            TokenAccount::LEN as u64,
+            &spl_token::id(),
+        ),
+        spl_token::instruction::initialize_account(&spl_token::id(), &acct.pubkey(), mint, owner).unwrap(),
+    ];
+    send(ctx, &ixs, &[&acct]).await.unwrap();
+    acct.pubkey()
+}
+
+pub async fn mint_to(ctx: &mut ProgramTestContext, mint: &Pubkey, dst: &Pubkey, amount: u64) {
+    let payer = ctx.payer.pubkey();
+    let ix = spl_token::instruction::mint_to(&spl_token::id(), mint, dst, &payer, &[], amount).unwrap();
+    send(ctx, &[ix], &[]).await.unwrap();
+}
+
+pub async fn token_transfer(ctx: &mut ProgramTestContext, src: &Pubkey, dst: &Pubkey, owner: &Keypair, amount: u64) {
+    let ix = spl_token::instruction::transfer(&spl_token::id(), src, dst, &owner.pubkey(), &[], amount).unwrap();
+    send(ctx, &[ix], &[owner]).await.unwrap();
+}
+
+// ---------------------------------------------------------------------
+// issuance setup through InitIssuance / FundReserve
+// ---------------------------------------------------------------------
+
+pub struct Spec {
+    pub reserve_total: u128,
+    pub start_ts: i64,
+    pub maturity_ts: i64,
+    pub params: IssuanceParams,
+    /// Existing lock mint (default: a fresh mint).
+    pub lock_mint: Option<Pubkey>,
+    /// Existing reward mint (default: a fresh mint).
+    pub reward_mint: Option<Pubkey>,
+}
+
+impl Spec {
+    pub fn new(reserve_total: u128, start_ts: i64, maturity_ts: i64) -> Self {
+        Spec {
+            reserve_total,
+            start_ts,
+            maturity_ts,
+            params: IssuanceParams::default(),
+            lock_mint: None,
+            reward_mint: None,
+        }
+    }
+
+    pub fn params(mut self, params: IssuanceParams) -> Self {
+        self.params = params;
+        self
+    }
+
+    pub fn lock_mint(mut self, mint: Pubkey) -> Self {
+        self.lock_mint = Some(mint);
+        self
+    }
+
+    pub fn reward_mint(mut self, mint: Pubkey) -> Self {
+        self.reward_mint = Some(mint);
+        self
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Issuance {
+    pub pda: Pubkey,
+    pub issuer: Pubkey,
+    pub lock_mint: Pubkey,
+    pub reward_mint: Pubkey,
+    pub deposit_escrow: Pubkey,
+    pub reward_escrow: Pubkey,
+    pub platform_treasury: Pubkey,
+    /// Reward account of the issuer, minted reserve_total (FundReserve source).
+    pub issuer_reward: Pubkey,
+    pub receipt_mint: Option<Pubkey>,
+    pub metadata: Option<Pubkey>,
+    pub reserve_total: u128,
+    pub start_ts: i64,
+    pub maturity_ts: i64,
+}
+
+pub async fn registry_accounts(ctx: &mut ProgramTestContext, scope: &Pubkey) -> (Pubkey, Pubkey) {
+    let program_id = lockrion_issuance_v1_1::id();
+    let (head, _) = pda::derive_registry_pda(&program_id, scope);
+    let count = match account(ctx, &head).await {
+        Some(acc) if acc.owner == program_id => RegistryHead::unpack(&acc.data).unwrap().count,
+        _ => 0,
+    };
+    let (page, _) = pda::derive_registry_page_pda(&program_id, scope, registry::page_index(count).unwrap());
+    (head, page)
+}
+
+/// InitIssuance instruction for the given accounts (receipt, metadata and
+/// registry accounts follow `params`).
+#[allow(clippy::too_many_arguments)]
+pub async fn init_ix(
+    ctx: &mut ProgramTestContext,
+    platform: &Pubkey,
+    issuance: &Pubkey,
+    lock_mint: &Pubkey,
+    reward_mint: &Pubkey,
+    deposit_escrow: &Pubkey,
+    reward_escrow: &Pubkey,
+    platform_treasury: &Pubkey,
+    reserve_total: u128,
+    start_ts: i64,
+    maturity_ts: i64,
+    params: IssuanceParams,
+) -> Instruction {
+    let program_id = lockrion_issuance_v1_1::id();
+    let mut metas = vec![
+        AccountMeta::new(*platform, true),
+        AccountMeta::new(*issuance, false),
+        AccountMeta::new_readonly(*lock_mint, false),
+        AccountMeta::new_readonly(*reward_mint, false),
+        AccountMeta::new_readonly(*deposit_escrow, false),
+        AccountMeta::new_readonly(*reward_escrow, false),
+        AccountMeta::new_readonly(*platform_treasury, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    if params.receipt_mint {
+        let (receipt_mint, _) = pda::derive_receipt_mint_pda(&program_id, issuance);
+        metas.push(AccountMeta::new(receipt_mint, false));
+        metas.push(AccountMeta::new_readonly(spl_token::id(), false));
+    }
+    if params.metadata.is_some() {
+        metas.push(AccountMeta::new(pda::derive_metadata_pda(&program_id, issuance).0, false));
+    }
+    let (head, page) = registry_accounts(ctx, platform).await;
+    metas.push(AccountMeta::new(head, false));
+    metas.push(AccountMeta::new(page, false));
+    if params.issuer != Pubkey::default() && params.issuer != *platform {
+        let (head, page) = registry_accounts(ctx, &params.issuer).await;
+        metas.push(AccountMeta::new(head, false));
+        metas.push(AccountMeta::new(page, false));
+    }
+    ix(LockrionInstruction::InitIssuance { reserve_total, start_ts, maturity_ts, params }, metas)
+}
+
+/// Creates mints (unless given), PDA-owned escrows, treasury and a funded issuer
+/// reward account, then runs InitIssuance signed by `platform`.
+pub async fn create_issuance(ctx: &mut ProgramTestContext, platform: &Keypair, spec: Spec) -> Issuance {
+    let (iss, res) = try_create_issuance(ctx, platform, spec).await;
+    res.unwrap();
+    iss
+}
+
+/// InitIssuance expected to fail with `code`: neither the issuance nor its
+/// receipt mint / metadata PDAs may exist afterwards.
+pub async fn assert_init_rejected(ctx: &mut ProgramTestContext, platform: &Keypair, spec: Spec, code: LockrionError) {
+    let (iss, res) = try_create_issuance(ctx, platform, spec).await;
+    assert_custom(res, code);
+    assert!(account(ctx, &iss.pda).await.is_none());
+    let program_id = lockrion_issuance_v1_1::id();
+    assert!(account(ctx, &pda::derive_receipt_mint_pda(&program_id, &iss.pda).0).await.is_none());
+    assert!(account(ctx, &pda::derive_metadata_pda(&program_id, &iss.pda).0).await.is_none());
+}
+
+/// Same setup as create_issuance, returning the InitIssuance result unchecked.
+pub async fn try_create_issuance(
+    ctx: &mut ProgramTestContext,
+    platform: &Keypair,
+    spec: Spec,
+) -> (Issuance, Result<(), BanksClientError>) {
+    let program_id = lockrion_issuance_v1_1::id();
+    let issuer = if spec.params.issuer == Pubkey::default() { platform.pubkey() } else { spec.params.issuer };
+    let (issuance, _) = pda::derive_issuance_pda(&program_id, &issuer, spec.start_ts, spec.reserve_total);
+
+    let lock_mint = match spec.lock_mint {
+        Some(m) => m,
+        None => create_mint(ctx).await,
+    };
+    let reward_mint = match spec.reward_mint {
+        Some(m) => m,
+        None => create_mint(ctx).await,
+    };
+    let deposit_escrow = create_token_account(ctx, &lock_mint, &issuance).await;
+    let reward_escrow = create_token_account(ctx, &reward_mint, &issuance).await;
+    let platform_treasury = create_token_account(ctx, &reward_mint, &platform.pubkey()).await;
+    let issuer_reward = create_token_account(ctx, &reward_mint, &issuer).await;
+    mint_to(ctx, &reward_mint, &issuer_reward, spec.reserve_total as u64).await;
+
+    airdrop(ctx, &platform.pubkey(), 1_000_000_000).await;
+    let receipt_mint = spec.params.receipt_mint.then(|| pda::derive_receipt_mint_pda(&program_id, &issuance).0);
+    let metadata = spec.params.metadata.is_some().then(|| pda::derive_metadata_pda(&program_id, &issuance).0);
+    let init = init_ix(
+        ctx,
+        &platform.pubkey(),
+        &issuance,
+        &lock_mint,
+        &reward_mint,
+        &deposit_escrow,
+        &reward_escrow,
+        &platform_treasury,
+        spec.reserve_total,
+        spec.start_ts,
+        spec.maturity_ts,
+        spec.params,
+    )
+    .await;
+    let res = send(ctx, &[init], &[platform]).await;
+
+    let iss = Issuance {
+        pda: issuance,
+        issuer,
+        lock_mint,
+        reward_mint,
+        deposit_escrow,
+        reward_escrow,
+        platform_treasury,
+        issuer_reward,
+        receipt_mint,
+        metadata,
+        reserve_total: spec.reserve_total,
+        start_ts: spec.start_ts,
+        maturity_ts: spec.maturity_ts,
+    };
+    (iss, res)
+}
+
+pub fn fund_ix(iss: &Issuance) -> Instruction {
+    ix(
+        LockrionInstruction::FundReserve { amount: iss.reserve_total as u64 },
+        vec![
+            AccountMeta::new(iss.pda, false),
+            AccountMeta::new(iss.issuer, true),
+            AccountMeta::new(iss.issuer_reward, false),
+            AccountMeta::new(iss.reward_escrow, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+    )
+}
+
+/// FundReserve signed by the issuer keypair (before start_ts).
+pub async fn fund(ctx: &mut ProgramTestContext, iss: &Issuance, issuer: &Keypair) {
+    send(ctx, &[fund_ix(iss)], &[issuer]).await.unwrap();
+}
+
+/// create_issuance + fund, for issuances whose issuer is the platform.
+pub async fn funded_issuance(ctx: &mut ProgramTestContext, platform: &Keypair, spec: Spec) -> Issuance {
+    let iss = create_issuance(ctx, platform, spec).await;
+    fund(ctx, &iss, platform).await;
+    iss
+}
+
+// ---------------------------------------------------------------------
+// participants and instructions
+// ---------------------------------------------------------------------
+
+pub struct Participant {
+    pub kp: Keypair,
+    pub lock_ata: Pubkey,
+    pub reward_ata: Pubkey,
+    pub user: Pubkey,
+}
+
+impl Participant {
+    pub fn pubkey(&self) -> Pubkey {
+        self.kp.pubkey()
+    }
+}
+
+/// Wallet with SOL, lock / reward token accounts and `amount` lock tokens.
+pub async fn participant(ctx: &mut ProgramTestContext, iss: &Issuance, amount: u64) -> Participant {
+    participant_with(ctx, iss, Keypair::new(), amount, 1_000_000_000).await
+}
+
+pub async fn participant_with(
+    ctx: &mut ProgramTestContext,
+    iss: &Issuance,
+    kp: Keypair,
+    amount: u64,
+    sol: u64,
+) -> Participant {
+    if sol > 0 {
+        airdrop(ctx, &kp.pubkey(), sol).await;
+    }
+    let lock_ata = create_token_account(ctx, &iss.lock_mint, &kp.pubkey()).await;
+    let reward_ata = if iss.reward_mint == iss.lock_mint {
+        lock_ata
+    } else {
+        create_token_account(ctx, &iss.reward_mint, &kp.pubkey()).await
+    };
+    if amount > 0 {
+        mint_to(ctx, &iss.lock_mint, &lock_ata, amount).await;
+    }
+    let user = user_pda(iss, &kp.pubkey());
+    Participant { kp, lock_ata, reward_ata, user }
+}
+
+pub fn user_pda(iss: &Issuance, participant: &Pubkey) -> Pubkey {
+    pda::derive_user_pda(&lockrion_issuance_v1_1::id(), &iss.pda, participant).0
+}
+
+pub fn deposit_metas(iss: &Issuance, p: &Participant) -> Vec<AccountMeta> {
+    vec![
+        AccountMeta::new(iss.pda, false),
+        AccountMeta::new(p.user, false),
+        AccountMeta::new(p.pubkey(), true),
+        AccountMeta::new(p.lock_ata, false),
+        AccountMeta::new(iss.deposit_escrow, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ]
+}
+
+pub fn deposit_ix(iss: &Issuance, p: &Participant, amount: u64) -> Instruction {
+    ix(LockrionInstruction::Deposit { amount }, deposit_metas(iss, p))
+}
+
+pub async fn deposit(ctx: &mut ProgramTestContext, iss: &Issuance, p: &Participant, amount: u64) {
+    send(ctx, &[deposit_ix(iss, p, amount)], &[&p.kp]).await.unwrap();
+}
+
+pub fn claim_ix(iss: &Issuance, p: &Participant) -> Instruction {
+    ix(
+        LockrionInstruction::ClaimReward,
+        vec![
+            AccountMeta::new(iss.pda, false),
+            AccountMeta::new(p.user, false),
+            AccountMeta::new(p.pubkey(), true),
+            AccountMeta::new(p.reward_ata, false),
+            AccountMeta::new(iss.reward_escrow, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+    )
+}
+
+pub async fn claim(ctx: &mut ProgramTestContext, iss: &Issuance, p: &Participant) -> Result<(), BanksClientError> {
+    send(ctx, &[claim_ix(iss, p)], &[&p.kp]).await
+}
+
+pub fn withdraw_metas(iss: &Issuance, p: &Participant) -> Vec<AccountMeta> {
+    vec![
+        AccountMeta::new(iss.pda, false),
+        AccountMeta::new(p.user, false),
+        AccountMeta::new(p.pubkey(), true),
+        AccountMeta::new(p.lock_ata, false),
+        AccountMeta::new(iss.deposit_escrow, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+    ]
+}
+
+pub fn withdraw_ix(iss: &Issuance, p: &Participant) -> Instruction {
+    ix(LockrionInstruction::WithdrawDeposit, withdraw_metas(iss, p))
+}
+
+pub async fn withdraw(ctx: &mut ProgramTestContext, iss: &Issuance, p: &Participant) -> Result<(), BanksClientError> {
+    send(ctx, &[withdraw_ix(iss, p)], &[&p.kp]).await
+}
+
+pub fn settle_ix(iss: &Issuance, p: &Participant) -> Instruction {
+    ix(
+        LockrionInstruction::Settle,
+        vec![
+            AccountMeta::new(iss.pda, false),
+            AccountMeta::new(p.user, false),
+            AccountMeta::new(p.pubkey(), true),
+            AccountMeta::new(p.lock_ata, false),
+            AccountMeta::new(p.reward_ata, false),
+            AccountMeta::new(iss.deposit_escrow, false),
+            AccountMeta::new(iss.reward_escrow, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+    )
+}
+
+pub async fn settle(ctx: &mut ProgramTestContext, iss: &Issuance, p: &Participant) -> Result<(), BanksClientError> {
+    send(ctx, &[settle_ix(iss, p)], &[&p.kp]).await
+}
+
+pub fn sweep_ix(iss: &Issuance) -> Instruction {
+    let mut metas = vec![
+        AccountMeta::new(iss.pda, false),
+        AccountMeta::new(iss.reward_escrow, false),
+        AccountMeta::new(iss.platform_treasury, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+    ];
+    if iss.lock_mint == iss.reward_mint {
+        metas.push(AccountMeta::new_readonly(iss.deposit_escrow, false));
+    }
+    ix(LockrionInstruction::Sweep, metas)
+}
+
+pub fn reclaim_ix(iss: &Issuance) -> Instruction {
+    let mut metas = vec![
+        AccountMeta::new(iss.pda, false),
+        AccountMeta::new(iss.issuer, true),
+        AccountMeta::new(iss.issuer_reward, false),
+        AccountMeta::new(iss.reward_escrow, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+    ];
+    if iss.lock_mint == iss.reward_mint {
+        metas.push(AccountMeta::new_readonly(iss.deposit_escrow, false));
+    }
+    ix(LockrionInstruction::ZeroParticipationReclaim, metas)
+}
+
+pub fn transfer_position_ix(iss: &Issuance, from: &Participant, to: &Pubkey) -> Instruction {
+    ix(
+        LockrionInstruction::TransferPosition,
+        vec![
+            AccountMeta::new(iss.pda, false),
+            AccountMeta::new(from.user, false),
+            AccountMeta::new(from.pubkey(), true),
+            AccountMeta::new_readonly(*to, false),
+            AccountMeta::new(user_pda(iss, to), false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    )
+}
@@ -28,7 +28,9 @@ const [issuancePda] = PublicKey.findProgramAddressSync([seed1,seed2,seed3,seed4]
 // ТУТ НУЖНО СООТВЕТСТВИЕ ТВОЕМУ enum/discriminant!
 // Если у тебя Instruction::InitIssuance { reserve_total, start_ts, maturity_ts }
 // и первый байт = 0, тогда так:
-const data = Buffer.alloc(1+16+8+8);
+// IssuanceParams (Borsh) — default params serialize to all-zero bytes
+const PARAMS_LEN = 1 + 8 + 4 + 8 + 1 + 1 + 32 + 1 + 32 + 32 + 4; // receipt_mint: bool, vesting_duration: i64, multiplier_schedule: Vec (len 0), accounting_period: i64, prorate_final_period: bool, continuous: bool, issuer: Pubkey (zero = payer), metadata: Option (None), attestation_program + attestation_schema: Pubkey (zero = no gate), lock_tiers: Vec (len 0)
+const data = Buffer.alloc(1+16+8+8+PARAMS_LEN);
 data.writeUInt8(0,0); // DISCRIMINANT INIT = 0 (ПРОВЕРЬ!)
 data.writeBigUInt64LE(reserveTotal,1);
 data.writeBigUInt64LE(0n,1+8);
@@ -46,8 +48,18 @@ const keys = [
   {pubkey: new PublicKey("11111111111111111111111111111111"), isSigner: false, isWritable: false},
 ];
 
+// Platform registry: head [registry, payer] + current page [registry_page, payer, u32 page_index]
+const RECORDS_PER_PAGE = 64n;
+const [registryHead] = PublicKey.findProgramAddressSync([Buffer.from("registry"), payer.publicKey.toBuffer()], programId);
+
 (async()=>{
   const c=new Connection(RPC,"confirmed");
+  const head=await c.getAccountInfo(registryHead);
+  const count=head ? head.data.readBigUInt64LE(34) : 0n; // RegistryHead.count at 34..42
+  const pageIndex=Buffer.alloc(4); pageIndex.writeUInt32LE(Number(count / RECORDS_PER_PAGE));
+  const [registryPage] = PublicKey.findProgramAddressSync([Buffer.from("registry_page"), payer.publicKey.toBuffer(), pageIndex], programId);
+  keys.push({pubkey: registryHead, isSigner: false, isWritable: true});
+  keys.push({pubkey: registryPage, isSigner: false, isWritable: true});
   const ix=new TransactionInstruction({programId, keys, data});
   const tx=new Transaction().add(ix);
   const sig=await sendAndConfirmTransaction(c, tx, [payer]);
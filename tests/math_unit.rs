@@ -0,0 +1,77 @@
+use lockrion_issuance_v1_1::error::LockrionError;
+use lockrion_issuance_v1_1::math::*;
+
+// =====================================================
+// WIDE MULTIPLY
+// =====================================================
+
+#[test]
+fn ut_mul_wide_small() {
+    assert_eq!(mul_wide(6, 7), (0, 42));
+}
+
+#[test]
+fn ut_mul_wide_carry_into_hi() {
+    // 2^64 * 2^64 = 2^128
+    let x = 1u128 << 64;
+    assert_eq!(mul_wide(x, x), (1, 0));
+}
+
+#[test]
+fn ut_mul_wide_max() {
+    // (2^128 - 1)^2 = 2^256 - 2^129 + 1
+    assert_eq!(mul_wide(u128::MAX, u128::MAX), (u128::MAX - 1, 1));
+}
+
+// =====================================================
+// MUL-DIV FLOOR
+// =====================================================
+
+#[test]
+fn ut_mul_div_matches_narrow_path() {
+    for (a, b, d) in [(1000u128, 25u128, 100u128), (1000, 1, 3), (7, 0, 5), (u64::MAX as u128, 3, 7)] {
+        assert_eq!(mul_div_floor(a, b, d).unwrap(), a * b / d);
+    }
+}
+
+#[test]
+fn ut_mul_div_wide_exact() {
+    // (2^100 * 2^100) / 2^90 = 2^110
+    let r = mul_div_floor(1u128 << 100, 1u128 << 100, 1u128 << 90).unwrap();
+    assert_eq!(r, 1u128 << 110);
+}
+
+#[test]
+fn ut_mul_div_wide_floor() {
+    // a * b / (b + 1) < a, floored
+    let a = (1u128 << 70) + 12_345;
+    let b = (1u128 << 90) + 1;
+    let r = mul_div_floor(a, b, b + 1).unwrap();
+    assert_eq!(r, a - 1);
+}
+
+#[test]
+fn ut_mul_div_share_of_total() {
+    // reward = reserve * w / total, w <= total  =>  result <= reserve
+    let reserve = u64::MAX as u128;
+    let total = u128::MAX / 3;
+    assert_eq!(mul_div_floor(reserve, total, total).unwrap(), reserve);
+    assert_eq!(mul_div_floor(reserve, 0, total).unwrap(), 0);
+}
+
+#[test]
+fn ut_mul_div_division_by_zero() {
+    assert!(matches!(mul_div_floor(1, 1, 0), Err(LockrionError::DivisionByZero)));
+}
+
+#[test]
+fn ut_mul_div_quotient_overflow() {
+    assert!(matches!(
+        mul_div_floor(u128::MAX, u128::MAX, 1),
+        Err(LockrionError::ArithmeticOverflow)
+    ));
+    assert!(matches!(
+        mul_div_floor(u128::MAX, 2, 1),
+        Err(LockrionError::ArithmeticOverflow)
+    ));
+}
@@ -0,0 +1,92 @@
+use lockrion_issuance_v1_1::error::LockrionError;
+use lockrion_issuance_v1_1::metadata::*;
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+fn args() -> MetadataArgs {
+    MetadataArgs {
+        name: "Lockrion Q3".to_string(),
+        symbol: "LKQ3".to_string(),
+        uri: "https://example.org/q3.json".to_string(),
+        terms_hash: [0xab; 32],
+    }
+}
+
+fn is_err(r: Result<impl core::fmt::Debug, ProgramError>, e: LockrionError) -> bool {
+    matches!(r, Err(ProgramError::Custom(c)) if c == e as u32)
+}
+
+fn padded(s: &str, cap: usize) -> Vec<u8> {
+    let mut v = vec![s.len() as u8];
+    v.extend_from_slice(s.as_bytes());
+    v.resize(1 + cap, 0);
+    v
+}
+
+#[test]
+fn ut_metadata_golden() {
+    let md = IssuanceMetadata::new(Pubkey::new_from_array([7; 32]), 251, &args());
+    let g = [
+        &[1u8, 251][..],
+        &[7; 32],
+        &[0xab; 32],
+        &padded("Lockrion Q3", MAX_NAME_LEN),
+        &padded("LKQ3", MAX_SYMBOL_LEN),
+        &padded("https://example.org/q3.json", MAX_URI_LEN),
+        &[0],
+    ]
+    .concat();
+    assert_eq!(g.len(), METADATA_SIZE);
+
+    let mut out = vec![0xffu8; METADATA_SIZE];
+    md.pack(&mut out).unwrap();
+    assert_eq!(out, g);
+    assert_eq!(IssuanceMetadata::unpack(&g).unwrap(), md);
+}
+
+#[test]
+fn ut_metadata_caps() {
+    args().validate().unwrap();
+
+    let mut full = args();
+    full.name = "n".repeat(MAX_NAME_LEN);
+    full.symbol = "s".repeat(MAX_SYMBOL_LEN);
+    full.uri = "u".repeat(MAX_URI_LEN);
+    full.validate().unwrap();
+    let mut out = vec![0u8; METADATA_SIZE];
+    IssuanceMetadata::new(Pubkey::default(), 1, &full).pack(&mut out).unwrap();
+    assert_eq!(IssuanceMetadata::unpack(&out).unwrap().uri, full.uri);
+
+    for over in [
+        MetadataArgs { name: "n".repeat(MAX_NAME_LEN + 1), ..args() },
+        MetadataArgs { symbol: "s".repeat(MAX_SYMBOL_LEN + 1), ..args() },
+        MetadataArgs { uri: "u".repeat(MAX_URI_LEN + 1), ..args() },
+    ] {
+        assert!(is_err(over.validate(), LockrionError::InvalidIssuanceParams));
+    }
+
+    // all-zero terms hash is the "none acknowledged" marker
+    let zero = MetadataArgs { terms_hash: [0u8; 32], ..args() };
+    assert!(is_err(zero.validate(), LockrionError::InvalidIssuanceParams));
+}
+
+#[test]
+fn ut_metadata_rejects_corrupt_data() {
+    let mut out = vec![0u8; METADATA_SIZE];
+    IssuanceMetadata::new(Pubkey::default(), 1, &args()).pack(&mut out).unwrap();
+
+    assert!(is_err(IssuanceMetadata::unpack(&out[..METADATA_SIZE - 1]), LockrionError::InvalidAccountSize));
+
+    let mut bad = out.clone();
+    bad[0] = 0;
+    assert!(is_err(IssuanceMetadata::unpack(&bad), LockrionError::InvalidStateVersion));
+
+    // name length byte beyond its cap
+    let mut bad = out.clone();
+    bad[66] = MAX_NAME_LEN as u8 + 1;
+    assert!(is_err(IssuanceMetadata::unpack(&bad), LockrionError::InvalidAccountSize));
+
+    // non-UTF-8 symbol bytes
+    let mut bad = out;
+    bad[100] = 0xff;
+    assert!(is_err(IssuanceMetadata::unpack(&bad), LockrionError::InvalidIssuanceParams));
+}
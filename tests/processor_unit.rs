@@ -3,7 +3,8 @@
 use lockrion_issuance_v1_1::{
     accumulator,
     error::LockrionError,
-    state::{IssuanceState, UserState},
+    math,
+    state::{IssuanceState, MultiplierTier, UserState},
 };
 
 // -----------------------------
@@ -34,7 +35,24 @@ fn mock_issuance() -> IssuanceState {
         reserve_funded: 1,
         sweep_executed: 0,
         reclaim_executed: 0,
-        reserved_padding: [0u8; 7],
+        receipt_mode: 0,
+        receipt_mint_bump: 0,
+        terms_required: 0,
+        reserved_padding: [0u8; 4],
+        vesting_duration: 0,
+        total_weighted_locked: 0,
+        multiplier_schedule: Default::default(),
+        accounting_period: 86_400,
+        prorated_tail: 0,
+        total_claimed: 0,
+        total_weight_claimed: 0,
+        participant_count: 0,
+        claimed_count: 0,
+        withdrawn_count: 0,
+        total_withdrawn: 0,
+        attestation_program: Default::default(),
+        attestation_schema: Default::default(),
+        lock_tiers: Default::default(),
     }
 }
 
@@ -50,7 +68,14 @@ fn mock_user() -> UserState {
         user_last_day_index: 0,
 
         reward_claimed: 0,
-        reserved_padding: [0u8; 5],
+        lock_tier: 0,
+        reserved_padding: [0u8; 4],
+        reward_total: 0,
+        reward_withdrawn: 0,
+        weighted_locked: 0,
+        rent_payer: Default::default(),
+        terms_hash: [0u8; 32],
+        terms_ack_ts: 0,
     }
 }
 
@@ -58,17 +83,20 @@ fn mock_user() -> UserState {
 // Pure helpers (mirror contract logic)
 // -----------------------------
 fn finalize_global_pure(issuance: &mut IssuanceState, now: i64) -> Result<u64, LockrionError> {
-    let raw = accumulator::raw_day_index(now, issuance.start_ts)?;
-    let current = accumulator::bounded_day_index(raw, issuance.final_day_index);
+    let current = accumulator::current_day_index(
+        now,
+        issuance.start_ts,
+        issuance.maturity_ts,
+        issuance.accounting_period,
+        issuance.final_day_index,
+    )?;
 
     if current > issuance.last_day_index {
-        let days_elapsed = current
-            .checked_sub(issuance.last_day_index)
-            .ok_or(LockrionError::ArithmeticUnderflow)?;
+        let units = period_units_pure(issuance, issuance.last_day_index, current)?;
 
         let inc = issuance
-            .total_locked
-            .checked_mul(days_elapsed as u128)
+            .total_weighted_locked
+            .checked_mul(units)
             .ok_or(LockrionError::ArithmeticOverflow)?;
 
         issuance.total_weight_accum = issuance
@@ -86,15 +114,17 @@ fn finalize_global_pure(issuance: &mut IssuanceState, now: i64) -> Result<u64, L
     Ok(current)
 }
 
-fn update_user_with_current_pure(user: &mut UserState, current: u64) -> Result<(), LockrionError> {
+fn update_user_with_current_pure(
+    issuance: &IssuanceState,
+    user: &mut UserState,
+    current: u64,
+) -> Result<(), LockrionError> {
     if current > user.user_last_day_index {
-        let days_elapsed_user = current
-            .checked_sub(user.user_last_day_index)
-            .ok_or(LockrionError::ArithmeticUnderflow)?;
+        let units = period_units_pure(issuance, user.user_last_day_index, current)?;
 
         let inc = user
-            .locked_amount
-            .checked_mul(days_elapsed_user as u128)
+            .weighted_locked
+            .checked_mul(units)
             .ok_or(LockrionError::ArithmeticOverflow)?;
 
         user.user_weight_accum = user
@@ -107,16 +137,44 @@ fn update_user_with_current_pure(user: &mut UserState, current: u64) -> Result<(
     Ok(())
 }
 
+fn period_units_pure(issuance: &IssuanceState, from: u64, to: u64) -> Result<u128, LockrionError> {
+    accumulator::period_units(
+        from,
+        to,
+        issuance.final_day_index,
+        issuance.accounting_period,
+        issuance.prorated_tail,
+    )
+}
+
 fn reward_calc(reserve_total: u128, user_weight: u128, total_weight: u128) -> Result<u128, LockrionError> {
-    if total_weight == 0 {
-        return Err(LockrionError::DivisionByZero);
+    math::mul_div_floor(reserve_total, user_weight, total_weight)
+}
+
+fn fix_entitlement_pure(issuance: &mut IssuanceState, user_weight: u128) -> Result<u128, LockrionError> {
+    let remaining_weight = issuance
+        .total_weight_accum
+        .checked_sub(issuance.total_weight_claimed)
+        .ok_or(LockrionError::InvariantViolation)?;
+    if user_weight > remaining_weight {
+        return Err(LockrionError::InvariantViolation);
     }
 
-    let num = reserve_total
-        .checked_mul(user_weight)
+    let reward = if user_weight == remaining_weight {
+        issuance
+            .reserve_total
+            .checked_sub(issuance.total_claimed)
+            .ok_or(LockrionError::InvariantViolation)?
+    } else {
+        reward_calc(issuance.reserve_total, user_weight, issuance.total_weight_accum)?
+    };
+
+    issuance.total_claimed = issuance.total_claimed.checked_add(reward).ok_or(LockrionError::ArithmeticOverflow)?;
+    issuance.total_weight_claimed = issuance
+        .total_weight_claimed
+        .checked_add(user_weight)
         .ok_or(LockrionError::ArithmeticOverflow)?;
-
-    Ok(num / total_weight)
+    Ok(reward)
 }
 
 // ==============================
@@ -126,7 +184,7 @@ fn reward_calc(reserve_total: u128, user_weight: u128, total_weight: u128) -> Re
 #[test]
 fn ut_gacc_01_basic_accumulation() {
     let mut iss = mock_issuance();
-    iss.total_locked = 100;
+    iss.total_weighted_locked = 100;
 
     finalize_global_pure(&mut iss, 86400 * 3).unwrap();
 
@@ -137,7 +195,7 @@ fn ut_gacc_01_basic_accumulation() {
 #[test]
 fn ut_gacc_02_zero_days_elapsed() {
     let mut iss = mock_issuance();
-    iss.total_locked = 100;
+    iss.total_weighted_locked = 100;
 
     finalize_global_pure(&mut iss, 0).unwrap();
 
@@ -148,7 +206,7 @@ fn ut_gacc_02_zero_days_elapsed() {
 #[test]
 fn ut_gacc_03_bounded_to_final_day() {
     let mut iss = mock_issuance();
-    iss.total_locked = 10;
+    iss.total_weighted_locked = 10;
     iss.final_day_index = 5;
 
     finalize_global_pure(&mut iss, 86400 * 100).unwrap();
@@ -160,7 +218,7 @@ fn ut_gacc_03_bounded_to_final_day() {
 #[test]
 fn ut_gacc_04_overflow_guard() {
     let mut iss = mock_issuance();
-    iss.total_locked = u128::MAX;
+    iss.total_weighted_locked = u128::MAX;
 
     // make days_elapsed >= 2 so mul overflows: MAX * 2
     let r = finalize_global_pure(&mut iss, 86400 * 2);
@@ -168,6 +226,19 @@ fn ut_gacc_04_overflow_guard() {
     assert!(matches!(r, Err(LockrionError::ArithmeticOverflow)));
 }
 
+#[test]
+fn ut_gacc_05_hourly_period() {
+    let mut iss = mock_issuance();
+    iss.accounting_period = 3_600;
+    iss.final_day_index = 240;
+    iss.total_weighted_locked = 100;
+
+    finalize_global_pure(&mut iss, 3_600 * 5 + 59).unwrap();
+
+    assert_eq!(iss.last_day_index, 5);
+    assert_eq!(iss.total_weight_accum, 500);
+}
+
 // ==============================
 // UT-UACC-01..04 (Per-user accumulator)
 // ==============================
@@ -175,10 +246,10 @@ fn ut_gacc_04_overflow_guard() {
 #[test]
 fn ut_uacc_01_basic_accumulation() {
     let mut user = mock_user();
-    user.locked_amount = 50;
+    user.weighted_locked = 50;
     user.user_last_day_index = 1;
 
-    update_user_with_current_pure(&mut user, 4).unwrap();
+    update_user_with_current_pure(&mock_issuance(), &mut user, 4).unwrap();
 
     assert_eq!(user.user_weight_accum, 150);
     assert_eq!(user.user_last_day_index, 4);
@@ -187,11 +258,11 @@ fn ut_uacc_01_basic_accumulation() {
 #[test]
 fn ut_uacc_02_zero_days_elapsed() {
     let mut user = mock_user();
-    user.locked_amount = 50;
+    user.weighted_locked = 50;
     user.user_last_day_index = 4;
     user.user_weight_accum = 777;
 
-    update_user_with_current_pure(&mut user, 4).unwrap();
+    update_user_with_current_pure(&mock_issuance(), &mut user, 4).unwrap();
 
     assert_eq!(user.user_weight_accum, 777);
     assert_eq!(user.user_last_day_index, 4);
@@ -200,11 +271,11 @@ fn ut_uacc_02_zero_days_elapsed() {
 #[test]
 fn ut_uacc_03_already_final_day_no_change() {
     let mut user = mock_user();
-    user.locked_amount = 123;
+    user.weighted_locked = 123;
     user.user_last_day_index = 5;
     user.user_weight_accum = 9;
 
-    update_user_with_current_pure(&mut user, 5).unwrap();
+    update_user_with_current_pure(&mock_issuance(), &mut user, 5).unwrap();
 
     assert_eq!(user.user_weight_accum, 9);
     assert_eq!(user.user_last_day_index, 5);
@@ -213,14 +284,136 @@ fn ut_uacc_03_already_final_day_no_change() {
 #[test]
 fn ut_uacc_04_overflow_guard() {
     let mut user = mock_user();
-    user.locked_amount = u128::MAX;
+    user.weighted_locked = u128::MAX;
     user.user_last_day_index = 0;
 
-    let r = update_user_with_current_pure(&mut user, 2);
+    let r = update_user_with_current_pure(&mock_issuance(), &mut user, 2);
 
     assert!(matches!(r, Err(LockrionError::ArithmeticOverflow)));
 }
 
+// ==============================
+// UT-MULT-01..02 (Early-bird multipliers keep sum(user) == total)
+// ==============================
+
+fn deposit_pure(
+    issuance: &mut IssuanceState,
+    user: &mut UserState,
+    now: i64,
+    amount: u128,
+) -> Result<(), LockrionError> {
+    let current = finalize_global_pure(issuance, now)?;
+    update_user_with_current_pure(issuance, user, current)?;
+
+    let mult = accumulator::multiplier_bps(&issuance.multiplier_schedule, current);
+    let weighted = accumulator::weighted_amount(amount, mult)?;
+
+    issuance.total_locked = issuance.total_locked.checked_add(amount).ok_or(LockrionError::ArithmeticOverflow)?;
+    issuance.total_weighted_locked = issuance
+        .total_weighted_locked
+        .checked_add(weighted)
+        .ok_or(LockrionError::ArithmeticOverflow)?;
+    user.locked_amount = user.locked_amount.checked_add(amount).ok_or(LockrionError::ArithmeticOverflow)?;
+    user.weighted_locked = user.weighted_locked.checked_add(weighted).ok_or(LockrionError::ArithmeticOverflow)?;
+    Ok(())
+}
+
+fn early_bird_issuance() -> IssuanceState {
+    let mut iss = mock_issuance();
+    iss.multiplier_schedule[0] = MultiplierTier { end_day: 3, mult_bps: 15_000 };
+    iss.multiplier_schedule[1] = MultiplierTier { end_day: 6, mult_bps: 11_111 };
+    iss
+}
+
+#[test]
+fn ut_mult_01_sum_of_users_equals_total() {
+    let mut iss = early_bird_issuance();
+    let mut users = [mock_user(), mock_user(), mock_user(), mock_user()];
+
+    // odd amounts on different days exercise floor rounding in every tier
+    let plan: [(usize, i64, u128); 7] = [
+        (0, 0, 7),
+        (1, 86400 + 5, 13),
+        (0, 86400 * 2, 1),
+        (2, 86400 * 4, 999),
+        (3, 86400 * 5 + 1, 3),
+        (1, 86400 * 6, 10),
+        (3, 86400 * 9, 77),
+    ];
+    for (who, now, amount) in plan {
+        deposit_pure(&mut iss, &mut users[who], now, amount).unwrap();
+    }
+
+    let end = iss.maturity_ts;
+    let current = finalize_global_pure(&mut iss, end).unwrap();
+    for u in users.iter_mut() {
+        update_user_with_current_pure(&iss, u, current).unwrap();
+    }
+
+    let sum_weight: u128 = users.iter().map(|u| u.user_weight_accum).sum();
+    let sum_weighted: u128 = users.iter().map(|u| u.weighted_locked).sum();
+    let sum_locked: u128 = users.iter().map(|u| u.locked_amount).sum();
+    assert_eq!(sum_weight, iss.total_weight_accum);
+    assert_eq!(sum_weighted, iss.total_weighted_locked);
+    assert_eq!(sum_locked, iss.total_locked);
+}
+
+#[test]
+fn ut_mult_02_early_deposit_outweighs_late() {
+    let mut iss = early_bird_issuance();
+    let mut early = mock_user();
+    let mut late = mock_user();
+
+    deposit_pure(&mut iss, &mut early, 0, 100).unwrap();
+    deposit_pure(&mut iss, &mut late, 86400 * 7, 100).unwrap();
+
+    let end = iss.maturity_ts;
+    let current = finalize_global_pure(&mut iss, end).unwrap();
+    update_user_with_current_pure(&iss, &mut early, current).unwrap();
+    update_user_with_current_pure(&iss, &mut late, current).unwrap();
+
+    assert_eq!(early.weighted_locked, 150);
+    assert_eq!(late.weighted_locked, 100);
+    assert_eq!(early.user_weight_accum, 150 * 10);
+    assert_eq!(late.user_weight_accum, 100 * 3);
+    assert_eq!(iss.total_weight_accum, 1500 + 300);
+}
+
+// ==============================
+// UT-TAIL-01 (Pro-rated final period keeps sum(user) == total)
+// ==============================
+
+#[test]
+fn ut_tail_01_tail_deposit_earns_prorated_weight() {
+    let mut iss = mock_issuance();
+    iss.maturity_ts = 86400 * 10 + 21_600; // quarter-day tail
+    let (fin, tail) =
+        accumulator::prorated_final_day_index(iss.start_ts, iss.maturity_ts, iss.accounting_period).unwrap();
+    iss.final_day_index = fin;
+    iss.prorated_tail = tail;
+
+    let mut early = mock_user();
+    let mut tail_user = mock_user();
+    deposit_pure(&mut iss, &mut early, 0, 100).unwrap();
+    deposit_pure(&mut iss, &mut tail_user, 86400 * 10 + 1, 100).unwrap();
+
+    let end = iss.maturity_ts;
+    let current = finalize_global_pure(&mut iss, end).unwrap();
+    update_user_with_current_pure(&iss, &mut early, current).unwrap();
+    update_user_with_current_pure(&iss, &mut tail_user, current).unwrap();
+
+    assert_eq!(current, 11);
+    assert_eq!(early.user_weight_accum, 100 * (86400 * 10 + 21_600));
+    assert_eq!(tail_user.user_weight_accum, 100 * 21_600);
+    assert_eq!(iss.total_weight_accum, early.user_weight_accum + tail_user.user_weight_accum);
+
+    // 10.25 days vs 0.25 day of the same principal
+    let r_early = reward_calc(iss.reserve_total, early.user_weight_accum, iss.total_weight_accum).unwrap();
+    let r_tail = reward_calc(iss.reserve_total, tail_user.user_weight_accum, iss.total_weight_accum).unwrap();
+    assert_eq!(r_early, 976);
+    assert_eq!(r_tail, 23);
+}
+
 // ==============================
 // UT-BOUND-01..02 (Bounded accumulation)
 // ==============================
@@ -245,17 +438,17 @@ fn ut_bound_02_clamp_idempotent() {
 fn ut_same_01_same_timestamp_same_day_index() {
     let start = 1_000_000;
     let block = start + 123;
-    let a = accumulator::raw_day_index(block, start).unwrap();
-    let b = accumulator::raw_day_index(block, start).unwrap();
+    let a = accumulator::raw_day_index(block, start, accumulator::DEFAULT_ACCOUNTING_PERIOD).unwrap();
+    let b = accumulator::raw_day_index(block, start, accumulator::DEFAULT_ACCOUNTING_PERIOD).unwrap();
     assert_eq!(a, b);
 }
 
 #[test]
 fn ut_same_02_two_calls_deterministic() {
     let start = 0;
-    let block = accumulator::ACCOUNTING_PERIOD * 7 + 9;
-    let a = accumulator::raw_day_index(block, start).unwrap();
-    let b = accumulator::raw_day_index(block, start).unwrap();
+    let block = accumulator::DEFAULT_ACCOUNTING_PERIOD * 7 + 9;
+    let a = accumulator::raw_day_index(block, start, accumulator::DEFAULT_ACCOUNTING_PERIOD).unwrap();
+    let b = accumulator::raw_day_index(block, start, accumulator::DEFAULT_ACCOUNTING_PERIOD).unwrap();
     assert_eq!(a, b);
 }
 
@@ -287,6 +480,47 @@ fn ut_reward_04_overflow_guard() {
     assert!(matches!(r, Err(LockrionError::ArithmeticOverflow)));
 }
 
+#[test]
+fn ut_reward_05_continuous_scale_no_overflow() {
+    // 1e9 tokens at 9 decimals locked for a year in second units: weight ~ 2^85,
+    // reserve ~ 2^60 -> product ~ 2^145 needs the wide intermediate
+    let locked: u128 = 1_000_000_000 * 1_000_000_000;
+    let secs: u128 = 365 * 86_400;
+    let reserve: u128 = 1_000_000_000 * 1_000_000_000;
+    let user_weight = locked * secs;
+    let total_weight = user_weight * 4;
+
+    assert!(reserve.checked_mul(user_weight).is_none());
+    assert_eq!(reward_calc(reserve, user_weight, total_weight).unwrap(), reserve / 4);
+}
+
+#[test]
+fn ut_reward_06_last_claimant_takes_remainder() {
+    let mut issuance = mock_issuance();
+    issuance.reserve_total = 1000;
+    issuance.total_weight_accum = 3;
+
+    let a = fix_entitlement_pure(&mut issuance, 1).unwrap();
+    let b = fix_entitlement_pure(&mut issuance, 1).unwrap();
+    let c = fix_entitlement_pure(&mut issuance, 1).unwrap();
+
+    assert_eq!((a, b, c), (333, 333, 334));
+    assert_eq!(a + b + c, 1000);
+    assert_eq!(issuance.total_claimed, issuance.reserve_total);
+    assert_eq!(issuance.total_weight_claimed, issuance.total_weight_accum);
+}
+
+#[test]
+fn ut_reward_07_weight_beyond_remaining_rejected() {
+    let mut issuance = mock_issuance();
+    issuance.reserve_total = 1000;
+    issuance.total_weight_accum = 3;
+    fix_entitlement_pure(&mut issuance, 2).unwrap();
+
+    let r = fix_entitlement_pure(&mut issuance, 2);
+    assert!(matches!(r, Err(LockrionError::InvariantViolation)));
+}
+
 // ==============================
 // UT-ARITH-01..03 (Arithmetic safety)
 // ==============================
@@ -303,6 +537,6 @@ fn ut_arith_02_sub_underflow() {
 
 #[test]
 fn ut_arith_03_negative_ts_returns_zero_day() {
-    let r = accumulator::raw_day_index(-10, 0).unwrap();
+    let r = accumulator::raw_day_index(-10, 0, accumulator::DEFAULT_ACCOUNTING_PERIOD).unwrap();
     assert_eq!(r, 0);
 }
\ No newline at end of file
@@ -0,0 +1,114 @@
+use lockrion_issuance_v1_1::error::LockrionError;
+use lockrion_issuance_v1_1::pda;
+use lockrion_issuance_v1_1::registry::*;
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+fn key(b: u8) -> Pubkey {
+    Pubkey::new_from_array([b; 32])
+}
+
+fn record(n: u8) -> RegistryRecord {
+    RegistryRecord {
+        issuance: key(n),
+        lock_mint: key(2),
+        reward_mint: key(3),
+        start_ts: 1_700_000_000 + n as i64,
+        maturity_ts: 1_700_864_000,
+    }
+}
+
+fn is_err(r: Result<impl core::fmt::Debug, ProgramError>, e: LockrionError) -> bool {
+    matches!(r, Err(ProgramError::Custom(c)) if c == e as u32)
+}
+
+// =====================================================
+// LAYOUT
+// =====================================================
+
+#[test]
+fn ut_registry_head_golden() {
+    let head = RegistryHead {
+        version: REGISTRY_VERSION,
+        bump: 250,
+        scope: key(1),
+        count: 65,
+        reserved_padding: [0u8; 6],
+    };
+    let g = [&[1u8, 250][..], &[1; 32], &[65, 0, 0, 0, 0, 0, 0, 0], &[0; 6]].concat();
+    assert_eq!(g.len(), REGISTRY_HEAD_SIZE);
+
+    let mut out = vec![0u8; REGISTRY_HEAD_SIZE];
+    head.pack(&mut out).unwrap();
+    assert_eq!(out, g);
+    assert_eq!(RegistryHead::unpack(&g).unwrap(), head);
+}
+
+#[test]
+fn ut_registry_record_golden() {
+    let g = [
+        &[7u8; 32][..],
+        &[2; 32],
+        &[3; 32],
+        &[0x07, 0xf1, 0x53, 0x65, 0, 0, 0, 0],
+        &[0x00, 0x20, 0x61, 0x65, 0, 0, 0, 0],
+    ]
+    .concat();
+    assert_eq!(g.len(), REGISTRY_RECORD_SIZE);
+
+    let mut out = vec![0u8; REGISTRY_RECORD_SIZE];
+    record(7).pack(&mut out).unwrap();
+    assert_eq!(out, g);
+    assert_eq!(RegistryRecord::unpack(&g).unwrap(), record(7));
+}
+
+// =====================================================
+// PAGINATION + CLIENT HELPERS
+// =====================================================
+
+#[test]
+fn ut_registry_page_math() {
+    assert_eq!(page_index(0).unwrap(), 0);
+    assert_eq!(page_index(RECORDS_PER_PAGE - 1).unwrap(), 0);
+    assert_eq!(page_index(RECORDS_PER_PAGE).unwrap(), 1);
+    assert!(is_err(page_index(u64::MAX), LockrionError::ArithmeticOverflow));
+
+    assert_eq!(page_size(0), REGISTRY_PAGE_HEADER_SIZE);
+    assert_eq!(page_size(RECORDS_PER_PAGE), 8 + 64 * 112);
+}
+
+#[test]
+fn ut_registry_page_records_in_order() {
+    let mut page = vec![0u8; page_size(3)];
+    pack_page_header(&mut page, 249, 4).unwrap();
+    assert_eq!(&page[..8], &[1, 249, 0, 0, 4, 0, 0, 0]);
+    for n in 0..3u8 {
+        let off = page_size(n as u64);
+        record(n).pack(&mut page[off..off + REGISTRY_RECORD_SIZE]).unwrap();
+    }
+
+    assert_eq!(page_records(&page).unwrap(), vec![record(0), record(1), record(2)]);
+    assert_eq!(page_records(&page[..page_size(1)]).unwrap().len(), 1);
+
+    // Truncated record / wrong version
+    assert!(is_err(page_records(&page[..page_size(1) + 1]), LockrionError::InvalidAccountSize));
+    page[0] = 0;
+    assert!(is_err(page_records(&page), LockrionError::InvalidStateVersion));
+}
+
+#[test]
+fn ut_registry_page_addresses() {
+    let program_id = Pubkey::new_unique();
+    let scope = key(1);
+
+    assert!(page_addresses(&program_id, &scope, 0).is_empty());
+    assert_eq!(page_addresses(&program_id, &scope, RECORDS_PER_PAGE).len(), 1);
+
+    let pages = page_addresses(&program_id, &scope, RECORDS_PER_PAGE + 1);
+    assert_eq!(pages.len(), 2);
+    assert_eq!(pages[1], pda::derive_registry_page_pda(&program_id, &scope, 1).0);
+
+    // Head, pages and other scopes never share an address
+    let head = pda::derive_registry_pda(&program_id, &scope).0;
+    assert!(!pages.contains(&head));
+    assert_ne!(pages[0], page_addresses(&program_id, &key(2), 1)[0]);
+}
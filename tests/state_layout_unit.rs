@@ -0,0 +1,283 @@
+use lockrion_issuance_v1_1::error::LockrionError;
+use lockrion_issuance_v1_1::state::*;
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+// =====================================================
+// GOLDEN FIXTURES
+// Expected bytes are spelled out field by field in layout order, independent
+// of the pack/unpack offsets under test.
+// =====================================================
+
+fn key(b: u8) -> Pubkey {
+    Pubkey::new_from_array([b; 32])
+}
+
+fn golden(parts: &[&[u8]]) -> Vec<u8> {
+    parts.concat()
+}
+
+fn issuance_v1() -> IssuanceStateV1 {
+    IssuanceStateV1 {
+        version: STATE_VERSION_V1,
+        bump: 254,
+        issuer_address: key(1),
+        lock_mint: key(2),
+        reward_mint: key(3),
+        deposit_escrow: key(4),
+        reward_escrow: key(5),
+        platform_treasury: key(6),
+        reserve_total: 0x0102_0304_0506_0708_090a_0b0c_0d0e_0f10,
+        start_ts: 1_700_000_000,
+        maturity_ts: 1_700_864_000,
+        claim_window: 7_776_000,
+        final_day_index: 10,
+        total_locked: 500,
+        total_weight_accum: 4_000,
+        last_day_index: 8,
+        reserve_funded: 1,
+        sweep_executed: 0,
+        reclaim_executed: 0,
+        reserved_padding: [0u8; 7],
+    }
+}
+
+fn issuance_v1_golden() -> Vec<u8> {
+    golden(&[
+        &[1, 254],
+        &[1; 32],
+        &[2; 32],
+        &[3; 32],
+        &[4; 32],
+        &[5; 32],
+        &[6; 32],
+        &[0x10, 0x0f, 0x0e, 0x0d, 0x0c, 0x0b, 0x0a, 0x09, 0x08, 0x07, 0x06, 0x05, 0x04, 0x03, 0x02, 0x01],
+        &[0x00, 0xf1, 0x53, 0x65, 0, 0, 0, 0],
+        &[0x00, 0x20, 0x61, 0x65, 0, 0, 0, 0],
+        &[0x00, 0xa7, 0x76, 0, 0, 0, 0, 0],
+        &[10, 0, 0, 0, 0, 0, 0, 0],
+        &[0xf4, 0x01, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        &[0xa0, 0x0f, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        &[8, 0, 0, 0, 0, 0, 0, 0],
+        &[1, 0, 0],
+        &[0; 7],
+    ])
+}
+
+fn issuance_v3() -> IssuanceStateV3 {
+    let mut s: IssuanceStateV3 = issuance_v1().into();
+    s.receipt_mode = 1;
+    s.receipt_mint_bump = 253;
+    s.terms_required = 1;
+    s.vesting_duration = 3_600;
+    s.total_weighted_locked = 600;
+    s.multiplier_schedule[0] = MultiplierTier { end_day: 3, mult_bps: 15_000 };
+    s.accounting_period = 86_400;
+    s.prorated_tail = 120;
+    s.total_claimed = 77;
+    s.total_weight_claimed = 1_000;
+    s.participant_count = 5;
+    s.claimed_count = 2;
+    s.withdrawn_count = 1;
+    s.total_withdrawn = 100;
+    s.attestation_program = key(10);
+    s.attestation_schema = key(11);
+    s.lock_tiers[1] = LockTier { unlock_day: 30, mult_bps: 12_000, penalty_bps: 500 };
+    s
+}
+
+fn issuance_v3_golden() -> Vec<u8> {
+    let mut v1_prefix = issuance_v1_golden();
+    v1_prefix.truncate(285);
+    v1_prefix[0] = 3;
+    golden(&[
+        &v1_prefix,
+        &[1, 253],
+        &[1],
+        &[0; 4],
+        &[0x10, 0x0e, 0, 0, 0, 0, 0, 0],
+        &[0x58, 0x02, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        &[3, 0, 0, 0, 0, 0, 0, 0, 0x98, 0x3a],
+        &[0; 30],
+        &[0x80, 0x51, 0x01, 0, 0, 0, 0, 0],
+        &[120, 0, 0, 0, 0, 0, 0, 0],
+        &[77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        &[0xe8, 0x03, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        &[5, 0, 0, 0, 0, 0, 0, 0],
+        &[2, 0, 0, 0, 0, 0, 0, 0],
+        &[1, 0, 0, 0, 0, 0, 0, 0],
+        &[100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        &[10; 32],
+        &[11; 32],
+        &[0; 12],
+        &[30, 0, 0, 0, 0, 0, 0, 0, 0xe0, 0x2e, 0xf4, 0x01],
+        &[0; 24],
+    ])
+}
+
+fn user_v1() -> UserStateV1 {
+    UserStateV1 {
+        version: STATE_VERSION_V1,
+        bump: 252,
+        issuance: key(7),
+        participant: key(8),
+        locked_amount: 300,
+        user_weight_accum: 2_400,
+        user_last_day_index: 8,
+        reward_claimed: 1,
+        reserved_padding: [0u8; 5],
+    }
+}
+
+fn user_v1_golden() -> Vec<u8> {
+    golden(&[
+        &[1, 252],
+        &[7; 32],
+        &[8; 32],
+        &[0x2c, 0x01, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        &[0x60, 0x09, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        &[8, 0, 0, 0, 0, 0, 0, 0],
+        &[1],
+        &[0; 5],
+    ])
+}
+
+fn user_v3() -> UserStateV3 {
+    let mut s: UserStateV3 = user_v1().into();
+    s.reward_total = 50;
+    s.reward_withdrawn = 20;
+    s.weighted_locked = 450;
+    s.rent_payer = key(9);
+    s.terms_hash = [0xcd; 32];
+    s.terms_ack_ts = 1_700_100_000;
+    s.lock_tier = 2;
+    s
+}
+
+fn user_v3_golden() -> Vec<u8> {
+    let mut v1 = user_v1_golden();
+    v1[0] = 3;
+    v1[107] = 2;
+    golden(&[
+        &v1,
+        &[50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        &[20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        &[0xc2, 0x01, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        &[9; 32],
+        &[0xcd; 32],
+        &[0xa0, 0x77, 0x55, 0x65, 0, 0, 0, 0],
+    ])
+}
+
+fn is_err(r: Result<impl core::fmt::Debug, ProgramError>, e: LockrionError) -> bool {
+    matches!(r, Err(ProgramError::Custom(c)) if c == e as u32)
+}
+
+// =====================================================
+// V1 LAYOUT
+// =====================================================
+
+#[test]
+fn ut_layout_v1_issuance_golden() {
+    let g = issuance_v1_golden();
+    assert_eq!(g.len(), ISSUANCE_STATE_SIZE_V1);
+
+    let mut out = vec![0u8; ISSUANCE_STATE_SIZE_V1];
+    issuance_v1().pack(&mut out).unwrap();
+    assert_eq!(out, g);
+    assert_eq!(IssuanceStateV1::unpack(&g).unwrap(), issuance_v1());
+}
+
+#[test]
+fn ut_layout_v1_user_golden() {
+    let g = user_v1_golden();
+    assert_eq!(g.len(), USER_STATE_SIZE_V1);
+
+    let mut out = vec![0u8; USER_STATE_SIZE_V1];
+    user_v1().pack(&mut out).unwrap();
+    assert_eq!(out, g);
+    assert_eq!(UserStateV1::unpack(&g).unwrap(), user_v1());
+}
+
+// =====================================================
+// V3 LAYOUT
+// =====================================================
+
+#[test]
+fn ut_layout_v3_issuance_golden() {
+    let g = issuance_v3_golden();
+    assert_eq!(g.len(), ISSUANCE_STATE_SIZE);
+
+    let mut out = vec![0u8; ISSUANCE_STATE_SIZE];
+    issuance_v3().pack(&mut out).unwrap();
+    assert_eq!(out, g);
+    assert_eq!(IssuanceStateV3::unpack(&g).unwrap(), issuance_v3());
+}
+
+#[test]
+fn ut_layout_v3_user_golden() {
+    let g = user_v3_golden();
+    assert_eq!(g.len(), USER_STATE_SIZE);
+
+    let mut out = vec![0u8; USER_STATE_SIZE];
+    user_v3().pack(&mut out).unwrap();
+    assert_eq!(out, g);
+    assert_eq!(UserStateV3::unpack(&g).unwrap(), user_v3());
+}
+
+// =====================================================
+// UNIFIED ACCESSOR + MIGRATION
+// =====================================================
+
+#[test]
+fn ut_layout_load_reads_both_versions() {
+    let iss = IssuanceState::load(&issuance_v1_golden()).unwrap();
+    assert_eq!(iss, IssuanceStateV3::from(issuance_v1()));
+    assert_eq!(IssuanceState::load(&issuance_v3_golden()).unwrap(), issuance_v3());
+
+    let user = UserState::load(&user_v1_golden()).unwrap();
+    assert_eq!(user, UserStateV3::from(user_v1()));
+    assert_eq!(UserState::load(&user_v3_golden()).unwrap(), user_v3());
+}
+
+#[test]
+fn ut_layout_v1_defaults_after_migration() {
+    let iss = IssuanceStateV3::from(issuance_v1());
+    assert_eq!(iss.version, STATE_VERSION);
+    assert_eq!(iss.total_weighted_locked, iss.total_locked);
+    assert_eq!(iss.accounting_period, 86_400);
+    assert_eq!(iss.receipt_mode, 0);
+    assert_eq!(iss.terms_required, 0);
+    assert!(!iss.is_attestation_required());
+    assert_eq!(iss.lock_tiers, [LockTier::default(); MAX_LOCK_TIERS]);
+    assert_eq!(iss.total_claimed, 0);
+    assert_eq!(iss.total_weight_claimed, 0);
+
+    let user = UserStateV3::from(user_v1());
+    assert_eq!(user.weighted_locked, user.locked_amount);
+    assert_eq!(user.rent_payer, user.participant);
+    assert_eq!(user.terms_ack_ts, 0);
+    assert_eq!(user.lock_tier, 0);
+    assert!(user.is_reward_settled(), "v1 claim was paid in full");
+}
+
+#[test]
+fn ut_layout_write_to_v1_account_requires_migration() {
+    let mut iss_buf = issuance_v1_golden();
+    assert!(is_err(issuance_v3().pack(&mut iss_buf), LockrionError::StateMigrationRequired));
+
+    let mut user_buf = user_v1_golden();
+    assert!(is_err(user_v3().pack(&mut user_buf), LockrionError::StateMigrationRequired));
+}
+
+#[test]
+fn ut_layout_version_byte_must_match_size() {
+    let mut g = issuance_v1_golden();
+    g[0] = STATE_VERSION;
+    assert!(is_err(IssuanceState::load(&g), LockrionError::InvalidStateVersion));
+
+    let mut g = user_v3_golden();
+    g[0] = STATE_VERSION_V1;
+    assert!(is_err(UserState::load(&g), LockrionError::InvalidStateVersion));
+
+    assert!(is_err(UserState::load(&[0u8; 100]), LockrionError::InvalidAccountSize));
+}
@@ -28,7 +28,9 @@ import {
     const maturityTs = BigInt(process.env.MATURITY_TS!);
     const reserveTotal = BigInt(process.env.RESERVE_TOTAL!);
   
-    const data = Buffer.alloc(1 + 16 + 8 + 8);
+    // IssuanceParams (Borsh) — default params serialize to all-zero bytes
+    const PARAMS_LEN = 1 + 8 + 4 + 8 + 1 + 1 + 32 + 1 + 32 + 32 + 4; // receipt_mint: bool, vesting_duration: i64, multiplier_schedule: Vec (len 0), accounting_period: i64, prorate_final_period: bool, continuous: bool, issuer: Pubkey (zero = payer), metadata: Option (None), attestation_program + attestation_schema: Pubkey (zero = no gate), lock_tiers: Vec (len 0)
+    const data = Buffer.alloc(1 + 16 + 8 + 8 + PARAMS_LEN);
   
     // enum variant index 0 = InitIssuance
     data.writeUInt8(0, 0);
@@ -43,12 +45,29 @@ import {
     // i64 maturity_ts
     data.writeBigInt64LE(maturityTs, 25);
   
+    // Platform registry: head [registry, payer] + current page [registry_page, payer, u32 page_index]
+    const RECORDS_PER_PAGE = 64n;
+    const [registryHead] = PublicKey.findProgramAddressSync(
+      [Buffer.from("registry"), issuer.publicKey.toBuffer()],
+      programId
+    );
+    const head = await connection.getAccountInfo(registryHead);
+    const count = head ? head.data.readBigUInt64LE(34) : 0n; // RegistryHead.count at 34..42
+    const pageIndex = Buffer.alloc(4);
+    pageIndex.writeUInt32LE(Number(count / RECORDS_PER_PAGE));
+    const [registryPage] = PublicKey.findProgramAddressSync(
+      [Buffer.from("registry_page"), issuer.publicKey.toBuffer(), pageIndex],
+      programId
+    );
+
     const ix = new TransactionInstruction({
       programId,
       keys: [
         { pubkey: issuer.publicKey, isSigner: true, isWritable: true },
         { pubkey: issuancePda, isSigner: false, isWritable: true },
         { pubkey: new PublicKey("11111111111111111111111111111111"), isSigner: false, isWritable: false },
+        { pubkey: registryHead, isSigner: false, isWritable: true },
+        { pubkey: registryPage, isSigner: false, isWritable: true },
       ],
       data,
     });
@@ -0,0 +1,63 @@
+use lockrion_issuance_v1_1::error::LockrionError;
+use lockrion_issuance_v1_1::vesting::*;
+
+// =====================================================
+// LINEAR VESTING TESTS
+// =====================================================
+
+const MATURITY: i64 = 1_000_000;
+
+#[test]
+fn ut_vest_before_maturity_is_zero() {
+    assert_eq!(vested_amount(1000, MATURITY, 100, MATURITY - 1).unwrap(), 0);
+}
+
+#[test]
+fn ut_vest_zero_duration_releases_all_at_maturity() {
+    assert_eq!(vested_amount(1000, MATURITY, 0, MATURITY).unwrap(), 1000);
+}
+
+#[test]
+fn ut_vest_at_maturity_with_duration_is_zero() {
+    assert_eq!(vested_amount(1000, MATURITY, 100, MATURITY).unwrap(), 0);
+}
+
+#[test]
+fn ut_vest_linear_midpoint() {
+    assert_eq!(vested_amount(1000, MATURITY, 100, MATURITY + 50).unwrap(), 500);
+}
+
+#[test]
+fn ut_vest_floor_rounding() {
+    // 1000 * 1 / 3 = 333.33 -> 333
+    assert_eq!(vested_amount(1000, MATURITY, 3, MATURITY + 1).unwrap(), 333);
+}
+
+#[test]
+fn ut_vest_full_at_and_after_end() {
+    assert_eq!(vested_amount(1000, MATURITY, 100, MATURITY + 100).unwrap(), 1000);
+    assert_eq!(vested_amount(1000, MATURITY, 100, MATURITY + 10_000).unwrap(), 1000);
+}
+
+#[test]
+fn ut_vest_monotonic() {
+    let mut prev = 0;
+    for t in 0..=120 {
+        let v = vested_amount(997, MATURITY, 100, MATURITY + t).unwrap();
+        assert!(v >= prev);
+        prev = v;
+    }
+    assert_eq!(prev, 997);
+}
+
+#[test]
+fn ut_vest_negative_duration_rejected() {
+    let r = vested_amount(1000, MATURITY, -1, MATURITY + 1);
+    assert!(matches!(r, Err(LockrionError::InvariantViolation)));
+}
+
+#[test]
+fn ut_vest_overflow_guard() {
+    let r = vested_amount(u128::MAX, MATURITY, 100, MATURITY + 2);
+    assert!(matches!(r, Err(LockrionError::ArithmeticOverflow)));
+}